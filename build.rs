@@ -23,8 +23,22 @@ fn main() {
             }
         });
 
+    let onnx = std::env::var("CARGO_FEATURE_ONNX").is_ok();
+    let dispatch = std::env::var("CARGO_FEATURE_DISPATCH").is_ok();
+    let link_static = std::env::var("CARGO_FEATURE_STATIC").is_ok();
+    let nvtx = std::env::var("CARGO_FEATURE_NVTX").is_ok();
+
     let mut cpp_build_config = cpp_build::Config::new();
     cpp_build_config.include(cuda_include_path);
+    if onnx {
+        cpp_build_config.define("ODDITY_FFI_ONNX", None);
+    }
+    if dispatch {
+        cpp_build_config.define("ODDITY_FFI_DISPATCH", None);
+    }
+    if nvtx {
+        cpp_build_config.define("ODDITY_FFI_NVTX", None);
+    }
     #[cfg(not(windows))]
     cpp_build_config.include("/usr/local/tensorrt/include");
     cpp_build_config.build("src/lib.rs");
@@ -33,6 +47,45 @@ fn main() {
     #[cfg(not(windows))]
     println!("cargo:rustc-link-search=/usr/local/tensorrt/lib64");
 
-    println!("cargo:rustc-link-lib=nvinfer");
-    println!("cargo:rustc-link-lib=nvonnxparser");
+    // Static libraries are named after their shared counterparts with a `_static` suffix.
+    let link_lib = |name: &str| {
+        if link_static {
+            println!("cargo:rustc-link-lib=static={name}_static");
+        } else {
+            println!("cargo:rustc-link-lib={name}");
+        }
+    };
+
+    if dispatch {
+        // The dispatch runtime only contains the runtime API. It loads the lean runtime that
+        // matches the engine at deserialization time.
+        link_lib("nvinfer_dispatch");
+    } else {
+        link_lib("nvinfer");
+        link_lib("nvinfer_plugin");
+    }
+    if onnx {
+        link_lib("nvonnxparser");
+    }
+
+    if link_static {
+        // The static TensorRT libraries do not carry their dependencies, so we must link the CUDA
+        // libraries they use ourselves.
+        println!("cargo:rustc-link-lib=static=cublas_static");
+        println!("cargo:rustc-link-lib=static=cublasLt_static");
+        println!("cargo:rustc-link-lib=static=culibos");
+        println!("cargo:rustc-link-lib=static=cudart_static");
+        // cuDNN is only required by TensorRT versions before 10, and only if it is used as a
+        // tactic source, so it is linked only if its location is provided explicitly.
+        if let Ok(cudnn_lib_path) = std::env::var("CUDNN_LIB_PATH") {
+            println!("cargo:rustc-link-search={cudnn_lib_path}");
+            println!("cargo:rustc-link-lib=static=cudnn_static");
+        }
+        #[cfg(not(windows))]
+        {
+            println!("cargo:rustc-link-lib=dl");
+            println!("cargo:rustc-link-lib=rt");
+            println!("cargo:rustc-link-lib=pthread");
+        }
+    }
 }
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_cuda::{DeviceBuffer, Stream};
+
+use crate::engine::{Engine, ExecutionContext};
+use crate::error::Error;
+use crate::event::Event;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Latency and throughput statistics of an engine, produced by [`benchmark_engine()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkReport {
+    /// Number of measured iterations.
+    pub iterations: usize,
+    /// Mean latency of a single inference.
+    pub mean: Duration,
+    /// Median latency of a single inference.
+    pub median: Duration,
+    /// 99th percentile latency of a single inference.
+    pub p99: Duration,
+    /// Lowest latency of a single inference.
+    pub min: Duration,
+    /// Highest latency of a single inference.
+    pub max: Duration,
+    /// Number of inferences per second.
+    pub throughput: f64,
+}
+
+impl BenchmarkReport {
+    /// Create a [`BenchmarkReport`] from latencies in milliseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `latencies` - Latency of each iteration in milliseconds.
+    fn from_latencies(mut latencies: Vec<f32>) -> Self {
+        latencies.sort_by(f32::total_cmp);
+        let iterations = latencies.len();
+        let to_duration =
+            |milliseconds: f64| Duration::from_secs_f64(milliseconds.max(0.0) / 1000.0);
+        let percentile = |p: f64| {
+            let index = ((iterations as f64 * p).ceil() as usize).clamp(1, iterations) - 1;
+            latencies[index] as f64
+        };
+        let total = latencies.iter().map(|latency| *latency as f64).sum::<f64>();
+        let mean = total / iterations as f64;
+        Self {
+            iterations,
+            mean: to_duration(mean),
+            median: to_duration(percentile(0.5)),
+            p99: to_duration(percentile(0.99)),
+            min: to_duration(latencies[0] as f64),
+            max: to_duration(latencies[iterations - 1] as f64),
+            throughput: if total > 0.0 {
+                iterations as f64 * 1000.0 / total
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "iterations: {}, mean: {:?}, median: {:?}, p99: {:?}, min: {:?}, max: {:?}, \
+             throughput: {:.2} inferences/s",
+            self.iterations, self.mean, self.median, self.p99, self.min, self.max, self.throughput,
+        )
+    }
+}
+
+/// Measure the latency and throughput of an engine.
+///
+/// This allocates a device buffer for each IO tensor and runs inference on uninitialized data. Each
+/// iteration is timed on the device with CUDA events, so the measurements do not include host-side
+/// overhead such as copying data to and from the device.
+///
+/// All IO tensors of the engine must have static shapes.
+///
+/// # Arguments
+///
+/// * `engine` - Engine to benchmark.
+/// * `iterations` - Number of iterations to measure.
+/// * `warmup` - Number of iterations to run before measuring.
+/// * `stream` - CUDA stream to execute on.
+///
+/// # Return value
+///
+/// Latency and throughput statistics.
+pub async fn benchmark_engine(
+    engine: &mut Engine,
+    iterations: usize,
+    warmup: usize,
+    stream: &Stream,
+) -> Result<BenchmarkReport> {
+    if iterations == 0 {
+        return Err(Error::InvalidArgument(
+            "number of iterations must be greater than zero".to_string(),
+        ));
+    }
+
+    let io_tensors = engine.io_tensors();
+    let mut io_buffers = HashMap::with_capacity(io_tensors.len());
+    for io_tensor in &io_tensors {
+        if io_tensor.shape.is_dynamic() {
+            return Err(Error::InvalidArgument(format!(
+                "tensor {} has a dynamic shape",
+                io_tensor.name,
+            )));
+        }
+        let data_type = io_tensor.data_type.ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "tensor {} has a data type that is not supported",
+                io_tensor.name,
+            ))
+        })?;
+        let num_elements = io_tensor.shape.num_elements().unwrap_or(0);
+        let buffer = DeviceBuffer::<u8>::new(num_elements * data_type.size(), stream).await;
+        io_buffers.insert(io_tensor.name.as_str(), buffer);
+    }
+    let mut io_buffers = io_buffers
+        .iter_mut()
+        .map(|(name, buffer)| (*name, buffer))
+        .collect::<HashMap<_, _>>();
+
+    let mut context = ExecutionContext::new(engine).await?;
+    for _ in 0..warmup {
+        context.enqueue(&mut io_buffers, stream).await?;
+    }
+    stream.synchronize().await?;
+
+    let start = Event::new_with_timing().await?;
+    let end = Event::new_with_timing().await?;
+    let mut latencies = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        start.record(stream).await?;
+        context.enqueue(&mut io_buffers, stream).await?;
+        end.record(stream).await?;
+        end.synchronize().await?;
+        latencies.push(start.elapsed_time(&end)?);
+    }
+
+    Ok(BenchmarkReport::from_latencies(latencies))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_benchmark_engine() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let report = benchmark_engine(&mut engine, 10, 2, &stream).await.unwrap();
+        assert_eq!(report.iterations, 10);
+        assert!(report.min <= report.median);
+        assert!(report.median <= report.p99);
+        assert!(report.p99 <= report.max);
+        assert!(report.throughput > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_engine_zero_iterations() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        assert!(matches!(
+            benchmark_engine(&mut engine, 0, 0, &stream).await,
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_benchmark_report_from_latencies() {
+        let report = BenchmarkReport::from_latencies(vec![4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(report.min, Duration::from_millis(1));
+        assert_eq!(report.median, Duration::from_millis(2));
+        assert_eq!(report.p99, Duration::from_millis(4));
+        assert_eq!(report.max, Duration::from_millis(4));
+        assert_eq!(report.throughput, 400.0);
+    }
+}
@@ -1,6 +1,9 @@
-use async_cuda::runtime::Future;
+use async_cuda::device::DeviceId;
 
+use crate::device::DeviceGuard;
+use crate::executor::Future;
 use crate::ffi::builder_config::BuilderConfig;
+use crate::ffi::gpu_allocator::GpuAllocator;
 use crate::ffi::memory::HostBuffer;
 use crate::ffi::network::{NetworkDefinition, NetworkDefinitionCreationFlags};
 use crate::ffi::optimization_profile::OptimizationProfile;
@@ -20,10 +23,38 @@ impl Builder {
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1_1_1anonymous__namespace_02_nv_infer_8h_03.html)
     pub async fn new() -> Result<Self> {
-        let inner = Future::new(InnerBuilder::new).await?;
+        // SAFETY: The future is awaited right away and not leaked.
+        let inner = unsafe { Future::new(InnerBuilder::new) }.await?;
         Ok(Builder { inner })
     }
 
+    /// Create a new [`Builder`] on the given device.
+    ///
+    /// This holds a [`crate::DeviceGuard`] while creating the builder, so it waits for other
+    /// guards to be released first.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Device to create the builder on.
+    pub async fn new_on_device(device: DeviceId) -> Result<Self> {
+        let device_guard = DeviceGuard::new(device).await?;
+        let builder = Self::new().await;
+        device_guard.restore().await?;
+        builder
+    }
+
+    /// Set the allocator for all GPU memory allocated by the builder.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `allocator` - GPU allocator to use.
+    #[inline(always)]
+    pub fn set_gpu_allocator(&mut self, allocator: impl GpuAllocator + 'static) {
+        self.inner.set_gpu_allocator(Box::new(allocator))
+    }
+
     /// Create a builder configuration object.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html#a8fac4203e688430dff87483fc9db6bf2)
@@ -33,7 +64,8 @@ impl Builder {
     /// A [`BuilderConfig`] that can later be passed to `build_serialized_network`.
     #[inline(always)]
     pub async fn config(&mut self) -> BuilderConfig {
-        Future::new(|| self.inner.config()).await
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(|| self.inner.config()) }.await
     }
 
     /// Create a new optimization profile.
@@ -87,18 +119,86 @@ impl Builder {
     ///
     /// * `network_definition` - Network definition.
     /// * `config` - Builder configuration.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(network = %network_definition.name()))
+    )]
     pub async fn build_serialized_network(
         &mut self,
         network_definition: &mut NetworkDefinition,
         config: BuilderConfig,
     ) -> Result<HostBuffer> {
-        Future::new(move || {
-            self.inner
-                .build_serialized_network(network_definition, config)
-        })
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .build_serialized_network(network_definition, config)
+            })
+        }
         .await
     }
 
+    /// Builds and serializes a network for the provided [`crate::ffi::network::NetworkDefinition`]
+    /// and [`BuilderConfig`], and cancels the build when the returned future is dropped.
+    ///
+    /// Building an engine can take minutes. Dropping the future (for example when it is used with
+    /// a timeout) signals TensorRT to stop building at the next step. Cancellation requires
+    /// TensorRT 10 or up. On older versions, the build always runs to completion.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_progress_monitor.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition.
+    /// * `config` - Builder configuration.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(network = %network_definition.name()))
+    )]
+    pub async fn build_serialized_network_cancellable(
+        &mut self,
+        network_definition: &mut NetworkDefinition,
+        mut config: BuilderConfig,
+    ) -> Result<HostBuffer> {
+        let cancelled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        config.set_cancellation_flag(cancelled.clone());
+        CancelOnDrop {
+            cancelled,
+            // SAFETY: The future is owned by `CancelOnDrop`, which is awaited right away and not
+            // leaked.
+            future: Box::pin(unsafe {
+                Future::new(move || {
+                    self.inner
+                        .build_serialized_network(network_definition, config)
+                })
+            }),
+        }
+        .await
+    }
+
+    /// Check whether a network can be built with a builder configuration.
+    ///
+    /// This checks the network against the restrictions of the configuration, such as the engine
+    /// capability and the DLA settings, without building it. Use it to reject a network up front
+    /// instead of after a build that can take minutes. Note that a build can still fail for other
+    /// reasons, such as running out of memory.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition.
+    /// * `config` - Builder configuration.
+    pub async fn is_network_supported(
+        &self,
+        network_definition: &mut NetworkDefinition,
+        config: &BuilderConfig,
+    ) -> bool {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.is_network_supported(network_definition, config)) }
+            .await
+    }
+
     /// Determine whether the platform has fast native INT8.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html#ab09433c57e3ef02f7aad672ec4235ea4)
@@ -114,4 +214,168 @@ impl Builder {
     pub fn platform_has_fast_fp16(&self) -> bool {
         self.inner.platform_has_fast_fp16()
     }
+
+    /// Determine whether the platform has TF32 support.
+    ///
+    /// TensorRT does not provide a similar query for FP8 or BF16 support.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    #[inline(always)]
+    pub fn platform_has_tf32(&self) -> bool {
+        self.inner.platform_has_tf32()
+    }
+
+    /// Set the maximum number of threads that the builder may use.
+    ///
+    /// The default is one thread, which includes the calling thread. Bounding this is useful in
+    /// environments with CPU quotas.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_threads` - Maximum number of threads, must be at least one.
+    #[inline(always)]
+    pub fn set_max_threads(&mut self, max_threads: usize) -> Result<()> {
+        self.inner.set_max_threads(max_threads)
+    }
+
+    /// Get the maximum number of threads that the builder may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    #[inline(always)]
+    pub fn max_threads(&self) -> usize {
+        self.inner.max_threads()
+    }
+
+    /// Get the number of DLA cores available to the builder.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder.html)
+    #[inline(always)]
+    pub fn num_dla_cores(&self) -> usize {
+        self.inner.num_dla_cores()
+    }
+}
+
+/// Future that sets a cancellation flag when it is dropped before completing.
+///
+/// The flag is set before the inner future is dropped, so that a build running on a worker thread
+/// is interrupted instead of waited on.
+struct CancelOnDrop<F> {
+    cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    future: std::pin::Pin<Box<F>>,
+}
+
+impl<F: std::future::Future> std::future::Future for CancelOnDrop<F> {
+    type Output = F::Output;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl<F> Drop for CancelOnDrop<F> {
+    fn drop(&mut self) {
+        // Setting the flag after the build finished has no effect, so we can set it regardless.
+        self.cancelled
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_builder_new_on_device() {
+        assert!(Builder::new_on_device(0).await.is_ok());
+        assert!(Builder::new_on_device(-1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_builder_build_serialized_network_cancellable() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await;
+        let plan = builder
+            .build_serialized_network_cancellable(&mut network, builder_config)
+            .await
+            .unwrap();
+        assert!(plan.size() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_builder_build_serialized_network_cancellable_dropped() {
+        if crate::ffi::version::tensorrt_version().major < 10 {
+            return;
+        }
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await;
+        // The build is submitted on the first poll and cancelled when the timeout drops it.
+        let result = tokio::time::timeout(
+            std::time::Duration::ZERO,
+            builder.build_serialized_network_cancellable(&mut network, builder_config),
+        )
+        .await;
+        assert!(result.is_err());
+        // The builder remains usable after a cancelled build.
+        let builder_config = builder.config().await;
+        let plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        assert!(plan.size() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_builder_build_serialized_network_cancelled() {
+        if crate::ffi::version::tensorrt_version().major < 10 {
+            return;
+        }
+        let (mut builder, mut network) = simple_network!();
+        let mut builder_config = builder.config().await;
+        builder_config.set_cancellation_flag(std::sync::Arc::new(
+            std::sync::atomic::AtomicBool::new(true),
+        ));
+        let err = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::BuildFailed { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_builder_build_serialized_network_failure_has_log() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let mut network =
+            builder.network_definition(crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let builder_config = builder.config().await;
+        let err = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap_err();
+        assert!(!err.build_log().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_builder_is_network_supported() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await;
+        assert!(
+            builder
+                .is_network_supported(&mut network, &builder_config)
+                .await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_builder_max_threads() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        builder.set_max_threads(2).unwrap();
+        assert_eq!(builder.max_threads(), 2);
+    }
 }
@@ -0,0 +1,327 @@
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use futures_core::Stream;
+
+use crate::ffi::calibrator::{CalibrationAlgorithm, Calibrator};
+use crate::ffi::memory::HostBuffer;
+
+/// INT8 calibrator that uses entropy calibration (`IInt8EntropyCalibrator2`).
+///
+/// Reads batches from a stream and stores the calibration cache at a path, so that subsequent
+/// builds skip calibration. This is the recommended calibrator for CNN-based networks.
+///
+/// The calibrator only supports networks with a single input. Each item of the stream holds the
+/// input data of a single batch.
+///
+/// Note that the stream is polled on the thread that builds the engine, which blocks until the
+/// next batch is available. The stream must not wait for other operations of this crate or of
+/// `async-cuda`, since these run on the same thread.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut config = builder
+///     .config()
+///     .await
+///     .with_int8_calibrator(EntropyCalibrator::new(batches, "calibration.cache"));
+/// config.set_flag(BuilderFlag::Int8)?;
+/// ```
+pub struct EntropyCalibrator<S> {
+    inner: StreamCalibrator<S>,
+}
+
+impl<S: Stream<Item = HostBuffer>> EntropyCalibrator<S> {
+    /// Create a new [`EntropyCalibrator`] with a batch size of 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `batches` - Stream of batches of input data.
+    /// * `cache_path` - Path to read the calibration cache from and write it to.
+    pub fn new(batches: S, cache_path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: StreamCalibrator::new(batches, cache_path.into()),
+        }
+    }
+
+    /// Set the batch size of the batches in the stream.
+    ///
+    /// For networks with an explicit batch dimension this should be 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - Batch size.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.inner.batch_size = batch_size;
+        self
+    }
+
+    /// Get the path of the calibration cache.
+    #[inline(always)]
+    pub fn cache_path(&self) -> &Path {
+        &self.inner.cache_path
+    }
+}
+
+impl<S: Stream<Item = HostBuffer> + Send> Calibrator for EntropyCalibrator<S> {
+    fn algorithm(&self) -> CalibrationAlgorithm {
+        CalibrationAlgorithm::Entropy
+    }
+
+    fn batch_size(&self) -> usize {
+        self.inner.batch_size
+    }
+
+    fn next_batch(&mut self, input_names: &[&str]) -> Option<Vec<HostBuffer>> {
+        self.inner.next_batch(input_names)
+    }
+
+    fn read_calibration_cache(&mut self) -> Option<Vec<u8>> {
+        self.inner.read_calibration_cache()
+    }
+
+    fn write_calibration_cache(&mut self, cache: &[u8]) {
+        self.inner.write_calibration_cache(cache);
+    }
+}
+
+/// INT8 calibrator that uses MinMax calibration (`IInt8MinMaxCalibrator`).
+///
+/// Reads batches from a stream and stores the calibration cache at a path, so that subsequent
+/// builds skip calibration. This is the recommended calibrator for NLP tasks.
+///
+/// The calibrator only supports networks with a single input. Each item of the stream holds the
+/// input data of a single batch.
+///
+/// Note that the stream is polled on the thread that builds the engine, which blocks until the
+/// next batch is available. The stream must not wait for other operations of this crate or of
+/// `async-cuda`, since these run on the same thread.
+pub struct MinMaxCalibrator<S> {
+    inner: StreamCalibrator<S>,
+}
+
+impl<S: Stream<Item = HostBuffer>> MinMaxCalibrator<S> {
+    /// Create a new [`MinMaxCalibrator`] with a batch size of 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `batches` - Stream of batches of input data.
+    /// * `cache_path` - Path to read the calibration cache from and write it to.
+    pub fn new(batches: S, cache_path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: StreamCalibrator::new(batches, cache_path.into()),
+        }
+    }
+
+    /// Set the batch size of the batches in the stream.
+    ///
+    /// For networks with an explicit batch dimension this should be 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - Batch size.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.inner.batch_size = batch_size;
+        self
+    }
+
+    /// Get the path of the calibration cache.
+    #[inline(always)]
+    pub fn cache_path(&self) -> &Path {
+        &self.inner.cache_path
+    }
+}
+
+impl<S: Stream<Item = HostBuffer> + Send> Calibrator for MinMaxCalibrator<S> {
+    fn algorithm(&self) -> CalibrationAlgorithm {
+        CalibrationAlgorithm::MinMax
+    }
+
+    fn batch_size(&self) -> usize {
+        self.inner.batch_size
+    }
+
+    fn next_batch(&mut self, input_names: &[&str]) -> Option<Vec<HostBuffer>> {
+        self.inner.next_batch(input_names)
+    }
+
+    fn read_calibration_cache(&mut self) -> Option<Vec<u8>> {
+        self.inner.read_calibration_cache()
+    }
+
+    fn write_calibration_cache(&mut self, cache: &[u8]) {
+        self.inner.write_calibration_cache(cache);
+    }
+}
+
+/// Calibrator state shared by [`EntropyCalibrator`] and [`MinMaxCalibrator`].
+struct StreamCalibrator<S> {
+    batches: Pin<Box<S>>,
+    batch_size: usize,
+    cache_path: PathBuf,
+}
+
+impl<S: Stream<Item = HostBuffer>> StreamCalibrator<S> {
+    fn new(batches: S, cache_path: PathBuf) -> Self {
+        Self {
+            batches: Box::pin(batches),
+            batch_size: 1,
+            cache_path,
+        }
+    }
+
+    fn next_batch(&mut self, input_names: &[&str]) -> Option<Vec<HostBuffer>> {
+        if input_names.len() != 1 {
+            tracing::error!(
+                num_inputs = input_names.len(),
+                "calibrator only supports networks with a single input"
+            );
+            return None;
+        }
+        let batches = &mut self.batches;
+        block_on(std::future::poll_fn(|cx| batches.as_mut().poll_next(cx))).map(|batch| vec![batch])
+    }
+
+    fn read_calibration_cache(&mut self) -> Option<Vec<u8>> {
+        match std::fs::read(&self.cache_path) {
+            Ok(cache) => {
+                tracing::debug!(path = %self.cache_path.display(), "loaded calibration cache");
+                Some(cache)
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                tracing::warn!(
+                    path = %self.cache_path.display(),
+                    "failed to read calibration cache: {err}"
+                );
+                None
+            }
+        }
+    }
+
+    fn write_calibration_cache(&mut self, cache: &[u8]) {
+        if let Err(err) = std::fs::write(&self.cache_path, cache) {
+            tracing::error!(
+                path = %self.cache_path.display(),
+                "failed to write calibration cache: {err}"
+            );
+        }
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// # Arguments
+///
+/// * `future` - Future to run.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    /// Waker that unparks the thread that is blocked on the future.
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+    use crate::BuilderFlag;
+
+    use super::*;
+
+    /// Stream that yields the items of an iterator.
+    struct IterStream<I>(I);
+
+    impl<I: Iterator + Unpin> Stream for IterStream<I> {
+        type Item = I::Item;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    fn batches(num_batches: usize) -> IterStream<impl Iterator<Item = HostBuffer> + Unpin> {
+        IterStream((0..num_batches).map(|batch| {
+            HostBuffer::from(
+                (0..4)
+                    .flat_map(|i| ((batch * 4 + i) as f32).to_ne_bytes())
+                    .collect::<Vec<_>>(),
+            )
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_entropy_calibrator_writes_and_reads_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("calibration.cache");
+
+        let (mut builder, mut network) = identity_network!();
+        let mut config = builder
+            .config()
+            .await
+            .with_int8_calibrator(EntropyCalibrator::new(batches(4), &cache_path));
+        config.set_flag(BuilderFlag::Int8).unwrap();
+        builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .unwrap();
+        let cache = std::fs::read(&cache_path).unwrap();
+        assert!(!cache.is_empty());
+
+        // With the cache in place, no batches are needed to build again.
+        let (mut builder, mut network) = identity_network!();
+        let mut config = builder
+            .config()
+            .await
+            .with_int8_calibrator(EntropyCalibrator::new(batches(0), &cache_path));
+        config.set_flag(BuilderFlag::Int8).unwrap();
+        builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_entropy_calibrator_rejects_batch_of_wrong_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("calibration.cache");
+        let batches = IterStream((0..4).map(|_| HostBuffer::from(vec![0u8; 8])));
+
+        let (mut builder, mut network) = identity_network!();
+        let mut config = builder
+            .config()
+            .await
+            .with_int8_calibrator(EntropyCalibrator::new(batches, &cache_path));
+        config.set_flag(BuilderFlag::Int8).unwrap();
+        assert!(builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .is_err());
+        assert!(!cache_path.exists());
+    }
+
+    #[test]
+    fn test_stream_calibrator_next_batch() {
+        let mut calibrator = EntropyCalibrator::new(batches(2), "calibration.cache");
+        assert_eq!(calibrator.batch_size(), 1);
+        assert_eq!(calibrator.next_batch(&["X"]).unwrap()[0].len(), 16);
+        assert!(calibrator.next_batch(&["X", "Y"]).is_none());
+        assert_eq!(calibrator.next_batch(&["X"]).unwrap().len(), 1);
+        assert!(calibrator.next_batch(&["X"]).is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::engine::{Engine, ExecutionContext};
+use crate::error::Error;
+use crate::pool::{Pool, PoolGuard};
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Pool of execution contexts for running concurrent inferences on a single engine.
+///
+/// The contexts are created with [`ExecutionContext::from_engine_many()`]. A context is checked
+/// out with [`ExecutionContextPool::acquire()`], which waits until a context is available, and is
+/// returned to the pool when the guard is dropped.
+pub struct ExecutionContextPool {
+    contexts: Pool<ExecutionContext<'static>>,
+    num_contexts: usize,
+}
+
+impl ExecutionContextPool {
+    /// Create a new [`ExecutionContextPool`].
+    ///
+    /// This consumes the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to create execution contexts for.
+    /// * `num_contexts` - Number of execution contexts in the pool. This is the maximum number of
+    ///   concurrent inferences.
+    pub async fn new(engine: Engine, num_contexts: usize) -> Result<Self> {
+        if num_contexts == 0 {
+            return Err(Error::InvalidArgument(
+                "execution context pool requires at least one context".to_string(),
+            ));
+        }
+        let contexts = ExecutionContext::from_engine_many(engine, num_contexts).await?;
+        Ok(Self {
+            contexts: Pool::new(contexts),
+            num_contexts,
+        })
+    }
+
+    /// Check out an execution context, waiting until one becomes available.
+    ///
+    /// The context is returned to the pool when the guard is dropped.
+    pub async fn acquire(&self) -> ExecutionContextGuard<'_> {
+        ExecutionContextGuard {
+            inner: self.contexts.acquire().await,
+        }
+    }
+
+    /// Get the total number of execution contexts in the pool.
+    #[inline(always)]
+    pub fn num_contexts(&self) -> usize {
+        self.num_contexts
+    }
+
+    /// Get the number of execution contexts that are currently not checked out.
+    #[inline(always)]
+    pub fn num_available(&self) -> usize {
+        self.contexts.num_available()
+    }
+}
+
+/// Execution context checked out from an [`ExecutionContextPool`].
+///
+/// The context is returned to the pool on drop.
+pub struct ExecutionContextGuard<'pool> {
+    inner: PoolGuard<'pool, ExecutionContext<'static>>,
+}
+
+impl<'pool> std::ops::Deref for ExecutionContextGuard<'pool> {
+    type Target = ExecutionContext<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'pool> std::ops::DerefMut for ExecutionContextGuard<'pool> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_cuda::Stream;
+
+    use crate::tests::memory::*;
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_execution_context_pool_acquire() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let pool = ExecutionContextPool::new(engine, 2).await.unwrap();
+        assert_eq!(pool.num_contexts(), 2);
+        let mut context = pool.acquire().await;
+        assert_eq!(pool.num_available(), 1);
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        drop(context);
+        assert_eq!(pool.num_available(), 2);
+    }
+}
@@ -0,0 +1,244 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::task::{Poll, Waker};
+
+use cpp::cpp;
+
+use async_cuda::device::DeviceId;
+
+use crate::executor::Future;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Lock that is held by every [`DeviceGuard`], so that guards do not change the current device
+/// under each other.
+static DEVICE_LOCK: DeviceLock = DeviceLock::new();
+
+/// Guard that sets the current device and restores the previous device when it goes out of scope.
+///
+/// Objects such as [`crate::Runtime`] and [`crate::Builder`] are bound to the device that is
+/// current when they are created. Creating them while holding a guard makes sure they end up on
+/// the intended device. [`crate::Runtime::new_on_device()`] and
+/// [`crate::Builder::new_on_device()`] do this for you.
+///
+/// Only one guard exists at a time: creating a guard waits until all other guards in the process
+/// are dropped or restored. This makes sure that tasks that use guards for different devices
+/// concurrently do not change the current device under each other. Code that sets the current
+/// device without a guard is not serialized, and guards must not be nested, since the inner guard
+/// would wait for the outer guard forever.
+///
+/// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__DEVICE.html)
+pub struct DeviceGuard {
+    previous: Option<DeviceId>,
+    _lock: DeviceLockGuard,
+}
+
+impl DeviceGuard {
+    /// Set the current device and create a guard that restores the previous device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Device to make current.
+    pub async fn new(device: DeviceId) -> Result<Self> {
+        let lock = DEVICE_LOCK.lock().await;
+        let previous = async_cuda::Device::get().await?;
+        async_cuda::Device::set(device).await?;
+        Ok(Self {
+            previous: Some(previous),
+            _lock: lock,
+        })
+    }
+
+    /// Get the device that was current before the guard was created.
+    #[inline(always)]
+    pub fn previous(&self) -> Option<DeviceId> {
+        self.previous
+    }
+
+    /// Restore the previous device and wait for it to become current.
+    ///
+    /// Dropping the guard restores the previous device as well, but does not wait for it and
+    /// ignores errors.
+    pub async fn restore(mut self) -> Result<()> {
+        if let Some(previous) = self.previous.take() {
+            async_cuda::Device::set(previous).await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DeviceGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            async_cuda::runtime::enqueue_decoupled(move || {
+                let _ = async_cuda::ffi::device::Device::set(previous);
+            });
+        }
+    }
+}
+
+/// Lock that serializes [`DeviceGuard`] holders.
+///
+/// The crate does not depend on an async runtime, so this is a minimal async lock.
+struct DeviceLock {
+    state: Mutex<DeviceLockState>,
+}
+
+struct DeviceLockState {
+    locked: bool,
+    waiters: Vec<Waker>,
+}
+
+impl DeviceLock {
+    const fn new() -> Self {
+        Self {
+            state: Mutex::new(DeviceLockState {
+                locked: false,
+                waiters: Vec::new(),
+            }),
+        }
+    }
+
+    /// Wait until the lock is free and acquire it.
+    async fn lock(&'static self) -> DeviceLockGuard {
+        std::future::poll_fn(|cx| {
+            let mut state = self.state();
+            if state.locked {
+                state.waiters.push(cx.waker().clone());
+                Poll::Pending
+            } else {
+                state.locked = true;
+                Poll::Ready(DeviceLockGuard { lock: self })
+            }
+        })
+        .await
+    }
+
+    fn state(&self) -> MutexGuard<'_, DeviceLockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Releases the [`DeviceLock`] when dropped.
+struct DeviceLockGuard {
+    lock: &'static DeviceLock,
+}
+
+impl Drop for DeviceLockGuard {
+    fn drop(&mut self) {
+        let waiters = {
+            let mut state = self.lock.state();
+            state.locked = false;
+            std::mem::take(&mut state.waiters)
+        };
+        // Wake all waiters instead of just the first one, since waiters that were dropped in the
+        // meantime would not pass the lock on.
+        for waker in waiters {
+            waker.wake();
+        }
+    }
+}
+
+/// Run a future with `device` as the current device, and restore the previous device afterwards.
+///
+/// This holds a [`DeviceGuard`] while `future` runs. Refer to [`DeviceGuard`] for the caveats of
+/// changing the current device.
+///
+/// # Arguments
+///
+/// * `device` - Device to make current.
+/// * `future` - Future to run.
+///
+/// # Return value
+///
+/// Output of `future`.
+pub async fn on_device<T>(
+    device: DeviceId,
+    future: impl std::future::Future<Output = T>,
+) -> Result<T> {
+    let guard = DeviceGuard::new(device).await?;
+    let output = future.await;
+    guard.restore().await?;
+    Ok(output)
+}
+
+/// Get the compute capability of a device.
+///
+/// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__DEVICE.html)
+///
+/// # Arguments
+///
+/// * `device` - Device to query.
+///
+/// # Return value
+///
+/// Major and minor version of the compute capability.
+pub async fn compute_capability(device: DeviceId) -> Result<(i32, i32)> {
+    // SAFETY: The future is awaited right away and not leaked.
+    let compute_capability = unsafe {
+        Future::new(move || {
+            let mut major: i32 = 0;
+            let mut minor: i32 = 0;
+            let major_ptr = &mut major as *mut i32;
+            let minor_ptr = &mut minor as *mut i32;
+            let success = cpp!(unsafe [
+                device as "std::int32_t",
+                major_ptr as "std::int32_t*",
+                minor_ptr as "std::int32_t*"
+            ] -> bool as "bool" {
+                return cudaDeviceGetAttribute(major_ptr, cudaDevAttrComputeCapabilityMajor, device)
+                        == cudaSuccess
+                    && cudaDeviceGetAttribute(minor_ptr, cudaDevAttrComputeCapabilityMinor, device)
+                        == cudaSuccess;
+            });
+            success.then_some((major, minor))
+        })
+    }
+    .await;
+    compute_capability.ok_or_else(|| {
+        crate::error::Error::InvalidArgument(format!(
+            "failed to get compute capability of device {device}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Runtime;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_device_guard_restores_previous_device() {
+        let previous = async_cuda::Device::get().await.unwrap();
+        let guard = DeviceGuard::new(0).await.unwrap();
+        assert_eq!(async_cuda::Device::get().await.unwrap(), 0);
+        assert_eq!(guard.previous(), Some(previous));
+        guard.restore().await.unwrap();
+        assert_eq!(async_cuda::Device::get().await.unwrap(), previous);
+    }
+
+    #[tokio::test]
+    async fn test_device_guard_waits_for_other_guard() {
+        let guard = DeviceGuard::new(0).await.unwrap();
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(100), DeviceGuard::new(0))
+                .await
+                .is_err()
+        );
+        guard.restore().await.unwrap();
+        assert!(DeviceGuard::new(0).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_compute_capability() {
+        let (major, _) = compute_capability(0).await.unwrap();
+        assert!(major > 0);
+        assert!(compute_capability(-1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_on_device() {
+        let runtime = on_device(0, Runtime::new()).await.unwrap();
+        assert_eq!(runtime.device(), 0);
+    }
+}
@@ -0,0 +1,294 @@
+use crate::ffi::data_type::DataType;
+
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
+
+/// Rust type that corresponds to the elements of a tensor with a TensorRT data type.
+///
+/// # Safety
+///
+/// Implementors must be plain old data: every bit pattern of the size of the type must be a valid
+/// value, and the type must have the same size as [`TensorElement::DATA_TYPE`].
+pub unsafe trait TensorElement: Copy + Send + Sync + 'static {
+    /// TensorRT data type of the element.
+    const DATA_TYPE: DataType;
+}
+
+// SAFETY: All bit patterns are valid `f32` values and `f32` is 4 bytes large.
+unsafe impl TensorElement for f32 {
+    const DATA_TYPE: DataType = DataType::Float;
+}
+
+// SAFETY: All bit patterns are valid `i8` values and `i8` is 1 byte large.
+unsafe impl TensorElement for i8 {
+    const DATA_TYPE: DataType = DataType::Int8;
+}
+
+// SAFETY: All bit patterns are valid `i32` values and `i32` is 4 bytes large.
+unsafe impl TensorElement for i32 {
+    const DATA_TYPE: DataType = DataType::Int32;
+}
+
+// SAFETY: All bit patterns are valid `i64` values and `i64` is 8 bytes large.
+unsafe impl TensorElement for i64 {
+    const DATA_TYPE: DataType = DataType::Int64;
+}
+
+// SAFETY: All bit patterns are valid `u8` values and `u8` is 1 byte large.
+unsafe impl TensorElement for u8 {
+    const DATA_TYPE: DataType = DataType::Uint8;
+}
+
+// SAFETY: All bit patterns are valid `f16` values and `f16` is 2 bytes large.
+#[cfg(feature = "half")]
+unsafe impl TensorElement for f16 {
+    const DATA_TYPE: DataType = DataType::Half;
+}
+
+// SAFETY: All bit patterns are valid `bf16` values and `bf16` is 2 bytes large.
+#[cfg(feature = "half")]
+unsafe impl TensorElement for bf16 {
+    const DATA_TYPE: DataType = DataType::Bf16;
+}
+
+/// Tensor data with a data type that is determined at runtime.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorData {
+    /// Data of a [`DataType::Float`] tensor.
+    F32(Vec<f32>),
+    /// Data of a [`DataType::Int8`] tensor.
+    I8(Vec<i8>),
+    /// Data of a [`DataType::Int32`] tensor.
+    I32(Vec<i32>),
+    /// Data of a [`DataType::Int64`] tensor.
+    I64(Vec<i64>),
+    /// Data of a [`DataType::Uint8`] tensor.
+    U8(Vec<u8>),
+    /// Data of a [`DataType::Half`] tensor.
+    #[cfg(feature = "half")]
+    F16(Vec<f16>),
+    /// Data of a [`DataType::Bf16`] tensor.
+    #[cfg(feature = "half")]
+    Bf16(Vec<bf16>),
+}
+
+impl TensorData {
+    /// Get the TensorRT data type of the data.
+    pub fn data_type(&self) -> DataType {
+        match self {
+            TensorData::F32(_) => DataType::Float,
+            TensorData::I8(_) => DataType::Int8,
+            TensorData::I32(_) => DataType::Int32,
+            TensorData::I64(_) => DataType::Int64,
+            TensorData::U8(_) => DataType::Uint8,
+            #[cfg(feature = "half")]
+            TensorData::F16(_) => DataType::Half,
+            #[cfg(feature = "half")]
+            TensorData::Bf16(_) => DataType::Bf16,
+        }
+    }
+
+    /// Get the number of elements.
+    pub fn len(&self) -> usize {
+        match self {
+            TensorData::F32(values) => values.len(),
+            TensorData::I8(values) => values.len(),
+            TensorData::I32(values) => values.len(),
+            TensorData::I64(values) => values.len(),
+            TensorData::U8(values) => values.len(),
+            #[cfg(feature = "half")]
+            TensorData::F16(values) => values.len(),
+            #[cfg(feature = "half")]
+            TensorData::Bf16(values) => values.len(),
+        }
+    }
+
+    /// Whether or not there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the data as `f32` values, if the data type is [`DataType::Float`].
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            TensorData::F32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Get the data as `i32` values, if the data type is [`DataType::Int32`].
+    pub fn as_i32(&self) -> Option<&[i32]> {
+        match self {
+            TensorData::I32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Get the data as `i64` values, if the data type is [`DataType::Int64`].
+    pub fn as_i64(&self) -> Option<&[i64]> {
+        match self {
+            TensorData::I64(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Reinterpret the data as bytes.
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        match self {
+            TensorData::F32(values) => as_bytes(values),
+            TensorData::I8(values) => as_bytes(values),
+            TensorData::I32(values) => as_bytes(values),
+            TensorData::I64(values) => as_bytes(values),
+            TensorData::U8(values) => values,
+            #[cfg(feature = "half")]
+            TensorData::F16(values) => as_bytes(values),
+            #[cfg(feature = "half")]
+            TensorData::Bf16(values) => as_bytes(values),
+        }
+    }
+
+    /// Create [`TensorData`] by copying raw data of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - Data type of tensor.
+    /// * `bytes` - Raw data of tensor.
+    ///
+    /// # Return value
+    ///
+    /// Tensor data, or `None` if there is no variant for the data type.
+    pub(crate) fn from_bytes(data_type: DataType, bytes: &[u8]) -> Option<Self> {
+        match data_type {
+            DataType::Float => Some(TensorData::F32(from_bytes(bytes))),
+            DataType::Int8 => Some(TensorData::I8(from_bytes(bytes))),
+            DataType::Int32 => Some(TensorData::I32(from_bytes(bytes))),
+            DataType::Int64 => Some(TensorData::I64(from_bytes(bytes))),
+            DataType::Uint8 => Some(TensorData::U8(bytes.to_vec())),
+            #[cfg(feature = "half")]
+            DataType::Half => Some(TensorData::F16(from_bytes(bytes))),
+            #[cfg(feature = "half")]
+            DataType::Bf16 => Some(TensorData::Bf16(from_bytes(bytes))),
+            _ => None,
+        }
+    }
+}
+
+/// Reinterpret a slice of tensor elements as bytes.
+///
+/// # Arguments
+///
+/// * `values` - Tensor elements.
+pub(crate) fn as_bytes<T: TensorElement>(values: &[T]) -> &[u8] {
+    // SAFETY: This is safe because `TensorElement` guarantees that `T` is plain old data, and the
+    // returned slice borrows from `values`.
+    unsafe {
+        std::slice::from_raw_parts(values.as_ptr() as *const u8, std::mem::size_of_val(values))
+    }
+}
+
+/// Copy bytes into a vector of tensor elements.
+///
+/// Trailing bytes that do not make up a whole element are ignored.
+///
+/// # Arguments
+///
+/// * `bytes` - Bytes to copy.
+pub(crate) fn from_bytes<T: TensorElement>(bytes: &[u8]) -> Vec<T> {
+    let num_elements = bytes.len() / std::mem::size_of::<T>();
+    let mut values = Vec::<T>::with_capacity(num_elements);
+    // SAFETY: This is safe because `TensorElement` guarantees that any bit pattern is a valid `T`,
+    // and we copy exactly `num_elements` elements into the allocated capacity before setting the
+    // length.
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            values.as_mut_ptr() as *mut u8,
+            num_elements * std::mem::size_of::<T>(),
+        );
+        values.set_len(num_elements);
+    }
+    values
+}
+
+/// Convert `f32` values to half precision.
+///
+/// The conversion helpers in this module run on the CPU, on host memory. Converting device
+/// buffers on the GPU is out of scope for this crate. To avoid the conversion altogether, mark the
+/// IO tensors of the network as half precision with [`crate::Tensor::set_type()`] and bind `f16`
+/// buffers directly, or keep `f32` IO tensors and let TensorRT convert inside the engine.
+///
+/// # Arguments
+///
+/// * `values` - Values to convert.
+#[cfg(feature = "half")]
+pub fn f32_to_f16(values: &[f32]) -> Vec<f16> {
+    values.iter().copied().map(f16::from_f32).collect()
+}
+
+/// Convert half precision values to `f32`.
+///
+/// Runs on the CPU, see [`f32_to_f16()`].
+///
+/// # Arguments
+///
+/// * `values` - Values to convert.
+#[cfg(feature = "half")]
+pub fn f16_to_f32(values: &[f16]) -> Vec<f32> {
+    values.iter().copied().map(f16::to_f32).collect()
+}
+
+/// Convert `f32` values to brain float.
+///
+/// Runs on the CPU, see [`f32_to_f16()`].
+///
+/// # Arguments
+///
+/// * `values` - Values to convert.
+#[cfg(feature = "half")]
+pub fn f32_to_bf16(values: &[f32]) -> Vec<bf16> {
+    values.iter().copied().map(bf16::from_f32).collect()
+}
+
+/// Convert brain float values to `f32`.
+///
+/// Runs on the CPU, see [`f32_to_f16()`].
+///
+/// # Arguments
+///
+/// * `values` - Values to convert.
+#[cfg(feature = "half")]
+pub fn bf16_to_f32(values: &[bf16]) -> Vec<f32> {
+    values.iter().copied().map(bf16::to_f32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_bytes_from_bytes() {
+        let values = [1.0_f32, -2.5, 3.25];
+        let bytes = as_bytes(&values);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(from_bytes::<f32>(bytes), values);
+    }
+
+    #[test]
+    fn test_tensor_data_from_bytes() {
+        let data = TensorData::I64(vec![1, -2, 3]);
+        assert_eq!(data.data_type(), DataType::Int64);
+        assert_eq!(
+            TensorData::from_bytes(DataType::Int64, data.as_bytes()),
+            Some(data)
+        );
+        assert_eq!(TensorData::from_bytes(DataType::Bool, &[1]), None);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_f16_conversion() {
+        let values = [1.0_f32, -2.5, 0.5];
+        assert_eq!(f16_to_f32(&f32_to_f16(&values)), values);
+        assert_eq!(bf16_to_f32(&f32_to_bf16(&values)), values);
+    }
+}
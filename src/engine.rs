@@ -1,11 +1,23 @@
-use async_cuda::runtime::Future;
+use async_cuda::device::DeviceId;
 use async_cuda::{DeviceBuffer, Stream};
 
+use crate::event::Event;
+use crate::executor::Future;
+use crate::ffi::data_type::DataType;
+use crate::ffi::debug_listener::DebugListener;
+use crate::ffi::dims::Dims;
+use crate::ffi::engine_capability::EngineCapability;
 use crate::ffi::memory::HostBuffer;
+use crate::ffi::output_allocator::OutputAllocator;
 use crate::ffi::sync::engine::Engine as InnerEngine;
 use crate::ffi::sync::engine::ExecutionContext as InnerExecutionContext;
+use crate::io_bindings::IoBindings;
+use crate::io_buffers::IoBuffers;
 
-pub use crate::ffi::sync::engine::TensorIoMode;
+pub use crate::ffi::sync::engine::{
+    ExecutionContextAllocationStrategy, HardwareCompatibilityLevel, IoTensorDescriptor,
+    SerializationConfig, SerializationFlag, TensorFormat, TensorIoMode, TensorLocation,
+};
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
@@ -22,6 +34,12 @@ impl Engine {
         Self { inner }
     }
 
+    /// Get mutable reference to inner object.
+    #[inline(always)]
+    pub(crate) fn inner_mut(&mut self) -> &mut InnerEngine {
+        &mut self.inner
+    }
+
     /// Serialize the network.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ab42c2fde3292f557ed17aae6f332e571)
@@ -29,11 +47,97 @@ impl Engine {
     /// # Return value
     ///
     /// A [`HostBuffer`] that contains the serialized engine.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device(), engine = %self.name()))
+    )]
     #[inline(always)]
     pub fn serialize(&self) -> Result<HostBuffer> {
         self.inner.serialize()
     }
 
+    /// Serialize the network with a serialization config.
+    ///
+    /// This can be used to exclude the weights from the plan, so that they can be shipped
+    /// separately and set with a [`crate::Refitter`] after deserialization. Excluding weights
+    /// requires an engine that was built with refit enabled. Requires TensorRT 10 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Serialization config.
+    ///
+    /// # Return value
+    ///
+    /// A [`HostBuffer`] that contains the serialized engine.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device(), engine = %self.name()))
+    )]
+    #[inline(always)]
+    pub fn serialize_with_config(&self, config: &SerializationConfig) -> Result<HostBuffer> {
+        self.inner.serialize_with_config(config)
+    }
+
+    /// Serialize the network and write it to a writer.
+    ///
+    /// This call blocks until the serialized engine has been written.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Writer to write serialized engine to.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device(), engine = %self.name()))
+    )]
+    #[inline(always)]
+    pub fn serialize_to_writer(&self, writer: impl std::io::Write) -> Result<()> {
+        self.inner.serialize_to_writer(writer)
+    }
+
+    /// Serialize the network and write it to a file.
+    ///
+    /// This call blocks until the serialized engine has been written. The file is created if it
+    /// does not exist, and truncated if it does.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to file to write serialized engine to.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device(), engine = %self.name()))
+    )]
+    #[inline(always)]
+    pub fn serialize_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        self.inner.serialize_to_file(path)
+    }
+
+    /// Get the name of the engine.
+    ///
+    /// The name is taken from the network the engine was built from, see
+    /// [`crate::NetworkDefinition::set_name()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Get the engine capability that the engine was built with.
+    ///
+    /// See [`crate::BuilderConfig::set_engine_capability()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn engine_capability(&self) -> EngineCapability {
+        self.inner.engine_capability()
+    }
+
     /// Get the number of IO tensors.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#af2018924cbea2fa84808040e60c58405)
@@ -54,7 +158,7 @@ impl Engine {
         self.inner.io_tensor_name(io_tensor_index)
     }
 
-    /// Get the shape of a tensor.
+    /// Get the shape of a tensor, where dynamic dimensions are `-1`.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#af96a2ee402ab47b7e0b7f0becb63d693)
     ///
@@ -62,180 +166,2560 @@ impl Engine {
     ///
     /// * `tensor_name` - Tensor name.
     #[inline(always)]
-    pub fn tensor_shape(&self, tensor_name: &str) -> Vec<usize> {
+    pub fn tensor_shape(&self, tensor_name: &str) -> Dims {
         self.inner.tensor_shape(tensor_name)
     }
 
-    /// Get the IO mode of a tensor.
+    /// Get the number of optimization profiles the engine was built with.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ae236a14178df506070cd39a9ef3775e7)
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn num_optimization_profiles(&self) -> usize {
+        self.inner.num_optimization_profiles()
+    }
+
+    /// Get the min, opt and max shape of an input tensor in an optimization profile.
+    ///
+    /// This can be used to check whether a shape is supported by the engine before passing it to
+    /// [`ExecutionContext::set_input_shape()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
     /// # Arguments
     ///
     /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Minimum, optimum and maximum shape, or `None` if the tensor is not an input or the profile
+    /// does not exist.
     #[inline(always)]
-    pub fn tensor_io_mode(&self, tensor_name: &str) -> TensorIoMode {
-        self.inner.tensor_io_mode(tensor_name)
+    pub fn profile_shape(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<(Dims, Dims, Dims)> {
+        self.inner.profile_shape(tensor_name, profile_index)
     }
-}
 
-/// Context for executing inference using an engine.
-///
-/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
-pub struct ExecutionContext<'engine> {
-    inner: InnerExecutionContext<'engine>,
-}
+    /// Get the min shape of an input tensor in an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Minimum shape, or `None` if the tensor is not an input or the profile does not exist.
+    #[inline(always)]
+    pub fn profile_min_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.inner.profile_min_shape(tensor_name, profile_index)
+    }
 
-impl ExecutionContext<'static> {
-    /// Create an execution context from an [`Engine`].
+    /// Get the opt shape of an input tensor in an optimization profile.
     ///
-    /// This is the owned version of [`ExecutionContext::new()`]. It consumes the engine. In
-    /// exchange, it produces an execution context with a `'static` lifetime.
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Optimum shape, or `None` if the tensor is not an input or the profile does not exist.
+    #[inline(always)]
+    pub fn profile_opt_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.inner.profile_opt_shape(tensor_name, profile_index)
+    }
+
+    /// Get the max shape of an input tensor in an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
     /// # Arguments
     ///
-    /// * `engine` - Parent engine.
-    pub async fn from_engine(engine: Engine) -> Result<Self> {
-        Future::new(move || {
-            InnerExecutionContext::from_engine(engine.inner).map(ExecutionContext::from_inner_owned)
-        })
-        .await
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Maximum shape, or `None` if the tensor is not an input or the profile does not exist.
+    #[inline(always)]
+    pub fn profile_max_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.inner.profile_max_shape(tensor_name, profile_index)
     }
 
-    /// Create multiple execution contexts from an [`Engine`].
+    /// Get the min, opt and max values of an input shape tensor in an optimization profile.
     ///
-    /// This is the owned version of [`ExecutionContext::new()`]. It consumes the engine. In
-    /// exchange, it produces a set of execution contexts with a `'static` lifetime.
+    /// This is the counterpart of [`Engine::profile_shape()`] for shape tensors, whose values
+    /// rather than dimensions are constrained by the profile. Like the other profile queries, this
+    /// only requires a deserialized engine, so it is available when building with the `dispatch`
+    /// feature.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
     /// # Arguments
     ///
-    /// * `engine` - Parent engine.
-    /// * `num` - Number of execution contexsts to produce.
-    pub async fn from_engine_many(engine: Engine, num: usize) -> Result<Vec<Self>> {
-        Future::new(move || {
-            Ok(InnerExecutionContext::from_engine_many(engine.inner, num)?
-                .into_iter()
-                .map(Self::from_inner_owned)
-                .collect())
-        })
-        .await
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Minimum, optimum and maximum values, or `None` if the tensor is not an input shape tensor or
+    /// the profile does not exist.
+    #[inline(always)]
+    pub fn profile_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<(Vec<i32>, Vec<i32>, Vec<i32>)> {
+        self.inner.profile_shape_values(tensor_name, profile_index)
     }
 
-    /// Create [`ExecutionContext`] from its inner object.
-    fn from_inner_owned(inner: InnerExecutionContext<'static>) -> Self {
-        Self { inner }
+    /// Get the min values of an input shape tensor in an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Minimum values, or `None` if the tensor is not an input shape tensor or the profile does not
+    /// exist.
+    #[inline(always)]
+    pub fn profile_min_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.inner
+            .profile_min_shape_values(tensor_name, profile_index)
     }
-}
 
-impl<'engine> ExecutionContext<'engine> {
-    /// Create [`ExecutionContext`] from its inner object.
-    fn from_inner(inner: InnerExecutionContext<'engine>) -> Self {
-        Self { inner }
+    /// Get the opt values of an input shape tensor in an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Optimum values, or `None` if the tensor is not an input shape tensor or the profile does not
+    /// exist.
+    #[inline(always)]
+    pub fn profile_opt_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.inner
+            .profile_opt_shape_values(tensor_name, profile_index)
     }
 
-    /// Create an execution context from an [`Engine`].
+    /// Get the max values of an input shape tensor in an optimization profile.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
     /// # Arguments
     ///
-    /// * `engine` - Parent engine.
-    pub async fn new(engine: &mut Engine) -> Result<ExecutionContext> {
-        Future::new(move || {
-            InnerExecutionContext::new(&mut engine.inner).map(ExecutionContext::from_inner)
-        })
-        .await
+    /// * `tensor_name` - Tensor name.
+    /// * `profile_index` - Index of optimization profile.
+    ///
+    /// # Return value
+    ///
+    /// Maximum values, or `None` if the tensor is not an input shape tensor or the profile does not
+    /// exist.
+    #[inline(always)]
+    pub fn profile_max_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.inner
+            .profile_max_shape_values(tensor_name, profile_index)
     }
 
-    /// Asynchronously execute inference.
+    /// Get the IO mode of a tensor.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html#a63cd95430852038ce864e17c670e0b36)
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ae236a14178df506070cd39a9ef3775e7)
     ///
-    /// # Stream ordered semantics
+    /// # Arguments
     ///
-    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
-    /// complete serially with respect to other operations on the same stream.
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn tensor_io_mode(&self, tensor_name: &str) -> TensorIoMode {
+        self.inner.tensor_io_mode(tensor_name)
+    }
+
+    /// Get the data type of a tensor.
     ///
-    /// # Thread-safety
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
-    /// Calling this function from the same context with a different CUDA stream concurrently
-    /// results in undefined behavior. To perform inference concurrently in multiple streams, use
-    /// one execution context per stream.
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Data type, or `None` if the data type is not known to this crate.
+    #[inline(always)]
+    pub fn tensor_data_type(&self, tensor_name: &str) -> Option<DataType> {
+        self.inner.tensor_data_type(tensor_name)
+    }
+
+    /// Get the location of a tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
     ///
     /// # Arguments
     ///
-    /// * `io_buffers` - Input and output buffers.
-    /// * `stream` - CUDA stream to execute on.
-    pub async fn enqueue<T: Copy>(
-        &mut self,
-        io_buffers: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
-        stream: &Stream,
-    ) -> Result<()> {
-        let mut io_buffers_inner = io_buffers
-            .iter_mut()
-            .map(|(name, buffer)| (*name, buffer.inner_mut()))
-            .collect::<std::collections::HashMap<_, _>>();
-        Future::new(move || self.inner.enqueue(&mut io_buffers_inner, stream.inner())).await
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn tensor_location(&self, tensor_name: &str) -> TensorLocation {
+        self.inner.tensor_location(tensor_name)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::tests::memory::*;
-    use crate::tests::utils::*;
+    /// Get descriptions of all IO tensors.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn io_tensors(&self) -> Vec<IoTensorDescriptor> {
+        self.inner.io_tensors()
+    }
 
-    use super::*;
+    /// Get descriptions of all input tensors.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    pub fn inputs(&self) -> Vec<IoTensorDescriptor> {
+        self.io_tensors()
+            .into_iter()
+            .filter(|io_tensor| io_tensor.io_mode == TensorIoMode::Input)
+            .collect()
+    }
 
-    #[tokio::test]
-    async fn test_engine_serialize() {
-        let engine = simple_engine!();
-        let serialized_engine = engine.serialize().unwrap();
-        let serialized_engine_bytes = serialized_engine.as_bytes();
-        assert!(serialized_engine_bytes.len() > 1800);
-        assert!(serialized_engine_bytes.len() < 2500);
-        assert_eq!(
-            &serialized_engine_bytes[..8],
-            &[102_u8, 116_u8, 114_u8, 116_u8, 0_u8, 0_u8, 0_u8, 0_u8],
-        );
+    /// Get descriptions of all output tensors.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    pub fn outputs(&self) -> Vec<IoTensorDescriptor> {
+        self.io_tensors()
+            .into_iter()
+            .filter(|io_tensor| io_tensor.io_mode == TensorIoMode::Output)
+            .collect()
     }
 
-    #[tokio::test]
-    async fn test_engine_tensor_info() {
-        let engine = simple_engine!();
-        assert_eq!(engine.num_io_tensors(), 2);
-        assert_eq!(engine.io_tensor_name(0), "X");
-        assert_eq!(engine.io_tensor_name(1), "Y");
-        assert_eq!(engine.tensor_io_mode("X"), TensorIoMode::Input);
-        assert_eq!(engine.tensor_io_mode("Y"), TensorIoMode::Output);
-        assert_eq!(engine.tensor_shape("X"), &[1, 2]);
-        assert_eq!(engine.tensor_shape("Y"), &[2, 3]);
+    /// Get the memory layout of a tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Tensor format, or `None` if the format is not known to this crate.
+    #[inline(always)]
+    pub fn tensor_format(&self, tensor_name: &str) -> Option<TensorFormat> {
+        self.inner.tensor_format(tensor_name)
     }
 
-    #[tokio::test]
-    async fn test_execution_context_new() {
-        let mut engine = simple_engine!();
-        assert!(ExecutionContext::new(&mut engine).await.is_ok());
-        assert!(ExecutionContext::new(&mut engine).await.is_ok());
+    /// Get the number of bytes per component of a tensor element.
+    ///
+    /// For vectorized formats, a component is a single scalar within a vector.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Number of bytes per component, or `None` if the tensor does not exist.
+    #[inline(always)]
+    pub fn tensor_bytes_per_component(&self, tensor_name: &str) -> Option<usize> {
+        self.inner.tensor_bytes_per_component(tensor_name)
     }
 
-    #[tokio::test]
-    async fn test_execution_context_enqueue() {
-        let stream = Stream::new().await.unwrap();
-        let mut engine = simple_engine!();
-        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
-        let mut io_buffers = std::collections::HashMap::from([
-            ("X", to_device!(&[2.0, 4.0], &stream)),
-            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
-        ]);
-        let mut io_buffers_ref = io_buffers
-            .iter_mut()
-            .map(|(name, buffer)| (*name, buffer))
-            .collect();
-        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+    /// Get the number of components in a tensor element.
+    ///
+    /// This is the vector width for vectorized formats, and 1 otherwise.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Number of components per element, or `None` if the tensor does not exist.
+    #[inline(always)]
+    pub fn tensor_components_per_element(&self, tensor_name: &str) -> Option<usize> {
+        self.inner.tensor_components_per_element(tensor_name)
+    }
+
+    /// Get the dimension index along which a tensor is vectorized.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Index of vectorized dimension, or `None` if the tensor is not vectorized or does not exist.
+    #[inline(always)]
+    pub fn tensor_vectorized_dim(&self, tensor_name: &str) -> Option<usize> {
+        self.inner.tensor_vectorized_dim(tensor_name)
+    }
+
+    /// Get a description of the memory layout of a tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Human readable description of the format, or `None` if the tensor does not exist.
+    #[inline(always)]
+    pub fn tensor_format_desc(&self, tensor_name: &str) -> Option<String> {
+        self.inner.tensor_format_desc(tensor_name)
+    }
+
+    /// Whether or not a tensor is an input that is required for shape inference, or an output
+    /// that is computed by shape inference.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn is_shape_inference_io(&self, tensor_name: &str) -> bool {
+        self.inner.is_shape_inference_io(tensor_name)
+    }
+
+    /// Get the number of layers in the engine.
+    ///
+    /// Layers may have been fused during the build, so this is not necessarily the number of
+    /// layers in the network definition.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn num_layers(&self) -> usize {
+        self.inner.num_layers()
+    }
+
+    /// Get the number of auxiliary streams the engine uses.
+    ///
+    /// See [`crate::BuilderConfig::set_max_aux_streams()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn num_aux_streams(&self) -> usize {
+        self.inner.num_aux_streams()
+    }
+
+    /// Whether or not the engine can be refitted.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn is_refittable(&self) -> bool {
+        self.inner.is_refittable()
+    }
+
+    /// Whether or not the engine was built from a network with an implicit batch dimension.
+    ///
+    /// TensorRT 10 and up do not support implicit batch dimensions, so this is always `false`
+    /// there.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn has_implicit_batch_dimension(&self) -> bool {
+        self.inner.has_implicit_batch_dimension()
+    }
+
+    /// Get the range of GPU architectures that the engine is compatible with.
+    ///
+    /// Requires TensorRT 8.6 or up. On older versions, this is always
+    /// [`HardwareCompatibilityLevel::None`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn hardware_compatibility_level(&self) -> HardwareCompatibilityLevel {
+        self.inner.hardware_compatibility_level()
+    }
+
+    /// Get the device the engine was deserialized on.
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.inner.device()
+    }
+
+    /// Get the amount of device memory required by an execution context.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn device_memory_size_v2(&self) -> i64 {
+        self.inner.device_memory_size_v2()
+    }
+
+    /// Get the amount of device memory required by an execution context that uses a specific
+    /// optimization profile.
+    ///
+    /// This can be less than [`Engine::device_memory_size_v2()`], which covers the worst case over
+    /// all profiles. Before TensorRT 10, this returns the worst case as well.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_index` - Index of optimization profile.
+    #[inline(always)]
+    pub fn device_memory_size_for_profile(&self, profile_index: usize) -> Result<i64> {
+        self.inner.device_memory_size_for_profile(profile_index)
+    }
+
+    /// Set the budget of device memory that may be used for weights when weight streaming is
+    /// enabled.
+    ///
+    /// The engine must have been built with the `kWEIGHT_STREAMING` flag. Requires TensorRT 10.1
+    /// or later.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Budget in bytes. Use `-1` to let TensorRT choose the budget automatically.
+    pub async fn set_weight_streaming_budget_v2(&mut self, budget: i64) -> Result<()> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.set_weight_streaming_budget_v2(budget)) }.await
+    }
+
+    /// Get the current weight streaming budget in bytes.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn get_weight_streaming_budget(&self) -> i64 {
+        self.inner.get_weight_streaming_budget()
+    }
+
+    /// Get the total size in bytes of all streamable weights.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    #[inline(always)]
+    pub fn get_streamable_weights_size(&self) -> i64 {
+        self.inner.get_streamable_weights_size()
+    }
+
+    /// Allocate a device buffer for every IO tensor of the engine.
+    ///
+    /// Dynamic dimensions of inputs are resolved using the opt shape of the first optimization
+    /// profile. Use [`Engine::allocate_io_buffers_for_profile()`] to use another profile.
+    ///
+    /// Shape tensor inputs, which live on the host, and outputs with data-dependent shapes are
+    /// skipped. Use [`ExecutionContext::set_shape_input()`] and
+    /// [`ExecutionContext::set_output_allocator()`] for those respectively.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - CUDA stream to allocate on.
+    pub async fn allocate_io_buffers(&mut self, stream: &Stream) -> Result<IoBuffers> {
+        self.allocate_io_buffers_for_profile(0, stream).await
+    }
+
+    /// Allocate a device buffer for every IO tensor of the engine, using the opt shapes of an
+    /// optimization profile.
+    ///
+    /// Refer to [`Engine::allocate_io_buffers()`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_index` - Index of optimization profile.
+    /// * `stream` - CUDA stream to allocate on.
+    pub async fn allocate_io_buffers_for_profile(
+        &mut self,
+        profile_index: usize,
+        stream: &Stream,
+    ) -> Result<IoBuffers> {
+        // SAFETY: The future is awaited right away and not leaked.
+        let io_buffer_sizes = unsafe {
+            Future::new(move || self.inner.io_buffer_sizes(profile_index, stream.inner()))
+        }
+        .await?;
+        let mut buffers = std::collections::HashMap::with_capacity(io_buffer_sizes.len());
+        for (tensor_name, num_bytes) in io_buffer_sizes {
+            buffers.insert(tensor_name, DeviceBuffer::new(num_bytes, stream).await);
+        }
+        Ok(IoBuffers::new(buffers))
+    }
+}
+
+/// Context for executing inference using an engine.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+pub struct ExecutionContext<'engine> {
+    inner: InnerExecutionContext<'engine>,
+    device_memory: Option<std::sync::Arc<DeviceBuffer<u8>>>,
+    input_consumed_event: Option<Event>,
+    aux_streams: Vec<Stream>,
+    timing_events: Option<(Event, Event)>,
+}
+
+impl ExecutionContext<'static> {
+    /// Create an execution context from an [`Engine`].
+    ///
+    /// This is the owned version of [`ExecutionContext::new()`]. It consumes the engine. In
+    /// exchange, it produces an execution context with a `'static` lifetime.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Parent engine.
+    pub async fn from_engine(engine: Engine) -> Result<Self> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                InnerExecutionContext::from_engine(engine.inner)
+                    .map(ExecutionContext::from_inner_owned)
+            })
+        }
+        .await
+    }
+
+    /// Create multiple execution contexts from an [`Engine`].
+    ///
+    /// This is the owned version of [`ExecutionContext::new()`]. It consumes the engine. In
+    /// exchange, it produces a set of execution contexts with a `'static` lifetime.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Parent engine.
+    /// * `num` - Number of execution contexsts to produce.
+    pub async fn from_engine_many(engine: Engine, num: usize) -> Result<Vec<Self>> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                Ok(InnerExecutionContext::from_engine_many(engine.inner, num)?
+                    .into_iter()
+                    .map(Self::from_inner_owned)
+                    .collect())
+            })
+        }
+        .await
+    }
+
+    /// Create [`ExecutionContext`] from its inner object.
+    fn from_inner_owned(inner: InnerExecutionContext<'static>) -> Self {
+        Self {
+            inner,
+            device_memory: None,
+            input_consumed_event: None,
+            aux_streams: Vec::new(),
+            timing_events: None,
+        }
+    }
+}
+
+impl<'engine> ExecutionContext<'engine> {
+    /// Create [`ExecutionContext`] from its inner object.
+    fn from_inner(inner: InnerExecutionContext<'engine>) -> Self {
+        Self {
+            inner,
+            device_memory: None,
+            input_consumed_event: None,
+            aux_streams: Vec::new(),
+            timing_events: None,
+        }
+    }
+
+    /// Create an execution context from an [`Engine`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html#ac7a34cf3b59aa633a35f66f07f22a617)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Parent engine.
+    pub async fn new(engine: &mut Engine) -> Result<ExecutionContext> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                InnerExecutionContext::new(&mut engine.inner).map(ExecutionContext::from_inner)
+            })
+        }
+        .await
+    }
+
+    /// Create an execution context from an [`Engine`] without any device memory allocated.
+    ///
+    /// The memory must be set with [`ExecutionContext::set_device_memory()`] before executing
+    /// inference. This allows activation memory to be shared or pooled across contexts.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Parent engine.
+    pub async fn new_without_device_memory(engine: &mut Engine) -> Result<ExecutionContext> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                InnerExecutionContext::new_without_device_memory(&mut engine.inner)
+                    .map(ExecutionContext::from_inner)
+            })
+        }
+        .await
+    }
+
+    /// Create an execution context from an [`Engine`] with the given strategy for allocating
+    /// device memory.
+    ///
+    /// With [`ExecutionContextAllocationStrategy::UserManaged`], the memory must be set with
+    /// [`ExecutionContext::set_device_memory()`] before executing inference. The
+    /// [`ExecutionContextAllocationStrategy::OnProfileChange`] strategy requires TensorRT 10.1 or
+    /// up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Parent engine.
+    /// * `strategy` - Strategy for allocating device memory.
+    pub async fn new_with_allocation_strategy(
+        engine: &mut Engine,
+        strategy: ExecutionContextAllocationStrategy,
+    ) -> Result<ExecutionContext> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                InnerExecutionContext::new_with_allocation_strategy(&mut engine.inner, strategy)
+                    .map(ExecutionContext::from_inner)
+            })
+        }
+        .await
+    }
+
+    /// Set the device memory for use by the execution context.
+    ///
+    /// The buffer must be at least [`Engine::device_memory_size_v2()`] bytes large. The context
+    /// keeps the buffer alive until it is dropped or the memory is replaced. Since the buffer is
+    /// shared, it can be used by multiple execution contexts.
+    ///
+    /// When replacing memory that was set before, this waits for the inference that this context
+    /// queued on its streams to complete, so that it does not outlive the previous memory. Work on
+    /// other streams is not waited for.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that no two execution contexts that use the same buffer execute
+    /// inference at the same time, since they would overwrite each other's activations.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Device memory to use.
+    pub async unsafe fn set_device_memory(
+        &mut self,
+        buffer: std::sync::Arc<DeviceBuffer<u8>>,
+    ) -> Result<()> {
+        let replace = self.device_memory.is_some();
+        let inner = &mut self.inner;
+        let buffer_ref = &buffer;
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                if replace {
+                    inner.synchronize_streams()?;
+                }
+                // SAFETY: This is safe because we store the buffer in the context, which means it
+                // lives as long as the inner context, or until it is replaced after all work that
+                // used it has completed. Exclusive use is up to the caller.
+                unsafe { inner.set_device_memory(buffer_ref.inner()) }
+            })
+        }
+        .await?;
+        self.device_memory = Some(buffer);
+        Ok(())
+    }
+
+    /// Set the name of the execution context.
+    ///
+    /// The name shows up in TensorRT log messages. With the `nvtx` feature enabled, every
+    /// inference is also wrapped in an NVTX range with this name, so that profiler traces of
+    /// applications that run multiple models show which model each kernel belongs to. Contexts
+    /// without a name use the name of the engine instead.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of execution context.
+    #[inline(always)]
+    pub fn set_name(&mut self, name: &str) {
+        self.inner.set_name(name)
+    }
+
+    /// Get the name of the execution context.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    #[inline(always)]
+    pub fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    /// Set the maximum amount of L2 cache that may be persisted for activations.
+    ///
+    /// Requires TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of persistent cache limit in bytes.
+    #[inline(always)]
+    pub fn set_persistent_cache_limit(&mut self, size: usize) -> Result<()> {
+        self.inner.set_persistent_cache_limit(size)
+    }
+
+    /// Get the maximum amount of L2 cache that may be persisted for activations.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    #[inline(always)]
+    pub fn persistent_cache_limit(&self) -> usize {
+        self.inner.persistent_cache_limit()
+    }
+
+    /// Set the auxiliary streams that TensorRT may use to run layers in parallel.
+    ///
+    /// The number of auxiliary streams that an engine uses is limited at build time with
+    /// [`crate::BuilderConfig::set_max_aux_streams()`]. TensorRT synchronizes the auxiliary
+    /// streams with the main stream passed to [`ExecutionContext::enqueue()`]. The context keeps
+    /// the streams alive. Requires TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - Auxiliary streams.
+    pub fn set_aux_streams(&mut self, streams: Vec<Stream>) -> Result<()> {
+        let streams_inner = streams.iter().map(Stream::inner).collect::<Vec<_>>();
+        // SAFETY: This is safe because the streams are kept alive by the context, and are only
+        // dropped after they have been replaced.
+        unsafe {
+            self.inner.set_aux_streams(&streams_inner)?;
+        }
+        self.aux_streams = streams;
+        Ok(())
+    }
+
+    /// Set the event that TensorRT records once it has consumed the input buffers.
+    ///
+    /// After [`ExecutionContext::enqueue()`], the input buffers may be overwritten as soon as the
+    /// event has completed, even if inference itself is still running. This allows staging the
+    /// next inputs while the current inference runs. The context keeps the event alive. Pass
+    /// `None` to remove the event.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Event to record, or `None`.
+    pub fn set_input_consumed_event(&mut self, event: Option<Event>) -> Result<()> {
+        // SAFETY: This is safe because the event is kept alive by the context, and is only
+        // dropped after it has been replaced.
+        unsafe {
+            self.inner
+                .set_input_consumed_event(event.as_ref().map(Event::inner))?;
+        }
+        self.input_consumed_event = event;
+        Ok(())
+    }
+
+    /// Get the event that TensorRT records once it has consumed the input buffers.
+    ///
+    /// See [`ExecutionContext::set_input_consumed_event()`].
+    #[inline(always)]
+    pub fn input_consumed_event(&self) -> Option<&Event> {
+        self.input_consumed_event.as_ref()
+    }
+
+    /// Set the output allocator for an output tensor.
+    ///
+    /// This is required for output tensors with data-dependent shapes, since their memory cannot
+    /// be allocated up front. Do not pass a buffer for the output tensor to
+    /// [`ExecutionContext::enqueue()`]; TensorRT will request memory from the allocator instead
+    /// and report the final shape through [`OutputAllocator::notify_shape()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `allocator` - Output allocator to use.
+    #[inline(always)]
+    pub fn set_output_allocator(
+        &mut self,
+        tensor_name: &str,
+        allocator: impl OutputAllocator + 'static,
+    ) -> Result<()> {
+        self.inner
+            .set_output_allocator(tensor_name, Box::new(allocator))
+    }
+
+    /// Set the debug listener that receives the values of debug tensors.
+    ///
+    /// Debug tensors require TensorRT 10 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Debug listener to use.
+    #[inline(always)]
+    pub fn set_debug_listener(&mut self, listener: impl DebugListener + 'static) -> Result<()> {
+        self.inner.set_debug_listener(Box::new(listener))
+    }
+
+    /// Enable or disable reporting a debug tensor to the debug listener.
+    ///
+    /// The tensor must have been marked with [`crate::NetworkDefinition::mark_debug()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of debug tensor.
+    /// * `enabled` - Whether or not to report the tensor.
+    #[inline(always)]
+    pub fn set_tensor_debug_state(&mut self, tensor_name: &str, enabled: bool) -> Result<()> {
+        self.inner.set_tensor_debug_state(tensor_name, enabled)
+    }
+
+    /// Enable or disable reporting all debug tensors to the debug listener.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether or not to report debug tensors.
+    #[inline(always)]
+    pub fn set_all_tensors_debug_state(&mut self, enabled: bool) -> Result<()> {
+        self.inner.set_all_tensors_debug_state(enabled)
+    }
+
+    /// Set whether buffers are checked to be large enough for the tensors they are bound to.
+    ///
+    /// When enabled, binding a buffer with [`ExecutionContext::bind_tensor()`],
+    /// [`ExecutionContext::enqueue()`] and similar functions fails with
+    /// [`crate::Error::BufferTooSmall`] if the buffer is smaller than the tensor, based on its
+    /// current shape and data type. Tensors of which the shape is not yet known are not checked.
+    ///
+    /// Undersized buffers otherwise result in out-of-bounds reads and writes on the device. The
+    /// check is enabled by default in debug builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to check buffer sizes.
+    #[inline(always)]
+    pub fn set_validate_buffer_sizes(&mut self, enabled: bool) {
+        self.inner.set_validate_buffer_sizes(enabled)
+    }
+
+    /// Get whether buffers are checked to be large enough for the tensors they are bound to.
+    ///
+    /// Refer to [`ExecutionContext::set_validate_buffer_sizes()`].
+    #[inline(always)]
+    pub fn validate_buffer_sizes(&self) -> bool {
+        self.inner.validate_buffer_sizes()
+    }
+
+    /// Set the shape of an input tensor.
+    ///
+    /// This is required for inputs with dynamic dimensions before executing inference. The shape
+    /// must be within the bounds of the optimization profile in use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Input tensor name.
+    /// * `dims` - Shape of input tensor.
+    #[inline(always)]
+    pub fn set_input_shape(&mut self, tensor_name: &str, dims: impl Into<Dims>) -> Result<()> {
+        self.inner.set_input_shape(tensor_name, dims)
+    }
+
+    /// Set the values of a shape tensor input.
+    ///
+    /// Shape tensors (for example the target size of a `Resize` layer) live in host memory. The
+    /// values are copied and kept alive by the context, so they do not need to outlive this call.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Shape tensor input name.
+    /// * `values` - Values of shape tensor.
+    #[inline(always)]
+    pub fn set_shape_input(&mut self, tensor_name: &str, values: &[i64]) -> Result<()> {
+        self.inner.set_shape_input(tensor_name, values)
+    }
+
+    /// Run shape inference for all tensors whose shapes can be computed.
+    ///
+    /// Use this before executing inference to find out which inputs still need their shape set
+    /// with [`ExecutionContext::set_input_shape()`] or their values set with
+    /// [`ExecutionContext::set_shape_input()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Return value
+    ///
+    /// Names of input tensors with shapes or values that are not specified yet. Empty if all
+    /// shapes are known.
+    #[inline(always)]
+    pub fn infer_shapes(&mut self) -> Result<Vec<String>> {
+        self.inner.infer_shapes()
+    }
+
+    /// Whether or not the shapes and values of all inputs required for inference are specified.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    pub fn all_shapes_specified(&mut self) -> bool {
+        self.infer_shapes()
+            .map(|missing| missing.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Get the shape of a tensor in this execution context.
+    ///
+    /// Unlike [`Engine::tensor_shape()`], this reflects the input shapes set on the context.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn tensor_shape(&self, tensor_name: &str) -> Dims {
+        self.inner.tensor_shape(tensor_name)
+    }
+
+    /// Bind a buffer to an IO tensor.
+    ///
+    /// The binding is used by subsequent calls to [`ExecutionContext::enqueue()`] for IO tensors
+    /// that are not in `io_buffers`.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of IO tensor.
+    /// * `buffer` - Buffer to bind.
+    #[inline(always)]
+    pub unsafe fn bind_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.inner.bind_tensor(tensor_name, buffer.inner_mut())
+    }
+
+    /// Bind a buffer to an input tensor.
+    ///
+    /// Unlike [`ExecutionContext::bind_tensor()`], this only requires shared access to the buffer,
+    /// so the same input buffer can be bound to multiple execution contexts that execute
+    /// concurrently.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive, and is not written to, until all
+    /// inference that uses the binding has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of input tensor.
+    /// * `buffer` - Buffer to bind.
+    #[inline(always)]
+    pub unsafe fn bind_input<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.inner.bind_input(tensor_name, buffer.inner())
+    }
+
+    /// Bind a buffer to an output tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `buffer` - Buffer to bind.
+    #[inline(always)]
+    pub unsafe fn bind_output<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.inner.bind_output(tensor_name, buffer.inner_mut())
+    }
+
+    /// Bind part of a buffer to an IO tensor.
+    ///
+    /// This allows multiple IO tensors to share a single pooled device allocation. TensorRT
+    /// requires tensor addresses to be aligned, so `byte_offset` must be a multiple of 256 bytes.
+    /// The tensor must fit in the buffer after the offset, which is checked against the current
+    /// shape of the tensor. The shape must therefore be known when binding, so for networks with
+    /// dynamic shapes, input shapes must be set first.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed, and that the shape of the tensor does not grow beyond the bound
+    /// region after binding.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of IO tensor.
+    /// * `buffer` - Buffer that contains the tensor.
+    /// * `byte_offset` - Offset of the tensor in the buffer in bytes.
+    #[inline(always)]
+    pub unsafe fn bind_tensor_with_offset<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut DeviceBuffer<T>,
+        byte_offset: usize,
+    ) -> Result<()> {
+        self.inner
+            .bind_tensor_with_offset(tensor_name, buffer.inner_mut(), byte_offset)
+    }
+
+    /// Whether or not the device supports binding page-locked host memory to IO tensors with
+    /// [`ExecutionContext::bind_host_tensor()`].
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__DEVICE.html)
+    #[inline(always)]
+    pub fn supports_host_tensors(&self) -> bool {
+        self.inner.supports_host_tensors()
+    }
+
+    /// Bind a page-locked host buffer directly to an IO tensor, without copying it to the device.
+    ///
+    /// This avoids copies on platforms where host and device share memory, such as Jetson. On
+    /// discrete GPUs, the device accesses the buffer over PCIe during inference, which is usually
+    /// slower than copying it first. Use [`ExecutionContext::supports_host_tensors()`] to check
+    /// whether the device supports this.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__MEMORY.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of IO tensor.
+    /// * `buffer` - Page-locked host buffer to bind.
+    #[inline(always)]
+    pub unsafe fn bind_host_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::HostBuffer<T>,
+    ) -> Result<()> {
+        self.inner.bind_host_tensor(tensor_name, buffer.inner_mut())
+    }
+
+    /// Bind a page-locked host buffer to an IO tensor that TensorRT places on the host.
+    ///
+    /// Some IO tensors, such as shape tensor outputs, are located on the host instead of the
+    /// device (see [`ExecutionContext::tensor_location()`]). These must be bound to host memory,
+    /// which TensorRT reads or writes directly. Unlike [`ExecutionContext::bind_host_tensor()`],
+    /// the buffer is not mapped into the address space of the device. Binding a device buffer to a
+    /// host-located tensor, or a host buffer with this function to a device-located tensor, results
+    /// in [`crate::Error::InvalidArgument`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of IO tensor.
+    /// * `buffer` - Page-locked host buffer to bind.
+    #[inline(always)]
+    pub unsafe fn bind_host_located_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::HostBuffer<T>,
+    ) -> Result<()> {
+        self.inner
+            .bind_host_located_tensor(tensor_name, buffer.inner_mut())
+    }
+
+    /// Get the location of an IO tensor.
+    ///
+    /// Tensors located on the host must be bound with
+    /// [`ExecutionContext::bind_host_located_tensor()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_cuda_engine.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn tensor_location(&self, tensor_name: &str) -> TensorLocation {
+        self.inner.tensor_location(tensor_name)
+    }
+
+    /// Asynchronously execute inference.
+    ///
+    /// Every IO tensor of the engine must be in `io_buffers`, be bound beforehand (for example with
+    /// [`ExecutionContext::bind_tensor()`]) or have an output allocator. Otherwise,
+    /// [`crate::Error::MissingBinding`] is returned. Names in `io_buffers` that are not IO tensors
+    /// of the engine result in [`crate::Error::UnknownTensor`].
+    ///
+    /// All buffers must have the same element type. Use [`ExecutionContext::enqueue_bound()`] for
+    /// engines with IO tensors of different data types.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html#a63cd95430852038ce864e17c670e0b36)
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
+    /// complete serially with respect to other operations on the same stream.
+    ///
+    /// # Thread-safety
+    ///
+    /// Calling this function from the same context with a different CUDA stream concurrently
+    /// results in undefined behavior. To perform inference concurrently in multiple streams, use
+    /// one execution context per stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `io_buffers` - Input and output buffers.
+    /// * `stream` - CUDA stream to execute on.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                device = self.inner.device(),
+                engine = %self.inner.engine_name(),
+                shapes = ?self.inner.io_tensor_shapes(),
+            )
+        )
+    )]
+    pub async fn enqueue<T: Copy>(
+        &mut self,
+        io_buffers: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
+        stream: &Stream,
+    ) -> Result<()> {
+        let mut io_buffers_inner = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer.inner_mut()))
+            .collect::<std::collections::HashMap<_, _>>();
+        #[cfg(feature = "metrics")]
+        let engine_name = self.inner.engine_name();
+        // SAFETY: The future is awaited right away and not leaked.
+        let result = unsafe {
+            Future::new(move || self.inner.enqueue(&mut io_buffers_inner, stream.inner()))
+        }
+        .await;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_enqueue(&engine_name, &result);
+        result
+    }
+
+    /// Asynchronously execute inference with separate input and output buffers.
+    ///
+    /// Unlike [`ExecutionContext::enqueue()`], this only requires shared access to the input
+    /// buffers, so the same input buffers can be used by multiple execution contexts concurrently.
+    /// Refer to [`ExecutionContext::enqueue()`] for details.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html#a63cd95430852038ce864e17c670e0b36)
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
+    /// complete serially with respect to other operations on the same stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input buffers.
+    /// * `outputs` - Output buffers.
+    /// * `stream` - CUDA stream to execute on.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                device = self.inner.device(),
+                engine = %self.inner.engine_name(),
+                shapes = ?self.inner.io_tensor_shapes(),
+            )
+        )
+    )]
+    pub async fn enqueue_with_inputs<T: Copy>(
+        &mut self,
+        inputs: &std::collections::HashMap<&str, &DeviceBuffer<T>>,
+        outputs: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
+        stream: &Stream,
+    ) -> Result<()> {
+        let inputs_inner = inputs
+            .iter()
+            .map(|(name, buffer)| (*name, buffer.inner()))
+            .collect::<std::collections::HashMap<_, _>>();
+        let mut outputs_inner = outputs
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer.inner_mut()))
+            .collect::<std::collections::HashMap<_, _>>();
+        #[cfg(feature = "metrics")]
+        let engine_name = self.inner.engine_name();
+        // SAFETY: The future is awaited right away and not leaked.
+        let result = unsafe {
+            Future::new(move || {
+                self.inner
+                    .enqueue_with_inputs(&inputs_inner, &mut outputs_inner, stream.inner())
+            })
+        }
+        .await;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_enqueue(&engine_name, &result);
+        result
+    }
+
+    /// Asynchronously execute inference with buffers of different element types.
+    ///
+    /// Each buffer in `bindings` is checked against the IO mode and data type of its tensor, so an
+    /// input that is bound as output results in [`crate::Error::InvalidArgument`] and a buffer with
+    /// the wrong element type results in [`crate::Error::DataTypeMismatch`]. Like
+    /// [`ExecutionContext::enqueue()`], every IO tensor must be bound or have an output allocator.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html#a63cd95430852038ce864e17c670e0b36)
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
+    /// complete serially with respect to other operations on the same stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindings` - Input and output buffers.
+    /// * `stream` - CUDA stream to execute on.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                device = self.inner.device(),
+                engine = %self.inner.engine_name(),
+                shapes = ?self.inner.io_tensor_shapes(),
+            )
+        )
+    )]
+    pub async fn enqueue_bound(
+        &mut self,
+        bindings: &mut IoBindings<'_>,
+        stream: &Stream,
+    ) -> Result<()> {
+        let bindings = &*bindings;
+        #[cfg(feature = "metrics")]
+        let engine_name = self.inner.engine_name();
+        // SAFETY: The future is awaited right away and not leaked.
+        let result =
+            unsafe { Future::new(move || self.inner.enqueue_bound(bindings, stream.inner())) }
+                .await;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_enqueue(&engine_name, &result);
+        result
+    }
+
+    /// Asynchronously execute inference with the buffers that are currently bound.
+    ///
+    /// Buffers must be bound beforehand, for example with [`ExecutionContext::bind_tensor()`], or
+    /// the tensor must have an output allocator. Before enqueueing, the context checks that the
+    /// shapes of all inputs are specified and that every IO tensor is bound. If not,
+    /// [`crate::Error::NotReady`] is returned, which lists the tensors that are missing.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html#a63cd95430852038ce864e17c670e0b36)
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
+    /// complete serially with respect to other operations on the same stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - CUDA stream to execute on.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                device = self.inner.device(),
+                engine = %self.inner.engine_name(),
+                shapes = ?self.inner.io_tensor_shapes(),
+            )
+        )
+    )]
+    pub async fn enqueue_prebound(&mut self, stream: &Stream) -> Result<()> {
+        #[cfg(feature = "metrics")]
+        let engine_name = self.inner.engine_name();
+        // SAFETY: The future is awaited right away and not leaked.
+        let result =
+            unsafe { Future::new(move || self.inner.enqueue_prebound(stream.inner())) }.await;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_enqueue(&engine_name, &result);
+        result
+    }
+
+    /// Execute inference and measure how long it took on the device.
+    ///
+    /// The inference is bracketed with timing events on `stream`, and the returned future resolves
+    /// once the inference has completed. The measured time only covers the work of this inference
+    /// on the device, and does not include host-side overhead or work that was queued on `stream`
+    /// before this call. Refer to [`ExecutionContext::enqueue()`] for details.
+    ///
+    /// The timing events are created on first use and reused afterwards.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    ///
+    /// # Thread-safety
+    ///
+    /// Calling this function from the same context with a different CUDA stream concurrently
+    /// results in undefined behavior. To perform inference concurrently in multiple streams, use
+    /// one execution context per stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `io_buffers` - Input and output buffers.
+    /// * `stream` - CUDA stream to execute on.
+    ///
+    /// # Return value
+    ///
+    /// Time the inference took on the device.
+    pub async fn enqueue_timed<T: Copy>(
+        &mut self,
+        io_buffers: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
+        stream: &Stream,
+    ) -> Result<std::time::Duration> {
+        let (start, end) = match self.timing_events.take() {
+            Some(timing_events) => timing_events,
+            None => (
+                Event::new_with_timing().await?,
+                Event::new_with_timing().await?,
+            ),
+        };
+        let result = async {
+            start.record(stream).await?;
+            self.enqueue(io_buffers, stream).await?;
+            end.record(stream).await?;
+            end.synchronize().await?;
+            start.elapsed_time(&end)
+        }
+        .await;
+        self.timing_events = Some((start, end));
+        let milliseconds = result?;
+        let elapsed = std::time::Duration::from_secs_f64(milliseconds.max(0.0) as f64 / 1000.0);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_gpu_latency(&self.inner.engine_name(), elapsed);
+        Ok(elapsed)
+    }
+
+    /// Run inference a number of times on zero-filled buffers to warm up the execution context.
+    ///
+    /// The first inferences of a context are usually slower than the ones after, because of lazy
+    /// initialization of libraries and kernels. Warming up moves this cost out of the first real
+    /// inference. A buffer is allocated for every IO tensor, sized from the current shapes, so all
+    /// input shapes must be specified beforehand with [`ExecutionContext::set_input_shape()`].
+    ///
+    /// The warm-up buffers are freed afterwards but remain bound, so the next inference must
+    /// provide or bind all IO tensors again.
+    ///
+    /// # Arguments
+    ///
+    /// * `iterations` - Number of inferences to run.
+    /// * `stream` - CUDA stream to execute on.
+    pub async fn warm_up(&mut self, iterations: usize, stream: &Stream) -> Result<()> {
+        let io_tensor_sizes = self.inner.io_tensor_sizes()?;
+        let mut io_buffers = std::collections::HashMap::with_capacity(io_tensor_sizes.len());
+        for (tensor_name, num_bytes) in &io_tensor_sizes {
+            let zeros = async_cuda::HostBuffer::from_slice(&vec![0_u8; *num_bytes]).await;
+            let mut buffer = DeviceBuffer::<u8>::new(*num_bytes, stream).await;
+            buffer.copy_from(&zeros, stream).await?;
+            io_buffers.insert(tensor_name.as_str(), buffer);
+        }
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(tensor_name, buffer)| (*tensor_name, buffer))
+            .collect();
+        for _ in 0..iterations {
+            self.enqueue(&mut io_buffers_ref, stream).await?;
+        }
+        stream.synchronize().await?;
+        Ok(())
+    }
+
+    /// Execute inference and wait for it to complete.
+    ///
+    /// Unlike [`ExecutionContext::enqueue()`], which returns as soon as the work has been queued,
+    /// the returned future only resolves once inference has actually completed on the device, so
+    /// the output buffers can be used right away.
+    ///
+    /// Note that this waits for all work on `stream`, including work that was queued before this
+    /// call.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_execution_context.html)
+    ///
+    /// # Thread-safety
+    ///
+    /// Calling this function from the same context with a different CUDA stream concurrently
+    /// results in undefined behavior. To perform inference concurrently in multiple streams, use
+    /// one execution context per stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `io_buffers` - Input and output buffers.
+    /// * `stream` - CUDA stream to execute on.
+    pub async fn execute<T: Copy>(
+        &mut self,
+        io_buffers: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
+        stream: &Stream,
+    ) -> Result<()> {
+        self.enqueue(io_buffers, stream).await?;
+        stream.synchronize().await?;
+        Ok(())
+    }
+
+    /// Capture inference into a CUDA graph for low-overhead replay.
+    ///
+    /// This binds the IO buffers and records a single inference into a CUDA graph, which can then
+    /// be replayed with [`ExecutionContext::launch_graph()`]. Capturing again replaces the
+    /// previously captured graph.
+    ///
+    /// Before capturing, this runs inference once on the stream and waits for it to complete,
+    /// since TensorRT may perform lazy initialization on the first inference that cannot be
+    /// captured. The output buffers therefore hold the result for the current inputs afterwards.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__GRAPH.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `io_buffers` - Input and output buffers.
+    /// * `stream` - CUDA stream to capture on.
+    pub async fn capture_cuda_graph<T: Copy>(
+        &mut self,
+        io_buffers: &mut std::collections::HashMap<&str, &mut DeviceBuffer<T>>,
+        stream: &Stream,
+    ) -> Result<()> {
+        let mut io_buffers_inner = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer.inner_mut()))
+            .collect::<std::collections::HashMap<_, _>>();
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .capture_cuda_graph(&mut io_buffers_inner, stream.inner())
+            })
+        }
+        .await
+    }
+
+    /// Launch the CUDA graph previously captured with [`ExecutionContext::capture_cuda_graph()`].
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__GRAPH.html)
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// This function exhibits stream ordered semantics. This means that it is only guaranteed to
+    /// complete serially with respect to other operations on the same stream.
+    ///
+    /// # Safety
+    ///
+    /// The graph refers to the IO buffers that were bound at capture time. The caller must make
+    /// sure that those buffers are still alive when the graph is launched.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - CUDA stream to execute on.
+    pub async unsafe fn launch_graph(&mut self, stream: &Stream) -> Result<()> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.launch_graph(stream.inner())) }.await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cpp::cpp;
+
+    use crate::tests::memory::*;
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_engine_device_memory_size_for_profile() {
+        let engine = simple_engine!();
+        let size = engine.device_memory_size_for_profile(0).unwrap();
+        assert!(size <= engine.device_memory_size_v2());
+        assert!(engine
+            .device_memory_size_for_profile(engine.num_optimization_profiles())
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_engine_configuration_queries() {
+        let engine = simple_engine!();
+        assert!(!engine.has_implicit_batch_dimension());
+        assert_eq!(
+            engine.hardware_compatibility_level(),
+            HardwareCompatibilityLevel::None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_engine_serialize() {
+        let engine = simple_engine!();
+        let serialized_engine = engine.serialize().unwrap();
+        let serialized_engine_bytes = serialized_engine.as_bytes();
+        assert!(serialized_engine_bytes.len() > 1800);
+        assert!(serialized_engine_bytes.len() < 2500);
+        assert_eq!(
+            &serialized_engine_bytes[..8],
+            &[102_u8, 116_u8, 114_u8, 116_u8, 0_u8, 0_u8, 0_u8, 0_u8],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_engine_serialize_with_config() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await.with_refit();
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let runtime = crate::Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        let config = SerializationConfig::new().with_flag(SerializationFlag::ExcludeWeights);
+        assert!(config.get_flag(SerializationFlag::ExcludeWeights));
+        assert!(!config.get_flag(SerializationFlag::ExcludeLeanRuntime));
+        let serialized_engine = engine.serialize_with_config(&config).unwrap();
+        assert!(!serialized_engine.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_engine_serialize_to_file() {
+        let engine = simple_engine!();
+        let plan_file = tempfile::NamedTempFile::new().unwrap();
+        engine.serialize_to_file(plan_file.path()).unwrap();
+        let serialized_engine = engine.serialize().unwrap();
+        assert_eq!(
+            std::fs::read(plan_file.path()).unwrap(),
+            serialized_engine.as_bytes(),
+        );
+        let runtime = crate::Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_from_file(plan_file.path())
+            .await
+            .unwrap();
+        assert_eq!(engine.num_io_tensors(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_engine_name() {
+        let (mut builder, mut network) = simple_network!();
+        network.set_name("simple");
+        assert_eq!(network.name(), "simple");
+        let builder_config = builder.config().await;
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let runtime = crate::Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        assert_eq!(engine.name(), "simple");
+    }
+
+    #[tokio::test]
+    async fn test_engine_capability() {
+        let engine = simple_engine!();
+        assert_eq!(engine.engine_capability(), EngineCapability::Standard);
+    }
+
+    #[tokio::test]
+    async fn test_engine_tensor_info() {
+        let engine = simple_engine!();
+        assert_eq!(engine.num_io_tensors(), 2);
+        assert_eq!(engine.io_tensor_name(0), "X");
+        assert_eq!(engine.io_tensor_name(1), "Y");
+        assert_eq!(engine.tensor_io_mode("X"), TensorIoMode::Input);
+        assert_eq!(engine.tensor_io_mode("Y"), TensorIoMode::Output);
+        assert_eq!(engine.tensor_shape("X"), &[1, 2]);
+        assert_eq!(engine.tensor_shape("Y"), &[2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_engine_io_tensors() {
+        let engine = simple_engine!();
+        let io_tensors = engine.io_tensors();
+        assert_eq!(io_tensors.len(), 2);
+        assert_eq!(
+            io_tensors[0],
+            IoTensorDescriptor {
+                name: "X".to_string(),
+                io_mode: TensorIoMode::Input,
+                data_type: Some(DataType::Float),
+                shape: vec![1, 2],
+                format: Some(TensorFormat::Linear),
+                location: TensorLocation::Device,
+            },
+        );
+        assert_eq!(engine.inputs().len(), 1);
+        assert_eq!(engine.outputs()[0].name, "Y");
+        assert_eq!(engine.outputs()[0].shape, &[2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_engine_tensor_format() {
+        let engine = simple_engine!();
+        assert_eq!(engine.tensor_format("X"), Some(TensorFormat::Linear));
+        assert_eq!(engine.tensor_bytes_per_component("X"), Some(4));
+        assert_eq!(engine.tensor_components_per_element("X"), Some(1));
+        assert_eq!(engine.tensor_vectorized_dim("X"), None);
+    }
+
+    #[tokio::test]
+    async fn test_engine_introspection() {
+        let engine = simple_engine!();
+        assert!(!engine.tensor_shape("X").is_dynamic());
+        assert_eq!(engine.num_optimization_profiles(), 1);
+        assert!(engine.num_layers() > 0);
+        assert!(!engine.is_refittable());
+        assert!(!engine.is_shape_inference_io("X"));
+        assert!(engine.tensor_format_desc("X").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_engine_profile_shape() {
+        let engine = simple_engine!();
+        let (min, opt, max) = engine.profile_shape("X", 0).unwrap();
+        assert_eq!(min, &[1, 2]);
+        assert_eq!(opt, &[1, 2]);
+        assert_eq!(max, &[1, 2]);
+        assert!(engine.profile_shape("X", 1).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engine_profile_shape_values() {
+        let engine = simple_engine!();
+        assert!(engine.profile_shape_values("X", 0).is_none());
+        assert!(engine.profile_min_shape_values("Y", 0).is_none());
+        assert!(engine.profile_max_shape_values("X", 1).is_none());
+    }
+
+    /// Engine with a float input `X`, that is reshaped to the values of the shape tensor input `S`
+    /// of type int32, and an output `Y`.
+    macro_rules! shape_tensor_engine {
+        () => {{
+            let mut builder = crate::Builder::new().await.unwrap();
+            let mut network = builder
+                .network_definition(crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+            {
+                let input = network.add_input("X", DataType::Float, &[4]).unwrap();
+                let shape = network.add_input("S", DataType::Int32, &[2]).unwrap();
+                let mut reshape = network.add_shuffle(&input, None).unwrap();
+                let reshape_internal = reshape.as_mut_ptr();
+                let shape_internal = shape.as_ptr();
+                // Reshape to the values of `S`, which makes it a shape tensor input.
+                cpp!(unsafe [
+                    reshape_internal as "void*",
+                    shape_internal as "const void*"
+                ] {
+                    ((ILayer*) reshape_internal)->setInput(1, *((ITensor*) shape_internal));
+                });
+                let mut output = reshape.output(0);
+                output.set_name("Y");
+                network.mark_output(&output);
+            }
+            let mut optimization_profile = builder.optimization_profile().unwrap();
+            optimization_profile
+                .try_set_min_shape_values("S", &[1, 4])
+                .unwrap();
+            optimization_profile
+                .try_set_opt_shape_values("S", &[2, 2])
+                .unwrap();
+            optimization_profile
+                .try_set_max_shape_values("S", &[4, 1])
+                .unwrap();
+            let builder_config = builder
+                .config()
+                .await
+                .with_optimization_profile(optimization_profile)
+                .unwrap();
+            let plan = builder
+                .build_serialized_network(&mut network, builder_config)
+                .await
+                .unwrap();
+            crate::Runtime::new()
+                .await
+                .deserialize_engine_from_plan(&plan)
+                .await
+                .unwrap()
+        }};
+    }
+
+    #[tokio::test]
+    async fn test_engine_profile_shape_values_shape_tensor() {
+        let engine = shape_tensor_engine!();
+        assert!(engine.is_shape_inference_io("S"));
+        assert_eq!(engine.profile_min_shape_values("S", 0), Some(vec![1, 4]));
+        assert_eq!(engine.profile_opt_shape_values("S", 0), Some(vec![2, 2]));
+        assert_eq!(engine.profile_max_shape_values("S", 0), Some(vec![4, 1]));
+        assert_eq!(
+            engine.profile_shape_values("S", 0),
+            Some((vec![1, 4], vec![2, 2], vec![4, 1]))
+        );
+        assert!(engine.profile_shape_values("X", 0).is_none());
+        assert!(engine.profile_shape_values("S", 1).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engine_allocate_io_buffers() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut io_buffers = engine.allocate_io_buffers(&stream).await.unwrap();
+        assert_eq!(io_buffers.len(), 2);
+        assert_eq!(io_buffers.get("X").unwrap().num_elements(), 2 * 4);
+        assert_eq!(io_buffers.get("Y").unwrap().num_elements(), 2 * 3 * 4);
+        assert!(engine
+            .allocate_io_buffers_for_profile(1, &stream)
+            .await
+            .is_err());
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        context
+            .enqueue(&mut io_buffers.as_map_mut(), &stream)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_engine_weight_streaming_disabled() {
+        let engine = simple_engine!();
+        assert_eq!(engine.get_streamable_weights_size(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_new() {
+        let mut engine = simple_engine!();
+        assert!(ExecutionContext::new(&mut engine).await.is_ok());
+        assert!(ExecutionContext::new(&mut engine).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_set_name() {
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        context.set_name("detector");
+        assert_eq!(context.name(), "detector");
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_io_tensor_shapes() {
+        let mut engine = identity_engine!();
+        let engine_name = engine.name();
+        let context = ExecutionContext::new(&mut engine).await.unwrap();
+        assert_eq!(context.inner.engine_name(), engine_name);
+        assert_eq!(
+            context.inner.io_tensor_shapes(),
+            vec![
+                ("X".to_string(), Dims::from(vec![1, 4])),
+                ("Y".to_string(), Dims::from(vec![1, 4])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_execute() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_timed() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        for _ in 0..2 {
+            let latency = context
+                .enqueue_timed(&mut io_buffers_ref, &stream)
+                .await
+                .unwrap();
+            assert!(latency > std::time::Duration::ZERO);
+        }
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_warm_up() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = identity_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        context.warm_up(3, &stream).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[1.0, 2.0, 3.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_bind_tensor_with_offset() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        // Pool holds X at offset 0 and Y at offset 256.
+        let mut pool_host = vec![0.0_f32; 64 + 6];
+        pool_host[0] = 2.0;
+        pool_host[1] = 4.0;
+        let mut pool = to_device!(&pool_host, &stream);
+        unsafe {
+            context.bind_tensor_with_offset("X", &mut pool, 0).unwrap();
+            context
+                .bind_tensor_with_offset("Y", &mut pool, 256)
+                .unwrap();
+        }
+        let mut io_buffers = std::collections::HashMap::<&str, &mut DeviceBuffer<f32>>::new();
+        context.execute(&mut io_buffers, &stream).await.unwrap();
+        let output = to_host!(pool, &stream);
+        assert_eq!(&output[64..], &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        unsafe {
+            assert!(context
+                .bind_tensor_with_offset("Y", &mut pool, 4096)
+                .is_err());
+            assert!(matches!(
+                context.bind_tensor_with_offset("Y", &mut pool, 128),
+                Err(crate::error::Error::InvalidArgument(_)),
+            ));
+        }
+        let mut small_pool = to_device!(&[0.0_f32; 64], &stream);
+        unsafe {
+            assert!(matches!(
+                context.bind_tensor_with_offset("Y", &mut small_pool, 256),
+                Err(crate::error::Error::BufferTooSmall { .. }),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_bind_host_tensor() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        if !context.supports_host_tensors() {
+            return;
+        }
+        let mut input = async_cuda::HostBuffer::from_slice(&[2.0_f32, 4.0]).await;
+        unsafe {
+            context.bind_host_tensor("X", &mut input).unwrap();
+        }
+        let mut output = to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream);
+        let mut io_buffers = std::collections::HashMap::from([("Y", &mut output)]);
+        context.execute(&mut io_buffers, &stream).await.unwrap();
+        let output = to_host!(output, &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_bind_host_located_tensor() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        assert_eq!(context.tensor_location("X"), TensorLocation::Device);
+        let mut input = async_cuda::HostBuffer::from_slice(&[2.0_f32, 4.0]).await;
+        assert!(matches!(
+            unsafe { context.bind_host_located_tensor("X", &mut input) },
+            Err(crate::Error::InvalidArgument(_))
+        ));
+        let mut input = to_device!(&[2.0, 4.0], &stream);
+        unsafe {
+            context.bind_tensor("X", &mut input).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_new_without_device_memory() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let device_memory_size = engine.device_memory_size_v2() as usize;
+        let device_memory = DeviceBuffer::<u8>::new(device_memory_size.max(1), &stream).await;
+        let mut context = ExecutionContext::new_without_device_memory(&mut engine)
+            .await
+            .unwrap();
+        // SAFETY: The buffer is used by this context only.
+        unsafe {
+            context
+                .set_device_memory(std::sync::Arc::new(device_memory))
+                .await
+                .unwrap();
+        }
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_set_device_memory_too_small() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let device_memory_size = engine.device_memory_size_v2() as usize;
+        if device_memory_size == 0 {
+            return;
+        }
+        let device_memory = DeviceBuffer::<u8>::new(device_memory_size - 1, &stream).await;
+        let mut context = ExecutionContext::new_without_device_memory(&mut engine)
+            .await
+            .unwrap();
+        // SAFETY: The buffer is used by this context only.
+        assert!(matches!(
+            unsafe {
+                context
+                    .set_device_memory(std::sync::Arc::new(device_memory))
+                    .await
+            },
+            Err(crate::error::Error::InvalidArgument(_)),
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_set_device_memory_shared_and_replaced() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let device_memory_size = (engine.device_memory_size_v2() as usize).max(1);
+        let device_memory =
+            std::sync::Arc::new(DeviceBuffer::<u8>::new(device_memory_size, &stream).await);
+        let mut contexts = ExecutionContext::from_engine_many(engine, 2).await.unwrap();
+        for context in contexts.iter_mut() {
+            // SAFETY: The contexts execute inference one after the other.
+            unsafe {
+                context
+                    .set_device_memory(device_memory.clone())
+                    .await
+                    .unwrap();
+            }
+        }
+        assert_eq!(std::sync::Arc::strong_count(&device_memory), 3);
+        for context in contexts.iter_mut() {
+            let mut io_buffers = std::collections::HashMap::from([
+                ("X", to_device!(&[2.0, 4.0], &stream)),
+                ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+            ]);
+            let mut io_buffers_ref = io_buffers
+                .iter_mut()
+                .map(|(name, buffer)| (*name, buffer))
+                .collect();
+            context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+            let output = to_host!(io_buffers["Y"], &stream);
+            assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        }
+        let replacement =
+            std::sync::Arc::new(DeviceBuffer::<u8>::new(device_memory_size, &stream).await);
+        // SAFETY: The replacement is used by this context only.
+        unsafe {
+            contexts[0].set_device_memory(replacement).await.unwrap();
+        }
+        assert_eq!(std::sync::Arc::strong_count(&device_memory), 2);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_new_with_allocation_strategy() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new_with_allocation_strategy(
+            &mut engine,
+            ExecutionContextAllocationStrategy::Static,
+        )
+        .await
+        .unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_set_shape_input_not_shape_tensor() {
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        assert!(matches!(
+            context.set_shape_input("X", &[1, 2]),
+            Err(crate::Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_set_shape_input_out_of_range() {
+        let mut engine = shape_tensor_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let value = i64::from(i32::MAX) + 1;
+        match context.set_shape_input("S", &[1, value]) {
+            Err(crate::Error::InvalidArgument(message)) => {
+                assert!(message.contains("shape tensor S"));
+                assert!(message.contains(&value.to_string()));
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_infer_shapes() {
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        assert!(context.infer_shapes().unwrap().is_empty());
+        assert!(context.all_shapes_specified());
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_missing_binding() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut input = to_device!(&[2.0_f32, 4.0], &stream);
+        let mut io_buffers = std::collections::HashMap::from([("X", &mut input)]);
+        assert!(matches!(
+            context.enqueue(&mut io_buffers, &stream).await,
+            Err(crate::Error::MissingBinding(name)) if name == "Y"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_unknown_tensor() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut input = to_device!(&[2.0_f32, 4.0], &stream);
+        let mut output = to_device!(&[0.0_f32; 6], &stream);
+        let mut typo = to_device!(&[0.0_f32; 6], &stream);
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", &mut input),
+            ("Y", &mut output),
+            ("Z", &mut typo),
+        ]);
+        assert!(matches!(
+            context.enqueue(&mut io_buffers, &stream).await,
+            Err(crate::Error::UnknownTensor(name)) if name == "Z"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_prebound() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        match context.enqueue_prebound(&stream).await {
+            Err(crate::Error::NotReady {
+                unspecified_shapes,
+                missing_bindings,
+            }) => {
+                assert!(unspecified_shapes.is_empty());
+                assert_eq!(missing_bindings, vec!["X".to_string(), "Y".to_string()]);
+            }
+            result => panic!("expected NotReady, got {result:?}"),
+        }
+        let mut input = to_device!(&[2.0, 4.0], &stream);
+        let mut output = to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream);
+        unsafe {
+            context.bind_tensor("X", &mut input).unwrap();
+            context.bind_tensor("Y", &mut output).unwrap();
+        }
+        context.enqueue_prebound(&stream).await.unwrap();
+        stream.synchronize().await.unwrap();
+        let output = to_host!(output, &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_validate_buffer_sizes() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut output = to_device!(&[0.0_f32; 5], &stream);
+        context.set_validate_buffer_sizes(true);
+        assert!(context.validate_buffer_sizes());
+        unsafe {
+            assert!(matches!(
+                context.bind_tensor("Y", &mut output),
+                Err(crate::Error::BufferTooSmall {
+                    required: 24,
+                    actual: 20,
+                    ..
+                })
+            ));
+        }
+        context.set_validate_buffer_sizes(false);
+        unsafe {
+            assert!(context.bind_tensor("Y", &mut output).is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_identity_round_trip() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = identity_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[1.0_f32, -2.0, 3.5, 4.0], &stream)),
+            ("Y", to_device!(&[0.0_f32, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[1.0, -2.0, 3.5, 4.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_bind_tensor_identity_round_trip() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = identity_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut input = to_device!(&[5.0_f32, 6.0, 7.0, 8.0], &stream);
+        let mut output = to_device!(&[0.0_f32, 0.0, 0.0, 0.0], &stream);
+        unsafe {
+            context.bind_tensor("X", &mut input).unwrap();
+            context.bind_tensor("Y", &mut output).unwrap();
+        }
+        let mut io_buffers = std::collections::HashMap::<&str, &mut DeviceBuffer<f32>>::new();
+        context.execute(&mut io_buffers, &stream).await.unwrap();
+        let output = to_host!(output, &stream);
+        assert_eq!(&output, &[5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_with_shared_inputs() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine1 = identity_engine!();
+        let mut engine2 = identity_engine!();
+        let mut context1 = ExecutionContext::new(&mut engine1).await.unwrap();
+        let mut context2 = ExecutionContext::new(&mut engine2).await.unwrap();
+        let input = to_device!(&[1.0_f32, 2.0, 3.0, 4.0], &stream);
+        let inputs = std::collections::HashMap::from([("X", &input)]);
+        let mut output1 = to_device!(&[0.0_f32; 4], &stream);
+        let mut output2 = to_device!(&[0.0_f32; 4], &stream);
+        let mut outputs1 = std::collections::HashMap::from([("Y", &mut output1)]);
+        let mut outputs2 = std::collections::HashMap::from([("Y", &mut output2)]);
+        context1
+            .enqueue_with_inputs(&inputs, &mut outputs1, &stream)
+            .await
+            .unwrap();
+        context2
+            .enqueue_with_inputs(&inputs, &mut outputs2, &stream)
+            .await
+            .unwrap();
+        assert_eq!(&to_host!(output1, &stream), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(&to_host!(output2, &stream), &[1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_bind_input_output_io_mode() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = identity_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut buffer = to_device!(&[0.0_f32; 4], &stream);
+        unsafe {
+            assert!(context.bind_input("X", &buffer).is_ok());
+            assert!(context.bind_input("Y", &buffer).is_err());
+            assert!(context.bind_output("X", &mut buffer).is_err());
+            assert!(context.bind_output("Y", &mut buffer).is_ok());
+            assert!(matches!(
+                context.bind_input("Z", &buffer),
+                Err(crate::Error::UnknownTensor(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_bound_mixed_types() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let mut network =
+            builder.network_definition(crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        {
+            let input_float = network
+                .add_input("X", crate::DataType::Float, &[1, 4])
+                .unwrap();
+            let input_int = network
+                .add_input("I", crate::DataType::Int32, &[1, 4])
+                .unwrap();
+            let identity_float = network.add_shuffle(&input_float, None).unwrap();
+            let mut output_float = identity_float.output(0);
+            output_float.set_name("Y");
+            network.mark_output(&output_float);
+            let identity_int = network.add_shuffle(&input_int, None).unwrap();
+            let mut output_int = identity_int.output(0);
+            output_int.set_name("J");
+            network.mark_output(&output_int);
+        }
+        let builder_config = builder.config().await;
+        let plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let mut engine = crate::Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&plan)
+            .await
+            .unwrap();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let stream = Stream::new().await.unwrap();
+        let input_float = to_device!(&[1.0_f32, 2.0, 3.0, 4.0], &stream);
+        let input_int = to_device!(&[5_i32, 6, 7, 8], &stream);
+        let mut output_float = to_device!(&[0.0_f32; 4], &stream);
+        let mut output_int = to_device!(&[0_i32; 4], &stream);
+        let mut bindings = IoBindings::new()
+            .with_input("X", &input_float)
+            .with_input("I", &input_int)
+            .with_output("Y", &mut output_float)
+            .with_output("J", &mut output_int);
+        assert_eq!(bindings.len(), 4);
+        context.enqueue_bound(&mut bindings, &stream).await.unwrap();
+        assert_eq!(to_host!(output_float, &stream), &[1.0_f32, 2.0, 3.0, 4.0]);
+        assert_eq!(to_host!(output_int, &stream), &[5_i32, 6, 7, 8]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_enqueue_bound_data_type_mismatch() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = identity_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let input = to_device!(&[0_i32; 4], &stream);
+        let mut output = to_device!(&[0.0_f32; 4], &stream);
+        let mut bindings = IoBindings::new()
+            .with_input("X", &input)
+            .with_output("Y", &mut output);
+        assert!(matches!(
+            context.enqueue_bound(&mut bindings, &stream).await,
+            Err(crate::Error::DataTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cuda_error() {
+        assert!(matches!(
+            crate::ffi::sync::engine::cuda_error(1),
+            crate::Error::Cuda(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_capture_cuda_graph() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context
+            .capture_cuda_graph(&mut io_buffers_ref, &stream)
+            .await
+            .unwrap();
+        unsafe {
+            context.launch_graph(&stream).await.unwrap();
+        }
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_output_allocator() {
+        struct TestOutputAllocator {
+            memory: *mut std::ffi::c_void,
+            shapes: std::sync::Arc<std::sync::Mutex<Vec<(String, Vec<i64>)>>>,
+        }
+
+        unsafe impl Send for TestOutputAllocator {}
+
+        impl OutputAllocator for TestOutputAllocator {
+            fn reallocate_output(
+                &mut self,
+                _tensor_name: &str,
+                _current_memory: *mut std::ffi::c_void,
+                size: usize,
+                _alignment: usize,
+            ) -> *mut std::ffi::c_void {
+                let memory = self.memory;
+                self.memory = cpp!(unsafe [
+                    memory as "void*",
+                    size as "std::size_t"
+                ] -> *mut std::ffi::c_void as "void*" {
+                    cudaFree(memory);
+                    void* newMemory = nullptr;
+                    cudaMalloc(&newMemory, size);
+                    return newMemory;
+                });
+                self.memory
+            }
+
+            fn notify_shape(&mut self, tensor_name: &str, dims: &[i64]) {
+                self.shapes
+                    .lock()
+                    .unwrap()
+                    .push((tensor_name.to_string(), dims.to_vec()));
+            }
+        }
+
+        impl Drop for TestOutputAllocator {
+            fn drop(&mut self) {
+                let memory = self.memory;
+                cpp!(unsafe [memory as "void*"] {
+                    cudaFree(memory);
+                });
+            }
+        }
+
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let shapes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        context
+            .set_output_allocator(
+                "Y",
+                TestOutputAllocator {
+                    memory: std::ptr::null_mut(),
+                    shapes: shapes.clone(),
+                },
+            )
+            .unwrap();
+        let mut input = to_device!(&[2.0, 4.0], &stream);
+        let mut io_buffers = std::collections::HashMap::from([("X", &mut input)]);
+        context.enqueue(&mut io_buffers, &stream).await.unwrap();
+        stream.synchronize().await.unwrap();
+        assert_eq!(
+            shapes.lock().unwrap().as_slice(),
+            &[("Y".to_string(), vec![2, 3])],
+        );
+        assert_eq!(context.tensor_shape("Y"), &[2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_debug_listener() {
+        struct TestDebugListener {
+            tensors: std::sync::Arc<std::sync::Mutex<Vec<(String, Vec<i64>)>>>,
+        }
+
+        impl crate::DebugListener for TestDebugListener {
+            fn process_debug_tensor(
+                &mut self,
+                tensor: crate::DebugTensor,
+                _stream: *mut std::ffi::c_void,
+            ) -> bool {
+                self.tensors
+                    .lock()
+                    .unwrap()
+                    .push((tensor.name.to_string(), tensor.shape.to_vec()));
+                true
+            }
+        }
+
+        let (mut builder, mut network) = simple_network!();
+        let output = network.output(0);
+        network.mark_debug(&output).unwrap();
+        assert!(network.is_debug_tensor(&output));
+        let builder_config = builder.config().await;
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let runtime = crate::Runtime::new().await;
+        let mut engine = runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+
+        let stream = Stream::new().await.unwrap();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        let tensors = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        context
+            .set_debug_listener(TestDebugListener {
+                tensors: tensors.clone(),
+            })
+            .unwrap();
+        context.set_tensor_debug_state("Y", true).unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await.unwrap();
+        assert_eq!(
+            tensors.lock().unwrap().as_slice(),
+            &[("Y".to_string(), vec![2, 3])],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_input_consumed_event() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        context
+            .set_input_consumed_event(Some(Event::new().await.unwrap()))
+            .unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.enqueue(&mut io_buffers_ref, &stream).await.unwrap();
+        let event = context.input_consumed_event().unwrap();
+        event.synchronize().await.unwrap();
+        assert!(event.query().unwrap());
+        stream.synchronize().await.unwrap();
+        let output = to_host!(io_buffers["Y"], &stream);
+        assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_context_aux_streams() {
+        let stream = Stream::new().await.unwrap();
+        let mut engine = simple_engine!();
+        let mut context = ExecutionContext::new(&mut engine).await.unwrap();
+        context.set_persistent_cache_limit(0).unwrap();
+        assert_eq!(context.persistent_cache_limit(), 0);
+        context
+            .set_aux_streams(vec![Stream::new().await.unwrap()])
+            .unwrap();
+        let mut io_buffers = std::collections::HashMap::from([
+            ("X", to_device!(&[2.0, 4.0], &stream)),
+            ("Y", to_device!(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0], &stream)),
+        ]);
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await.unwrap();
         let output = to_host!(io_buffers["Y"], &stream);
         assert_eq!(&output, &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
     }
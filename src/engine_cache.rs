@@ -0,0 +1,196 @@
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::device::compute_capability;
+use crate::executor::Future;
+use crate::ffi::memory::HostBuffer;
+use crate::ffi::version::{runtime_tensorrt_version, tensorrt_version};
+use crate::onnx::{build_engine_from_onnx, BuildOptions};
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Extension of cached plan files.
+const PLAN_EXTENSION: &str = "engine";
+
+/// Counter that makes the names of temporary plan files unique within the process.
+static TEMPORARY_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Cache of engines built from ONNX models, stored as plan files in a directory.
+///
+/// Building an engine can take minutes, so applications usually build it on first run and load it
+/// afterwards. Since plans only work with the GPU architecture and TensorRT version that built
+/// them, each plan is stored under a key derived from the ONNX model, the build options, the
+/// compute capability of the current device and the TensorRT version. A change to any of these
+/// results in a new build.
+///
+/// Stale plans are never removed. Use [`EngineCache::clear()`] to remove all cached plans.
+pub struct EngineCache {
+    directory: PathBuf,
+}
+
+impl EngineCache {
+    /// Create a new [`EngineCache`].
+    ///
+    /// The directory is created when the first plan is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - Directory to store plans in.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Get the directory that plans are stored in.
+    #[inline(always)]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Get the cached plan for an ONNX model, or build and store it if there is none.
+    ///
+    /// Plans that cannot be read are rebuilt.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Serialized ONNX model.
+    /// * `options` - Build options.
+    ///
+    /// # Return value
+    ///
+    /// Serialized engine.
+    pub async fn get_or_build(&self, model: &[u8], options: &BuildOptions) -> Result<HostBuffer> {
+        let path = self.path(model, options).await?;
+        let path_ref = &path;
+        // SAFETY: The future is awaited right away and not leaked.
+        if let Ok(plan) = unsafe { Future::new(move || std::fs::read(path_ref)) }.await {
+            tracing::debug!(path = %path.display(), "loaded engine from cache");
+            return Ok(HostBuffer::from(plan));
+        }
+        tracing::info!(path = %path.display(), "engine not in cache, building");
+        let plan = build_engine_from_onnx(model, options.clone()).await?;
+        let plan_bytes = plan.as_bytes();
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.store(path_ref, plan_bytes)) }.await?;
+        Ok(plan)
+    }
+
+    /// Get the path of the cached plan for an ONNX model, whether it exists or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - Serialized ONNX model.
+    /// * `options` - Build options.
+    pub async fn path(&self, model: &[u8], options: &BuildOptions) -> Result<PathBuf> {
+        let device = async_cuda::Device::get().await?;
+        let (major, minor) = compute_capability(device).await?;
+        // All fields are written explicitly as little-endian bytes, since the output of `Hash`
+        // implementations is not guaranteed to be stable across Rust versions.
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(&(model.len() as u64).to_le_bytes());
+        hasher.write(model);
+        options.write_cache_key(&mut hasher);
+        hasher.write(&major.to_le_bytes());
+        hasher.write(&minor.to_le_bytes());
+        for version in [tensorrt_version(), runtime_tensorrt_version()] {
+            hasher.write(&version.major.to_le_bytes());
+            hasher.write(&version.minor.to_le_bytes());
+            hasher.write(&version.patch.to_le_bytes());
+        }
+        Ok(self
+            .directory
+            .join(format!("{:016x}.{PLAN_EXTENSION}", hasher.finish())))
+    }
+
+    /// Remove all cached plans.
+    pub fn clear(&self) -> Result<()> {
+        let entries = match std::fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == PLAN_EXTENSION)
+            {
+                std::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write plan to the cache.
+    ///
+    /// The plan is written to a temporary file first and then moved into place, so that other
+    /// processes never observe a partially written plan. The name of the temporary file is unique
+    /// to the process and the call, so that concurrent stores do not write to the same file.
+    fn store(&self, path: &Path, plan: &[u8]) -> Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        let temporary_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed),
+        ));
+        std::fs::write(&temporary_path, plan)?;
+        std::fs::rename(&temporary_path, path)?;
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a hasher.
+///
+/// Unlike [`std::collections::hash_map::DefaultHasher`], its output is stable across Rust
+/// versions, which is required for cache keys that are persisted.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::onnx::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_engine_cache_get_or_build() {
+        let directory = tempfile::tempdir().unwrap();
+        let cache = EngineCache::new(directory.path());
+        let options = BuildOptions::new();
+        let path = cache.path(SIMPLE_ONNX, &options).await.unwrap();
+        assert!(!path.exists());
+        let plan = cache.get_or_build(SIMPLE_ONNX, &options).await.unwrap();
+        assert!(path.exists());
+        let cached_plan = cache.get_or_build(SIMPLE_ONNX, &options).await.unwrap();
+        assert_eq!(plan.as_bytes(), cached_plan.as_bytes());
+        assert_ne!(
+            cache
+                .path(SIMPLE_ONNX, &BuildOptions::new().with_fp16())
+                .await
+                .unwrap(),
+            path
+        );
+        cache.clear().unwrap();
+        assert!(!path.exists());
+    }
+}
@@ -1,22 +1,170 @@
+use crate::ffi::data_type::DataType;
+use crate::ffi::version::Version;
+use crate::ffi::ErrorCode;
+
 /// An error that occurred in TensorRT.
 #[derive(Debug, Clone)]
 pub enum Error {
-    /// TensorRT error described by error message.
-    TensorRt { message: String },
+    /// TensorRT error with error code and message, along with all errors reported to the error
+    /// recorder since the previous error (see [`Error::recorded_errors()`]).
+    TensorRt(ErrorCode, String, Vec<(ErrorCode, String)>),
     /// Error in CUDA backend.
     Cuda(async_cuda::Error),
+    /// I/O error while reading or writing a file or stream.
+    Io(std::sync::Arc<std::io::Error>),
+    /// Invalid argument provided by caller.
+    InvalidArgument(String),
+    /// Data type of tensor does not match the expected data type.
+    DataTypeMismatch {
+        tensor_name: String,
+        expected: DataType,
+        actual: DataType,
+    },
+    /// Shape of tensor does not match the expected shape.
+    ShapeMismatch {
+        tensor_name: String,
+        expected: Vec<usize>,
+        actual: Vec<usize>,
+    },
+    /// Version of the TensorRT library loaded at runtime does not match the version of the headers
+    /// the crate was compiled against.
+    VersionMismatch { compiled: Version, runtime: Version },
+    /// Buffer is too small to hold the tensor bound to it.
+    BufferTooSmall {
+        tensor_name: String,
+        required: usize,
+        actual: usize,
+    },
+    /// IO tensor of the engine has no buffer bound to it.
+    MissingBinding(String),
+    /// Tensor is not an IO tensor of the engine.
+    UnknownTensor(String),
+    /// Execution context is not ready for inference.
+    NotReady {
+        /// Input tensors with shapes or values that are not specified.
+        unspecified_shapes: Vec<String>,
+        /// IO tensors without a buffer or output allocator.
+        missing_bindings: Vec<String>,
+    },
+    /// Building a network failed.
+    BuildFailed {
+        /// Error that caused the build to fail.
+        error: Box<Error>,
+        /// Last warnings and errors that TensorRT logged during the build, oldest first.
+        log: Vec<String>,
+    },
+}
+
+impl Error {
+    /// Get the errors that were reported to the TensorRT error recorder.
+    ///
+    /// # Return value
+    ///
+    /// Recorded error codes and descriptions, or an empty slice if this is not a TensorRT error.
+    pub fn recorded_errors(&self) -> &[(ErrorCode, String)] {
+        match self {
+            Error::TensorRt(_, _, errors) => errors,
+            _ => &[],
+        }
+    }
+
+    /// Get the warnings and errors that TensorRT logged during a failed build.
+    ///
+    /// # Return value
+    ///
+    /// Logged messages, oldest first, or `None` if the error is not a build failure.
+    pub fn build_log(&self) -> Option<&[String]> {
+        match self {
+            Error::BuildFailed { log, .. } => Some(log),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Error::TensorRt { message } => write!(f, "{message}"),
+            Error::TensorRt(_, message, _) => write!(f, "{message}"),
             Error::Cuda(err) => write!(f, "{err}"),
+            Error::Io(err) => write!(f, "{err}"),
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+            Error::DataTypeMismatch {
+                tensor_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "data type mismatch for tensor {tensor_name}: expected {expected:?}, got {actual:?}"
+            ),
+            Error::ShapeMismatch {
+                tensor_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "shape mismatch for tensor {tensor_name}: expected {expected:?}, got {actual:?}"
+            ),
+            Error::VersionMismatch { compiled, runtime } => write!(
+                f,
+                "TensorRT version mismatch: compiled against {compiled}, but loaded {runtime}"
+            ),
+            Error::BufferTooSmall {
+                tensor_name,
+                required,
+                actual,
+            } => write!(
+                f,
+                "buffer for tensor {tensor_name} is too small: {required} bytes required, got \
+                 {actual} bytes"
+            ),
+            Error::MissingBinding(tensor_name) => {
+                write!(f, "no buffer bound to IO tensor {tensor_name}")
+            }
+            Error::UnknownTensor(tensor_name) => {
+                write!(f, "tensor {tensor_name} is not an IO tensor of the engine")
+            }
+            Error::NotReady {
+                unspecified_shapes,
+                missing_bindings,
+            } => {
+                write!(f, "execution context is not ready for inference")?;
+                if !unspecified_shapes.is_empty() {
+                    write!(
+                        f,
+                        "; shapes not specified for input tensors: {}",
+                        unspecified_shapes.join(", ")
+                    )?;
+                }
+                if !missing_bindings.is_empty() {
+                    write!(
+                        f,
+                        "; no buffer bound to IO tensors: {}",
+                        missing_bindings.join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            Error::BuildFailed { error, log } => {
+                write!(f, "build failed: {error}")?;
+                for message in log {
+                    write!(f, "\n  {message}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Cuda(err) => Some(err),
+            Error::Io(err) => Some(err.as_ref()),
+            Error::BuildFailed { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl From<async_cuda::Error> for Error {
     #[inline]
@@ -25,7 +173,19 @@ impl From<async_cuda::Error> for Error {
     }
 }
 
-/// Create a TensorRT error from the last recorded error produced by the logger.
+impl From<std::io::Error> for Error {
+    #[inline]
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(std::sync::Arc::new(err))
+    }
+}
+
+/// Create a TensorRT error from the errors recorded by the error recorder, or the last error
+/// produced by the logger if no errors were recorded.
+///
+/// If multiple errors were recorded, the error code of the first one is used and the message
+/// contains all of them. The recorded errors remain available through
+/// [`Error::recorded_errors()`].
 ///
 /// # Thread-safety
 ///
@@ -34,10 +194,45 @@ impl From<async_cuda::Error> for Error {
 ///
 /// # Return value
 ///
-/// TensorRT error with corresponding error message.
-#[inline]
+/// TensorRT error with corresponding error code and message.
 pub(crate) fn last_error() -> Error {
-    Error::TensorRt {
-        message: crate::ffi::error::get_last_error_message(),
+    let recorded_errors = crate::ffi::error::take_recorded_errors();
+    match recorded_errors.first() {
+        Some((code, _)) => {
+            let message = recorded_errors
+                .iter()
+                .map(|(_, message)| message.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            Error::TensorRt(*code, message, recorded_errors)
+        }
+        None => Error::TensorRt(
+            ErrorCode::UnspecifiedError,
+            crate::ffi::error::get_last_error_message(),
+            Vec::new(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_recorded_errors() {
+        let recorded_errors = vec![
+            (ErrorCode::InvalidArgument, "first".to_string()),
+            (ErrorCode::InvalidState, "second".to_string()),
+        ];
+        let err = Error::TensorRt(
+            ErrorCode::InvalidArgument,
+            "first; second".to_string(),
+            recorded_errors.clone(),
+        );
+        assert_eq!(err.recorded_errors(), recorded_errors.as_slice());
+        assert_eq!(err.to_string(), "first; second");
+        assert!(Error::InvalidArgument("invalid".to_string())
+            .recorded_errors()
+            .is_empty());
     }
 }
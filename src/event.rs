@@ -0,0 +1,87 @@
+use async_cuda::Stream;
+
+use crate::executor::Future;
+use crate::ffi::sync::event::Event as InnerEvent;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// CUDA event that marks a point in a stream.
+///
+/// Events are used by [`crate::ExecutionContext::set_input_consumed_event()`] to signal that
+/// TensorRT no longer needs the input buffers of an inference.
+///
+/// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+pub struct Event {
+    inner: InnerEvent,
+}
+
+impl Event {
+    /// Create a new [`Event`] on the current device.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    pub async fn new() -> Result<Self> {
+        // SAFETY: The future is awaited right away and not leaked.
+        let inner = unsafe { Future::new(InnerEvent::new) }.await?;
+        Ok(Self { inner })
+    }
+
+    /// Create a new [`Event`] on the current device that records timing information.
+    ///
+    /// Timing events can be used with [`Event::elapsed_time()`], but have more overhead than
+    /// events created with [`Event::new()`].
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    pub async fn new_with_timing() -> Result<Self> {
+        // SAFETY: The future is awaited right away and not leaked.
+        let inner = unsafe { Future::new(InnerEvent::new_with_timing) }.await?;
+        Ok(Self { inner })
+    }
+
+    /// Capture the work that is currently queued on a stream.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Stream to record event on.
+    pub async fn record(&self, stream: &Stream) -> Result<()> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.record(stream.inner())) }.await
+    }
+
+    /// Get the time elapsed between this event and a later event, in milliseconds.
+    ///
+    /// Both events must have been created with [`Event::new_with_timing()`], and must have
+    /// completed.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - Event that was recorded after this event.
+    pub fn elapsed_time(&self, end: &Event) -> Result<f32> {
+        self.inner.elapsed_time(&end.inner)
+    }
+
+    /// Check whether all work captured by the event has completed, without waiting.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    #[inline(always)]
+    pub fn query(&self) -> Result<bool> {
+        self.inner.query()
+    }
+
+    /// Wait until all work captured by the event has completed.
+    ///
+    /// [CUDA documentation](https://docs.nvidia.com/cuda/cuda-runtime-api/group__CUDART__EVENT.html)
+    pub async fn synchronize(&self) -> Result<()> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.synchronize()) }.await
+    }
+
+    /// Get readonly reference to inner [`InnerEvent`].
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &InnerEvent {
+        &self.inner
+    }
+}
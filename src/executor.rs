@@ -0,0 +1,493 @@
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+
+use cpp::cpp;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Executor that runs blocking TensorRT calls, chosen on first use.
+static EXECUTOR: OnceLock<Executor> = OnceLock::new();
+
+/// Serializes calls to [`configure_executor()`], so that only one executor is started.
+static CONFIGURE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Name of the dedicated executor thread.
+const DEDICATED_THREAD_NAME: &str = "async-tensorrt-executor";
+
+/// Strategy for executing the blocking TensorRT calls behind the async API.
+///
+/// [`ExecutorStrategy::Shared`] is the default.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ExecutorStrategy {
+    /// Execute calls on the runtime thread of `async_cuda`, which is shared with all other CUDA
+    /// work in the process, such as copies and stream synchronization.
+    #[default]
+    Shared,
+    /// Execute calls on a thread that is dedicated to TensorRT.
+    ///
+    /// Long running calls such as engine builds then no longer hold up CUDA work that goes through
+    /// the `async_cuda` runtime thread. Before each call, the device that is current on the
+    /// `async_cuda` runtime thread is made current on the dedicated thread, so
+    /// [`crate::DeviceGuard`] works with both strategies.
+    Dedicated,
+}
+
+/// Configuration for [`configure_executor()`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ExecutorConfig {
+    strategy: ExecutorStrategy,
+    core: Option<usize>,
+}
+
+impl ExecutorConfig {
+    /// Create [`ExecutorConfig`] with the shared strategy and no core pinning.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the execution strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - Execution strategy.
+    pub fn with_strategy(mut self, strategy: ExecutorStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Pin the thread that executes calls to a CPU core.
+    ///
+    /// With [`ExecutorStrategy::Shared`], this pins the `async_cuda` runtime thread. Pinning is
+    /// only supported on Linux.
+    ///
+    /// # Arguments
+    ///
+    /// * `core` - Index of CPU core.
+    pub fn with_core(mut self, core: usize) -> Self {
+        self.core = Some(core);
+        self
+    }
+
+    /// Get the execution strategy.
+    #[inline(always)]
+    pub fn strategy(&self) -> ExecutorStrategy {
+        self.strategy
+    }
+
+    /// Get the CPU core that the executing thread is pinned to, if any.
+    #[inline(always)]
+    pub fn core(&self) -> Option<usize> {
+        self.core
+    }
+}
+
+/// Configure how the blocking TensorRT calls behind the async API are executed.
+///
+/// The executor is chosen once per process. This must be called before any other function of the
+/// crate that executes TensorRT calls, typically at startup. Calls made before the executor is
+/// configured select the default configuration. Once a configuration is in use, this function
+/// returns an error for any other configuration.
+///
+/// This function blocks until the executing thread is pinned, if requested. If starting the
+/// executor fails, for example because the thread could not be pinned, no executor is selected and
+/// the function can be called again.
+///
+/// # Arguments
+///
+/// * `config` - Executor configuration.
+///
+/// # Example
+///
+/// ```no_run
+/// use async_tensorrt::{configure_executor, ExecutorConfig, ExecutorStrategy};
+///
+/// configure_executor(
+///     ExecutorConfig::new()
+///         .with_strategy(ExecutorStrategy::Dedicated)
+///         .with_core(3),
+/// )
+/// .unwrap();
+/// ```
+pub fn configure_executor(config: ExecutorConfig) -> Result<()> {
+    configure(&EXECUTOR, config)
+}
+
+/// Start the executor with the given configuration and store it in `cell`, unless it holds one
+/// already.
+///
+/// The cell is only initialized once the executor started, so that a configuration that failed to
+/// start (for example because pinning failed) can be retried.
+fn configure(cell: &OnceLock<Executor>, config: ExecutorConfig) -> Result<()> {
+    let _lock = CONFIGURE_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if cell.get().is_none() {
+        let executor = Executor::start(config)?;
+        // This fails if `executor()` selected the default configuration in the meantime, in which
+        // case the configuration is compared against that one below.
+        let _ = cell.set(executor);
+    }
+    if cell.get().map(Executor::config) != Some(config) {
+        return Err(crate::error::Error::InvalidArgument(
+            "executor already configured".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Get the configuration of the executor.
+///
+/// If the executor was not configured yet, this selects the default configuration.
+pub fn executor_config() -> ExecutorConfig {
+    executor().config()
+}
+
+/// Running executor.
+enum Executor {
+    Shared,
+    SharedPinned(usize),
+    Dedicated {
+        sender: Mutex<Sender<Task>>,
+        core: Option<usize>,
+    },
+}
+
+/// Closure sent to the dedicated executor thread.
+type Task = Box<dyn FnOnce() + Send + 'static>;
+
+impl Executor {
+    fn start(config: ExecutorConfig) -> Result<Self> {
+        match config.strategy {
+            ExecutorStrategy::Shared => match config.core {
+                Some(core) => {
+                    let (pinned_sender, pinned_receiver) = std::sync::mpsc::channel();
+                    async_cuda::runtime::enqueue_decoupled(move || {
+                        let _ = pinned_sender.send(pin_current_thread(core));
+                    });
+                    pinned_receiver.recv().unwrap_or(Ok(()))?;
+                    Ok(Executor::SharedPinned(core))
+                }
+                None => Ok(Executor::Shared),
+            },
+            ExecutorStrategy::Dedicated => {
+                let (sender, receiver) = std::sync::mpsc::channel::<Task>();
+                let (pinned_sender, pinned_receiver) = std::sync::mpsc::channel();
+                let core = config.core;
+                std::thread::Builder::new()
+                    .name(DEDICATED_THREAD_NAME.to_string())
+                    .spawn(move || {
+                        let pinned = core.map(pin_current_thread).unwrap_or(Ok(()));
+                        let _ = pinned_sender.send(pinned);
+                        run_dedicated(receiver);
+                    })?;
+                pinned_receiver.recv().unwrap_or(Ok(()))?;
+                Ok(Executor::Dedicated {
+                    sender: Mutex::new(sender),
+                    core,
+                })
+            }
+        }
+    }
+
+    fn config(&self) -> ExecutorConfig {
+        match self {
+            Executor::Shared => ExecutorConfig::new(),
+            Executor::SharedPinned(core) => ExecutorConfig::new().with_core(*core),
+            Executor::Dedicated { core, .. } => ExecutorConfig {
+                strategy: ExecutorStrategy::Dedicated,
+                core: *core,
+            },
+        }
+    }
+}
+
+fn executor() -> &'static Executor {
+    EXECUTOR.get_or_init(|| Executor::Shared)
+}
+
+fn run_dedicated(receiver: Receiver<Task>) {
+    while let Ok(task) = receiver.recv() {
+        task();
+    }
+}
+
+fn pin_current_thread(core: usize) -> Result<()> {
+    let core_index = core as u64;
+    let success = cpp!(unsafe [
+        core_index as "std::uint64_t"
+    ] -> bool as "bool" {
+        #ifdef __linux__
+        if (core_index >= CPU_SETSIZE) {
+            return false;
+        }
+        cpu_set_t cpu_set;
+        CPU_ZERO(&cpu_set);
+        CPU_SET(core_index, &cpu_set);
+        return pthread_setaffinity_np(pthread_self(), sizeof(cpu_set_t), &cpu_set) == 0;
+        #else
+        return false;
+        #endif
+    });
+    if success {
+        Ok(())
+    } else {
+        Err(crate::error::Error::InvalidArgument(format!(
+            "failed to pin executor thread to core {core}"
+        )))
+    }
+}
+
+/// Future that executes a blocking closure on the configured executor.
+///
+/// This is a drop-in replacement for [`async_cuda::runtime::Future`] that honors
+/// [`configure_executor()`].
+pub(crate) struct Future<'closure, T> {
+    inner: Pin<Box<dyn std::future::Future<Output = T> + Send + 'closure>>,
+}
+
+impl<'closure, T: Send + 'closure> Future<'closure, T> {
+    /// Create a future that executes `closure` on the configured executor.
+    ///
+    /// # Safety
+    ///
+    /// The closure may borrow data that lives for `'closure` only, while it runs on another
+    /// thread. Dropping the future blocks until the closure has finished, which keeps the borrowed
+    /// data alive. The caller must make sure that the future is not leaked (for example with
+    /// [`std::mem::forget()`]) after it was first polled, since the closure could then use the
+    /// borrowed data after it was freed. The async functions of this crate satisfy this by
+    /// awaiting the future right away, which moves the requirement to their callers (see the
+    /// safety warning of the crate).
+    ///
+    /// # Arguments
+    ///
+    /// * `closure` - Closure to execute.
+    pub(crate) unsafe fn new<Closure>(closure: Closure) -> Self
+    where
+        Closure: FnOnce() -> T + Send + 'closure,
+    {
+        Self::new_on(executor(), closure)
+    }
+
+    /// Create a future that executes `closure` on the given executor.
+    ///
+    /// # Safety
+    ///
+    /// See [`Future::new()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - Executor to execute closure on.
+    /// * `closure` - Closure to execute.
+    unsafe fn new_on<Closure>(executor: &Executor, closure: Closure) -> Self
+    where
+        Closure: FnOnce() -> T + Send + 'closure,
+    {
+        let inner: Pin<Box<dyn std::future::Future<Output = T> + Send + 'closure>> = match executor
+        {
+            Executor::Shared | Executor::SharedPinned(_) => {
+                Box::pin(async_cuda::runtime::Future::new(closure))
+            }
+            Executor::Dedicated { sender, .. } => {
+                let sender = sender.lock().unwrap().clone();
+                Box::pin(async move {
+                    let device = async_cuda::Device::get().await.ok();
+                    DedicatedFuture::new(sender, move || {
+                        if let Some(device) = device {
+                            let _ = async_cuda::ffi::device::Device::set(device);
+                        }
+                        closure()
+                    })
+                    .await
+                })
+            }
+        };
+        Self { inner }
+    }
+}
+
+impl<T> std::future::Future for Future<'_, T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+/// Future that executes a closure on the dedicated executor thread.
+///
+/// The closure may borrow from its environment. Dropping the future after the closure was sent
+/// blocks until the closure has finished, which is only sound if the future is not leaked (see
+/// [`Future::new()`]).
+struct DedicatedFuture<'closure, T> {
+    sender: Sender<Task>,
+    closure: Option<Box<dyn FnOnce() -> T + Send + 'closure>>,
+    completion: Arc<Completion<T>>,
+}
+
+/// Output of a closure executed on the dedicated executor thread.
+struct Completion<T> {
+    state: Mutex<CompletionState<T>>,
+    done: Condvar,
+}
+
+struct CompletionState<T> {
+    output: Option<std::thread::Result<T>>,
+    waker: Option<Waker>,
+    finished: bool,
+}
+
+impl<'closure, T: Send + 'closure> DedicatedFuture<'closure, T> {
+    fn new(sender: Sender<Task>, closure: impl FnOnce() -> T + Send + 'closure) -> Self {
+        Self {
+            sender,
+            closure: Some(Box::new(closure)),
+            completion: Arc::new(Completion {
+                state: Mutex::new(CompletionState {
+                    output: None,
+                    waker: None,
+                    finished: false,
+                }),
+                done: Condvar::new(),
+            }),
+        }
+    }
+
+    fn submit(&mut self, closure: Box<dyn FnOnce() -> T + Send + 'closure>) {
+        let completion = self.completion.clone();
+        let task: Box<dyn FnOnce() + Send + 'closure> = Box::new(move || {
+            let output = catch_unwind(AssertUnwindSafe(closure));
+            let mut state = completion.state.lock().unwrap();
+            state.output = Some(output);
+            state.finished = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            completion.done.notify_all();
+        });
+        // SAFETY: The closure may borrow data that lives for `'closure`. Dropping the future
+        // waits until the task has finished, and the creator of the future guarantees that it is
+        // not leaked (see `Future::new`), so the borrowed data outlives the task.
+        let task: Task = unsafe { std::mem::transmute(task) };
+        if self.sender.send(task).is_err() {
+            self.completion.state.lock().unwrap().finished = true;
+            panic!("dedicated executor thread stopped");
+        }
+    }
+}
+
+impl<T: Send> std::future::Future for DedicatedFuture<'_, T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        {
+            let mut state = self.completion.state.lock().unwrap();
+            if let Some(output) = state.output.take() {
+                return match output {
+                    Ok(output) => Poll::Ready(output),
+                    Err(panic) => resume_unwind(panic),
+                };
+            }
+            state.waker = Some(cx.waker().clone());
+        }
+        if let Some(closure) = self.closure.take() {
+            self.submit(closure);
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for DedicatedFuture<'_, T> {
+    fn drop(&mut self) {
+        if self.closure.is_none() {
+            let mut state = self.completion.state.lock().unwrap();
+            while !state.finished {
+                state = self.completion.done.wait(state).unwrap();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_executor_config() {
+        let config = ExecutorConfig::new()
+            .with_strategy(ExecutorStrategy::Dedicated)
+            .with_core(0);
+        assert_eq!(config.strategy(), ExecutorStrategy::Dedicated);
+        assert_eq!(config.core(), Some(0));
+        assert_eq!(ExecutorConfig::new().strategy(), ExecutorStrategy::Shared);
+    }
+
+    #[tokio::test]
+    async fn test_executor_future() {
+        let value = 21;
+        assert_eq!(unsafe { Future::new(|| value * 2) }.await, 42);
+        assert!(configure_executor(executor_config()).is_ok());
+        assert!(configure_executor(executor_config().with_strategy(
+            match executor_config().strategy() {
+                ExecutorStrategy::Shared => ExecutorStrategy::Dedicated,
+                ExecutorStrategy::Dedicated => ExecutorStrategy::Shared,
+            }
+        ))
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_executor_configure_retry_after_failure() {
+        let cell = OnceLock::new();
+        let dedicated = ExecutorConfig::new().with_strategy(ExecutorStrategy::Dedicated);
+        assert!(configure(&cell, dedicated.with_core(usize::MAX)).is_err());
+        assert!(cell.get().is_none());
+        assert!(configure(&cell, dedicated).is_ok());
+        assert_eq!(cell.get().map(Executor::config), Some(dedicated));
+        assert!(configure(&cell, dedicated.with_core(usize::MAX)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_executor_dedicated() {
+        let executor =
+            Executor::start(ExecutorConfig::new().with_strategy(ExecutorStrategy::Dedicated))
+                .unwrap();
+        let thread_name = unsafe {
+            Future::new_on(&executor, || {
+                std::thread::current().name().map(String::from)
+            })
+        }
+        .await;
+        assert_eq!(thread_name.as_deref(), Some(DEDICATED_THREAD_NAME));
+        let values = vec![1, 2, 3];
+        let sum = unsafe { Future::new_on(&executor, || values.iter().sum::<i32>()) }.await;
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn test_dedicated_future_drop_waits_for_task() {
+        use std::future::Future as _;
+
+        struct NoopWaker;
+
+        impl std::task::Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let (sender, receiver) = std::sync::mpsc::channel::<Task>();
+        std::thread::spawn(move || run_dedicated(receiver));
+        let finished = std::sync::atomic::AtomicBool::new(false);
+        let mut future = DedicatedFuture::new(sender, || {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            finished.store(true, std::sync::atomic::Ordering::SeqCst);
+        });
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        // The first poll submits the task to the dedicated thread.
+        assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+        drop(future);
+        assert!(finished.load(std::sync::atomic::Ordering::SeqCst));
+    }
+}
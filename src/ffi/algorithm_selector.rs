@@ -0,0 +1,324 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_ALGORITHM_SELECTOR
+    #define ODDITY_FFI_ALGORITHM_SELECTOR
+
+    #ifndef ODDITY_FFI_DISPATCH
+    // Algorithm selector that forwards all calls to the Rust algorithm selector state. The state
+    // is owned by the Rust side, this class only holds a pointer to it.
+    class RustAlgorithmSelector : public IAlgorithmSelector
+    {
+    public:
+        RustAlgorithmSelector(void* state) : m_state(state) {}
+
+        std::int32_t selectAlgorithms(
+            const IAlgorithmContext& context,
+            const IAlgorithm* const* choices,
+            std::int32_t nbChoices,
+            std::int32_t* selection
+        ) noexcept override {
+            void* state = m_state;
+            const void* contextPtr = (const void*) &context;
+            const void* const* choicesPtr = (const void* const*) choices;
+            return rust!(RustAlgorithmSelector_selectAlgorithms [
+                state : *const std::ffi::c_void as "void*",
+                contextPtr : *const std::ffi::c_void as "const void*",
+                choicesPtr : *const *const std::ffi::c_void as "const void* const*",
+                nbChoices : i32 as "std::int32_t",
+                selection : *mut i32 as "std::int32_t*"
+            ] -> i32 as "std::int32_t" {
+                select_algorithms_raw(state, contextPtr, choicesPtr, nbChoices, selection)
+            });
+        }
+
+        void reportAlgorithms(
+            const IAlgorithmContext* const* algoContexts,
+            const IAlgorithm* const* algoChoices,
+            std::int32_t nbAlgorithms
+        ) noexcept override {
+            void* state = m_state;
+            const void* const* contextsPtr = (const void* const*) algoContexts;
+            const void* const* choicesPtr = (const void* const*) algoChoices;
+            rust!(RustAlgorithmSelector_reportAlgorithms [
+                state : *const std::ffi::c_void as "void*",
+                contextsPtr : *const *const std::ffi::c_void as "const void* const*",
+                choicesPtr : *const *const std::ffi::c_void as "const void* const*",
+                nbAlgorithms : i32 as "std::int32_t"
+            ] {
+                report_algorithms_raw(state, contextsPtr, choicesPtr, nbAlgorithms);
+            });
+        }
+
+    private:
+        void* m_state;
+    };
+    #endif
+
+    #endif // ODDITY_FFI_ALGORITHM_SELECTOR
+}}
+
+/// Layer or fused group of layers that the builder selects an algorithm for.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_algorithm_context.html)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlgorithmContext {
+    /// Name of the node.
+    pub name: String,
+    /// Number of inputs of the node.
+    pub num_inputs: usize,
+    /// Number of outputs of the node.
+    pub num_outputs: usize,
+}
+
+impl AlgorithmContext {
+    /// Read [`AlgorithmContext`] from internal pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid `IAlgorithmContext` object.
+    unsafe fn from_raw(internal: *const std::ffi::c_void) -> Self {
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const IAlgorithmContext*) internal)->getName();
+        });
+        let name = if !name.is_null() {
+            std::ffi::CStr::from_ptr(name)
+                .to_string_lossy()
+                .into_owned()
+        } else {
+            String::new()
+        };
+        let num_inputs = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IAlgorithmContext*) internal)->getNbInputs();
+        });
+        let num_outputs = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IAlgorithmContext*) internal)->getNbOutputs();
+        });
+        Self {
+            name,
+            num_inputs: num_inputs.max(0) as usize,
+            num_outputs: num_outputs.max(0) as usize,
+        }
+    }
+}
+
+/// Algorithm that the builder can choose to implement a node with.
+///
+/// The combination of implementation and tactic uniquely identifies an algorithm, and can be used
+/// to pin the same choice in a later build.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_algorithm.html)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Algorithm {
+    /// Implementation of the algorithm.
+    pub implementation: i64,
+    /// Tactic of the algorithm.
+    pub tactic: i64,
+    /// Time the algorithm took during timing, in milliseconds.
+    pub timing_msec: f32,
+    /// Size of the workspace the algorithm requires, in bytes.
+    pub workspace_size: usize,
+}
+
+impl Algorithm {
+    /// Read [`Algorithm`] from internal pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid `IAlgorithm` object.
+    unsafe fn from_raw(internal: *const std::ffi::c_void) -> Self {
+        let implementation = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            return ((const IAlgorithm*) internal)->getAlgorithmVariant().getImplementation();
+        });
+        let tactic = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            return ((const IAlgorithm*) internal)->getAlgorithmVariant().getTactic();
+        });
+        let timing_msec = cpp!(unsafe [
+            internal as "const void*"
+        ] -> f32 as "float" {
+            return ((const IAlgorithm*) internal)->getTimingMSec();
+        });
+        let workspace_size = cpp!(unsafe [
+            internal as "const void*"
+        ] -> usize as "std::size_t" {
+            return ((const IAlgorithm*) internal)->getWorkspaceSize();
+        });
+        Self {
+            implementation,
+            tactic,
+            timing_msec,
+            workspace_size,
+        }
+    }
+}
+
+/// Selects the algorithms that the builder may use for each node of the network.
+///
+/// Install an algorithm selector with [`crate::BuilderConfig::set_algorithm_selector()`]. This can
+/// be used to pin tactic choices, so that builds on different machines produce the same engine.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1v__1__0_1_1_i_algorithm_selector.html)
+pub trait AlgorithmSelector: Send {
+    /// Select the algorithms the builder may choose from for a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Node to select algorithms for.
+    /// * `choices` - Algorithms that are available for the node.
+    ///
+    /// # Return value
+    ///
+    /// Indices into `choices` of the allowed algorithms. If empty, the builder chooses from all
+    /// algorithms, or fails if [`crate::BuilderFlag::RejectEmptyAlgorithms`] is set.
+    fn select_algorithms(
+        &mut self,
+        context: &AlgorithmContext,
+        choices: &[Algorithm],
+    ) -> Vec<usize>;
+
+    /// Called with the algorithms that the builder chose for each node, after the build.
+    ///
+    /// # Arguments
+    ///
+    /// * `choices` - Node and the algorithm that was chosen for it.
+    fn report_algorithms(&mut self, choices: &[(AlgorithmContext, Algorithm)]) {
+        let _ = choices;
+    }
+}
+
+/// Bridge between a Rust [`AlgorithmSelector`] and a TensorRT `IAlgorithmSelector`.
+///
+/// Owns both the Rust selector and the C++ object that forwards to it.
+pub(crate) struct AlgorithmSelectorBridge {
+    internal: *mut std::ffi::c_void,
+    #[allow(dead_code)]
+    state: Box<std::sync::Mutex<Box<dyn AlgorithmSelector>>>,
+}
+
+/// Implements [`Send`] for [`AlgorithmSelectorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust selector, which is [`Send`] itself.
+unsafe impl Send for AlgorithmSelectorBridge {}
+
+/// Implements [`Sync`] for [`AlgorithmSelectorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust selector, which is behind a mutex.
+unsafe impl Sync for AlgorithmSelectorBridge {}
+
+impl AlgorithmSelectorBridge {
+    /// Create a new bridge that forwards to `selector`.
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Algorithm selector.
+    pub(crate) fn new(selector: Box<dyn AlgorithmSelector>) -> Self {
+        let state = Box::new(std::sync::Mutex::new(selector));
+        let state_ptr = state.as_ref() as *const std::sync::Mutex<Box<dyn AlgorithmSelector>>
+            as *const std::ffi::c_void;
+        let internal = cpp!(unsafe [
+            state_ptr as "const void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) new RustAlgorithmSelector((void*) state_ptr);
+        });
+        AlgorithmSelectorBridge { internal, state }
+    }
+
+    /// Get internal pointer to `IAlgorithmSelector`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let AlgorithmSelectorBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for AlgorithmSelectorBridge {
+    fn drop(&mut self) {
+        let AlgorithmSelectorBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            delete ((RustAlgorithmSelector*) internal);
+        });
+    }
+}
+
+/// Raw handler for `IAlgorithmSelector::selectAlgorithms`.
+///
+/// # Return value
+///
+/// Number of selected algorithms written to `selection`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live algorithm selector, that `context` points
+/// to a valid `IAlgorithmContext`, that `choices` points to `num_choices` valid `IAlgorithm`
+/// pointers and that `selection` has room for `num_choices` indices.
+unsafe fn select_algorithms_raw(
+    state: *const std::ffi::c_void,
+    context: *const std::ffi::c_void,
+    choices: *const *const std::ffi::c_void,
+    num_choices: i32,
+    selection: *mut i32,
+) -> i32 {
+    let state = &*(state as *const std::sync::Mutex<Box<dyn AlgorithmSelector>>);
+    let num_choices = num_choices.max(0) as usize;
+    let context = AlgorithmContext::from_raw(context);
+    let choices = std::slice::from_raw_parts(choices, num_choices)
+        .iter()
+        .map(|choice| Algorithm::from_raw(*choice))
+        .collect::<Vec<_>>();
+    let selected = state.lock().unwrap().select_algorithms(&context, &choices);
+    let selection = std::slice::from_raw_parts_mut(selection, num_choices);
+    let mut num_selected = 0;
+    for index in selected {
+        if index < num_choices && num_selected < num_choices {
+            selection[num_selected] = index as i32;
+            num_selected += 1;
+        }
+    }
+    num_selected as i32
+}
+
+/// Raw handler for `IAlgorithmSelector::reportAlgorithms`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live algorithm selector, and that `contexts`
+/// and `choices` each point to `num_algorithms` valid `IAlgorithmContext` and `IAlgorithm`
+/// pointers respectively.
+unsafe fn report_algorithms_raw(
+    state: *const std::ffi::c_void,
+    contexts: *const *const std::ffi::c_void,
+    choices: *const *const std::ffi::c_void,
+    num_algorithms: i32,
+) {
+    let state = &*(state as *const std::sync::Mutex<Box<dyn AlgorithmSelector>>);
+    let num_algorithms = num_algorithms.max(0) as usize;
+    let contexts = std::slice::from_raw_parts(contexts, num_algorithms);
+    let choices = std::slice::from_raw_parts(choices, num_algorithms);
+    let reported = contexts
+        .iter()
+        .zip(choices)
+        .map(|(context, choice)| {
+            (
+                AlgorithmContext::from_raw(*context),
+                Algorithm::from_raw(*choice),
+            )
+        })
+        .collect::<Vec<_>>();
+    state.lock().unwrap().report_algorithms(&reported);
+}
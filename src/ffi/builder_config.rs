@@ -1,13 +1,104 @@
 use cpp::cpp;
 
+use crate::ffi::algorithm_selector::{AlgorithmSelector, AlgorithmSelectorBridge};
+use crate::ffi::calibrator::{Calibrator, CalibratorBridge};
+use crate::ffi::dims::Dims;
+use crate::ffi::engine_capability::EngineCapability;
+use crate::ffi::network::NetworkDefinition;
+use crate::ffi::progress_monitor::{ProgressMonitor, ProgressMonitorBridge};
+use crate::ffi::result;
+use crate::ffi::timing_cache::TimingCache;
 use crate::OptimizationProfile;
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
+cpp! {{
+    // Convert the Rust representation of a builder flag to `BuilderFlag`. Returns `false` if the
+    // flag is not supported by the version of TensorRT we are compiling against.
+    bool builderFlagFromRust(std::int32_t value, BuilderFlag* flag) {
+        switch (value) {
+            case 0: *flag = BuilderFlag::kFP16; return true;
+            case 1: *flag = BuilderFlag::kINT8; return true;
+            case 2: *flag = BuilderFlag::kDEBUG; return true;
+            case 3: *flag = BuilderFlag::kGPU_FALLBACK; return true;
+            case 4: *flag = BuilderFlag::kREFIT; return true;
+            case 5: *flag = BuilderFlag::kDISABLE_TIMING_CACHE; return true;
+            case 6: *flag = BuilderFlag::kTF32; return true;
+            case 7: *flag = BuilderFlag::kSPARSE_WEIGHTS; return true;
+            case 8: *flag = BuilderFlag::kSAFETY_SCOPE; return true;
+            case 9: *flag = BuilderFlag::kOBEY_PRECISION_CONSTRAINTS; return true;
+            case 10: *flag = BuilderFlag::kPREFER_PRECISION_CONSTRAINTS; return true;
+            case 11: *flag = BuilderFlag::kDIRECT_IO; return true;
+            case 12: *flag = BuilderFlag::kREJECT_EMPTY_ALGORITHMS; return true;
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            case 13: *flag = BuilderFlag::kVERSION_COMPATIBLE; return true;
+            case 14: *flag = BuilderFlag::kEXCLUDE_LEAN_RUNTIME; return true;
+            case 15: *flag = BuilderFlag::kFP8; return true;
+            #endif
+            #if NV_TENSORRT_MAJOR >= 10
+            case 16: *flag = BuilderFlag::kERROR_ON_TIMING_CACHE_MISS; return true;
+            case 17: *flag = BuilderFlag::kBF16; return true;
+            case 18: *flag = BuilderFlag::kDISABLE_COMPILATION_CACHE; return true;
+            case 19: *flag = BuilderFlag::kSTRIP_PLAN; return true;
+            case 20: *flag = BuilderFlag::kREFIT_IDENTICAL; return true;
+            case 21: *flag = BuilderFlag::kWEIGHT_STREAMING; return true;
+            #endif
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            case 22: *flag = BuilderFlag::kINT4; return true;
+            #endif
+            default: return false;
+        }
+    }
+
+    // Convert the Rust representation of a memory pool type to `MemoryPoolType`. Returns `false` if
+    // the memory pool type is not supported by the version of TensorRT we are compiling against.
+    bool memoryPoolTypeFromRust(std::int32_t value, MemoryPoolType* pool) {
+        switch (value) {
+            case 0: *pool = MemoryPoolType::kWORKSPACE; return true;
+            case 1: *pool = MemoryPoolType::kDLA_MANAGED_SRAM; return true;
+            case 2: *pool = MemoryPoolType::kDLA_LOCAL_DRAM; return true;
+            case 3: *pool = MemoryPoolType::kDLA_GLOBAL_DRAM; return true;
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            case 4: *pool = MemoryPoolType::kTACTIC_DRAM; return true;
+            #endif
+            #if NV_TENSORRT_MAJOR >= 10
+            case 5: *pool = MemoryPoolType::kTACTIC_SHARED_MEMORY; return true;
+            #endif
+            default: return false;
+        }
+    }
+
+    // Convert the Rust representation of a preview feature to `PreviewFeature`. Returns `false` if
+    // the preview feature is not supported by the version of TensorRT we are compiling against.
+    #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 5)
+    bool previewFeatureFromRust(std::int32_t value, PreviewFeature* feature) {
+        switch (value) {
+            #if NV_TENSORRT_MAJOR == 8
+            case 0: *feature = PreviewFeature::kFASTER_DYNAMIC_SHAPES_0805; return true;
+            case 1: *feature = PreviewFeature::kDISABLE_EXTERNAL_TACTIC_SOURCES_FOR_CORE_0805; return true;
+            #endif
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            case 2: *feature = PreviewFeature::kPROFILE_SHARING_0806; return true;
+            #endif
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 3)
+            case 3: *feature = PreviewFeature::kALIASED_PLUGIN_IO_10_03; return true;
+            #endif
+            default: return false;
+        }
+    }
+    #endif
+}}
+
 /// Holds properties for configuring a builder to produce an engine.
 ///
 /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
-pub struct BuilderConfig(*mut std::ffi::c_void);
+pub struct BuilderConfig {
+    internal: *mut std::ffi::c_void,
+    timing_cache: Option<std::sync::Arc<TimingCache>>,
+    progress_monitor: Option<ProgressMonitorBridge>,
+    algorithm_selector: Option<AlgorithmSelectorBridge>,
+    int8_calibrator: Option<CalibratorBridge>,
+}
 
 /// Implements [`Send`] for [`BuilderConfig`].
 ///
@@ -30,7 +121,13 @@ impl BuilderConfig {
     ///
     /// The pointer must point to a valid `IBuilderConfig` object.
     pub(crate) fn wrap(internal: *mut std::ffi::c_void) -> Self {
-        Self(internal)
+        Self {
+            internal,
+            timing_cache: None,
+            progress_monitor: None,
+            algorithm_selector: None,
+            int8_calibrator: None,
+        }
     }
 
     /// Set the maximum workspace size.
@@ -52,6 +149,391 @@ impl BuilderConfig {
         self
     }
 
+    /// Set the memory size limit of a memory pool.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Memory pool to limit.
+    /// * `size` - Maximum size of the memory pool in bytes.
+    ///
+    /// # Return value
+    ///
+    /// An error if the memory pool type is not supported by the version of TensorRT in use.
+    pub fn set_memory_pool_limit(&mut self, pool: MemoryPoolType, size: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let pool_value = pool as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            pool_value as "std::int32_t",
+            size as "std::size_t"
+        ] -> bool as "bool" {
+            MemoryPoolType pool;
+            if (!memoryPoolTypeFromRust(pool_value, &pool)) {
+                return false;
+            }
+            ((IBuilderConfig*) internal)->setMemoryPoolLimit(pool, size);
+            return true;
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(format!(
+                "memory pool type {pool:?} not supported by this version of TensorRT"
+            )))
+        }
+    }
+
+    /// Set the memory size limit of a memory pool.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Memory pool to limit.
+    /// * `size` - Maximum size of the memory pool in bytes.
+    pub fn with_memory_pool_limit(mut self, pool: MemoryPoolType, size: usize) -> Result<Self> {
+        self.set_memory_pool_limit(pool, size)?;
+        Ok(self)
+    }
+
+    /// Get the memory size limit of a memory pool.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Memory pool to get limit of.
+    ///
+    /// # Return value
+    ///
+    /// Maximum size of the memory pool in bytes. Memory pool types that are not supported by the
+    /// version of TensorRT in use have a limit of zero.
+    pub fn get_memory_pool_limit(&self, pool: MemoryPoolType) -> usize {
+        let internal = self.as_ptr();
+        let pool_value = pool as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            pool_value as "std::int32_t"
+        ] -> usize as "std::size_t" {
+            MemoryPoolType pool;
+            if (!memoryPoolTypeFromRust(pool_value, &pool)) {
+                return 0;
+            }
+            return ((const IBuilderConfig*) internal)->getMemoryPoolLimit(pool);
+        })
+    }
+
+    /// Set the maximum number of auxiliary streams that the engine may use.
+    ///
+    /// Auxiliary streams allow layers to run in parallel with the main stream, at the cost of more
+    /// device memory. Set to zero to disable them. Requires TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_aux_streams` - Maximum number of auxiliary streams.
+    pub fn set_max_aux_streams(&mut self, max_aux_streams: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let max_aux_streams = max_aux_streams.min(i32::MAX as usize) as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            max_aux_streams as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IBuilderConfig*) internal)->setMaxAuxStreams(max_aux_streams);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "auxiliary streams not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Set the maximum number of auxiliary streams that the engine may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_aux_streams` - Maximum number of auxiliary streams.
+    pub fn with_max_aux_streams(mut self, max_aux_streams: usize) -> Result<Self> {
+        self.set_max_aux_streams(max_aux_streams)?;
+        Ok(self)
+    }
+
+    /// Get the maximum number of auxiliary streams that the engine may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Return value
+    ///
+    /// Maximum number of auxiliary streams, or `None` if TensorRT decides by itself or the version
+    /// of TensorRT in use does not support auxiliary streams.
+    pub fn get_max_aux_streams(&self) -> Option<usize> {
+        let internal = self.as_ptr();
+        let max_aux_streams = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IBuilderConfig*) internal)->getMaxAuxStreams();
+            #else
+            return -1;
+            #endif
+        });
+        (max_aux_streams >= 0).then_some(max_aux_streams as usize)
+    }
+
+    /// Set the builder optimization level.
+    ///
+    /// Higher levels allow the builder to spend more time searching for faster tactics. Level 3 is
+    /// the default. Lower levels build faster, at the cost of possibly slower engines. Requires
+    /// TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Optimization level between 0 and 5.
+    pub fn set_builder_optimization_level(&mut self, level: i32) -> Result<()> {
+        if !(0..=MAX_BUILDER_OPTIMIZATION_LEVEL).contains(&level) {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "builder optimization level {level} not between 0 and \
+                 {MAX_BUILDER_OPTIMIZATION_LEVEL}"
+            )));
+        }
+        let internal = self.as_mut_ptr();
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            level as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IBuilderConfig*) internal)->setBuilderOptimizationLevel(level);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "builder optimization level not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Set the builder optimization level.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Optimization level between 0 and 5.
+    pub fn with_builder_optimization_level(mut self, level: i32) -> Result<Self> {
+        self.set_builder_optimization_level(level)?;
+        Ok(self)
+    }
+
+    /// Get the builder optimization level.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Return value
+    ///
+    /// Optimization level, or `None` if the version of TensorRT in use does not support
+    /// optimization levels.
+    pub fn get_builder_optimization_level(&self) -> Option<i32> {
+        let internal = self.as_ptr();
+        let level = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IBuilderConfig*) internal)->getBuilderOptimizationLevel();
+            #else
+            return -1;
+            #endif
+        });
+        (level >= 0).then_some(level)
+    }
+
+    /// Set the number of averaging iterations used when timing tactics.
+    ///
+    /// Fewer iterations shorten the build, which helps on slow devices, at the cost of less
+    /// accurate tactic selection.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `avg_timing_iterations` - Number of averaging iterations. Must be at least one.
+    pub fn set_avg_timing_iterations(&mut self, avg_timing_iterations: usize) -> Result<()> {
+        if avg_timing_iterations == 0 {
+            return Err(crate::error::Error::InvalidArgument(
+                "number of averaging iterations must be at least one".to_string(),
+            ));
+        }
+        let internal = self.as_mut_ptr();
+        let avg_timing_iterations = avg_timing_iterations.min(i32::MAX as usize) as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            avg_timing_iterations as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->setAvgTimingIterations(avg_timing_iterations);
+        });
+        Ok(())
+    }
+
+    /// Set the number of averaging iterations used when timing tactics.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `avg_timing_iterations` - Number of averaging iterations. Must be at least one.
+    pub fn with_avg_timing_iterations(mut self, avg_timing_iterations: usize) -> Result<Self> {
+        self.set_avg_timing_iterations(avg_timing_iterations)?;
+        Ok(self)
+    }
+
+    /// Get the number of averaging iterations used when timing tactics.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn get_avg_timing_iterations(&self) -> usize {
+        let internal = self.as_ptr();
+        let avg_timing_iterations = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IBuilderConfig*) internal)->getAvgTimingIterations();
+        });
+        avg_timing_iterations.max(0) as usize
+    }
+
+    /// Set the maximum number of tactics to time for each layer.
+    ///
+    /// Limiting the number of tactics bounds the build time, which helps on slow devices, at the
+    /// cost of possibly slower engines. Requires TensorRT 10.4 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_nb_tactics` - Maximum number of tactics.
+    pub fn set_max_nb_tactics(&mut self, max_nb_tactics: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let max_nb_tactics = max_nb_tactics.min(i32::MAX as usize) as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            max_nb_tactics as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 4)
+            ((IBuilderConfig*) internal)->setMaxNbTactics(max_nb_tactics);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "limiting the number of tactics not supported by this version of TensorRT"
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Set the maximum number of tactics to time for each layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_nb_tactics` - Maximum number of tactics.
+    pub fn with_max_nb_tactics(mut self, max_nb_tactics: usize) -> Result<Self> {
+        self.set_max_nb_tactics(max_nb_tactics)?;
+        Ok(self)
+    }
+
+    /// Get the maximum number of tactics to time for each layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Return value
+    ///
+    /// Maximum number of tactics, or `None` if TensorRT decides by itself or the version of
+    /// TensorRT in use does not support limiting the number of tactics.
+    pub fn get_max_nb_tactics(&self) -> Option<usize> {
+        let internal = self.as_ptr();
+        let max_nb_tactics = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 4)
+            return ((const IBuilderConfig*) internal)->getMaxNbTactics();
+            #else
+            return -1;
+            #endif
+        });
+        (max_nb_tactics >= 0).then_some(max_nb_tactics as usize)
+    }
+
+    /// Configure the builder for a common use case.
+    ///
+    /// This sets the workspace size, the number of auxiliary streams, the tactic sources and the
+    /// builder optimization level to values that work well together for the given [`Preset`].
+    /// Refer to [`Preset`] for the exact settings. Settings can still be changed afterwards.
+    ///
+    /// Auxiliary streams and the optimization level are left untouched if the version of
+    /// TensorRT in use does not support them.
+    ///
+    /// # Arguments
+    ///
+    /// * `preset` - Preset to apply.
+    pub fn set_preset(&mut self, preset: Preset) -> Result<()> {
+        let (workspace_size, max_aux_streams, tactic_sources, optimization_level) = match preset {
+            Preset::LowLatency => (None, None, TacticSources::all(), 5),
+            Preset::MaxThroughput => (None, Some(0), TacticSources::all(), 5),
+            Preset::MinMemory => (
+                Some(MIN_MEMORY_WORKSPACE_SIZE),
+                Some(0),
+                TacticSources::EDGE_MASK_CONVOLUTIONS | TacticSources::JIT_CONVOLUTIONS,
+                3,
+            ),
+        };
+        if let Some(workspace_size) = workspace_size {
+            self.set_memory_pool_limit(MemoryPoolType::Workspace, workspace_size)?;
+        }
+        self.set_tactic_sources(tactic_sources)?;
+        let version = crate::ffi::version::tensorrt_version();
+        if (version.major, version.minor) >= (8, 6) {
+            if let Some(max_aux_streams) = max_aux_streams {
+                self.set_max_aux_streams(max_aux_streams)?;
+            }
+            self.set_builder_optimization_level(optimization_level)?;
+        }
+        Ok(())
+    }
+
+    /// Configure the builder for a common use case.
+    ///
+    /// Refer to [`BuilderConfig::set_preset()`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `preset` - Preset to apply.
+    pub fn with_preset(mut self, preset: Preset) -> Result<Self> {
+        self.set_preset(preset)?;
+        Ok(self)
+    }
+
     /// Set the `kSTRICT_TYPES` flag.
     ///
     /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
@@ -86,61 +568,1545 @@ impl BuilderConfig {
         self
     }
 
-    /// Add an optimization profile.
+    /// Set the `kFP8` flag.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ab97fa40c85fa8afab65fc2659e38da82)
-    pub fn with_optimization_profile(
-        mut self,
-        optimization_profile: OptimizationProfile,
-    ) -> Result<Self> {
-        self.add_optimization_profile(optimization_profile)?;
+    /// This flag is only supported by TensorRT 8.6 and later.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_fp8(mut self) -> Result<Self> {
+        self.set_flag(BuilderFlag::Fp8)?;
         Ok(self)
     }
 
-    /// Add an optimization profile.
+    /// Set the `kBF16` flag.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ab97fa40c85fa8afab65fc2659e38da82)
-    pub fn add_optimization_profile(
-        &mut self,
-        optimization_profile: OptimizationProfile,
-    ) -> Result<()> {
+    /// This flag is only supported by TensorRT 10 and later.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_bf16(mut self) -> Result<Self> {
+        self.set_flag(BuilderFlag::Bf16)?;
+        Ok(self)
+    }
+
+    /// Set the `kINT4` flag.
+    ///
+    /// This flag is only supported by TensorRT 10.1 and later.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_int4(mut self) -> Result<Self> {
+        self.set_flag(BuilderFlag::Int4)?;
+        Ok(self)
+    }
+
+    /// Set the `kREFIT` flag.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_refit(mut self) -> Self {
         let internal = self.as_mut_ptr();
-        let optimization_profile = optimization_profile.as_ptr();
-        let index = cpp!(unsafe [
-            internal as "void*",
-            optimization_profile as "const IOptimizationProfile*"
-        ] -> i32 as "std::int32_t" {
-           return ((IBuilderConfig*) internal)->addOptimizationProfile(optimization_profile);
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            ((IBuilderConfig*) internal)->setFlag(BuilderFlag::kREFIT);
         });
-        if index >= 0 {
-            Ok(())
-        } else {
-            Err(crate::error::last_error())
-        }
+        self
     }
 
-    /// Get internal readonly pointer.
-    #[inline(always)]
-    pub fn as_ptr(&self) -> *const std::ffi::c_void {
-        let BuilderConfig(internal) = *self;
-        internal
+    /// Set the `kREFIT_IDENTICAL` flag.
+    ///
+    /// This flag is only supported by TensorRT 10 and later.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_refit_identical(mut self) -> Result<Self> {
+        self.set_flag(BuilderFlag::RefitIdentical)?;
+        Ok(self)
     }
 
-    /// Get internal mutable pointer.
-    #[inline(always)]
-    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
-        let BuilderConfig(internal) = *self;
-        internal
+    /// Set the `kWEIGHT_STREAMING` flag.
+    ///
+    /// This flag is only supported by TensorRT 10 and later.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_weight_streaming(mut self) -> Result<Self> {
+        self.set_flag(BuilderFlag::WeightStreaming)?;
+        Ok(self)
     }
-}
 
-impl Drop for BuilderConfig {
-    fn drop(&mut self) {
+    /// Set the `kGPU_FALLBACK` flag.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    pub fn with_gpu_fallback(mut self) -> Self {
         let internal = self.as_mut_ptr();
         cpp!(unsafe [
             internal as "void*"
         ] {
-            destroy((IBuilderConfig*) internal);
+            ((IBuilderConfig*) internal)->setFlag(BuilderFlag::kGPU_FALLBACK);
         });
+        self
+    }
+
+    /// Set the default device type that layers execute on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `device_type` - Device type to execute layers on by default.
+    pub fn with_default_device_type(mut self, device_type: DeviceType) -> Self {
+        let internal = self.as_mut_ptr();
+        let device_type = device_type as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            device_type as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->setDefaultDeviceType(static_cast<DeviceType>(device_type));
+        });
+        self
+    }
+
+    /// Set the DLA core that the engine must execute on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `dla_core` - DLA core to execute the engine on, in the range `[0, num_dla_cores)`.
+    pub fn with_dla_core(mut self, dla_core: i32) -> Self {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            dla_core as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->setDLACore(dla_core);
+        });
+        self
+    }
+
+    /// Get the DLA core that the engine executes on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn get_dla_core(&self) -> i32 {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IBuilderConfig*) internal)->getDLACore();
+        })
+    }
+
+    /// Set the engine capability, which restricts the engine to a subset of TensorRT features.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine_capability` - Engine capability to build for.
+    pub fn set_engine_capability(&mut self, engine_capability: EngineCapability) {
+        let internal = self.as_mut_ptr();
+        let engine_capability = engine_capability as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            engine_capability as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->setEngineCapability(
+                static_cast<EngineCapability>(engine_capability)
+            );
+        });
+    }
+
+    /// Set the engine capability, which restricts the engine to a subset of TensorRT features.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine_capability` - Engine capability to build for.
+    pub fn with_engine_capability(mut self, engine_capability: EngineCapability) -> Self {
+        self.set_engine_capability(engine_capability);
+        self
+    }
+
+    /// Get the engine capability.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn get_engine_capability(&self) -> EngineCapability {
+        let internal = self.as_ptr();
+        let engine_capability = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const IBuilderConfig*) internal)->getEngineCapability();
+        });
+        EngineCapability::from_i32(engine_capability)
+    }
+
+    /// Set a builder flag.
+    ///
+    /// [TensorRT documentation for `setFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ac9821504ae7a11769e48b0e62761837e)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to set.
+    ///
+    /// # Return value
+    ///
+    /// An error if the flag is not supported by the version of TensorRT in use.
+    pub fn set_flag(&mut self, flag: BuilderFlag) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            BuilderFlag flag;
+            if (!builderFlagFromRust(flag_value, &flag)) {
+                return false;
+            }
+            ((IBuilderConfig*) internal)->setFlag(flag);
+            return true;
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(unsupported_flag_error(flag))
+        }
+    }
+
+    /// Clear a builder flag.
+    ///
+    /// [TensorRT documentation for `clearFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to clear.
+    ///
+    /// # Return value
+    ///
+    /// An error if the flag is not supported by the version of TensorRT in use.
+    pub fn clear_flag(&mut self, flag: BuilderFlag) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            BuilderFlag flag;
+            if (!builderFlagFromRust(flag_value, &flag)) {
+                return false;
+            }
+            ((IBuilderConfig*) internal)->clearFlag(flag);
+            return true;
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(unsupported_flag_error(flag))
+        }
+    }
+
+    /// Get whether a builder flag is set.
+    ///
+    /// [TensorRT documentation for `getFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to check.
+    ///
+    /// # Return value
+    ///
+    /// `true` if the flag is set. Flags that are not supported by the version of TensorRT in use
+    /// are never set.
+    pub fn get_flag(&self, flag: BuilderFlag) -> bool {
+        let internal = self.as_ptr();
+        let flag_value = flag as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            BuilderFlag flag;
+            if (!builderFlagFromRust(flag_value, &flag)) {
+                return false;
+            }
+            return ((const IBuilderConfig*) internal)->getFlag(flag);
+        })
+    }
+
+    /// Set a quantization flag.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Quantization flag to set.
+    pub fn set_quantization_flag(&mut self, flag: QuantizationFlag) {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->setQuantizationFlag((QuantizationFlag) flag_value);
+        });
+    }
+
+    /// Set a quantization flag.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Quantization flag to set.
+    pub fn with_quantization_flag(mut self, flag: QuantizationFlag) -> Self {
+        self.set_quantization_flag(flag);
+        self
+    }
+
+    /// Clear a quantization flag.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Quantization flag to clear.
+    pub fn clear_quantization_flag(&mut self, flag: QuantizationFlag) {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] {
+            ((IBuilderConfig*) internal)->clearQuantizationFlag((QuantizationFlag) flag_value);
+        });
+    }
+
+    /// Get whether a quantization flag is set.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Quantization flag to check.
+    pub fn get_quantization_flag(&self, flag: QuantizationFlag) -> bool {
+        let internal = self.as_ptr();
+        let flag_value = flag as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            return ((const IBuilderConfig*) internal)->getQuantizationFlag((QuantizationFlag) flag_value);
+        })
+    }
+
+    /// Set the tactic sources that the builder may use.
+    ///
+    /// Tactic sources that are not included are not used when building the engine, which can be
+    /// used to avoid loading libraries such as cuDNN.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tactic_sources` - Tactic sources to enable.
+    pub fn set_tactic_sources(&mut self, tactic_sources: TacticSources) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let tactic_sources = tactic_sources.bits();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            tactic_sources as "std::uint32_t"
+        ] -> bool as "bool" {
+            return ((IBuilderConfig*) internal)->setTacticSources(tactic_sources);
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Set the tactic sources that the builder may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tactic_sources` - Tactic sources to enable.
+    pub fn with_tactic_sources(mut self, tactic_sources: TacticSources) -> Result<Self> {
+        self.set_tactic_sources(tactic_sources)?;
+        Ok(self)
+    }
+
+    /// Get the tactic sources that the builder may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn get_tactic_sources(&self) -> TacticSources {
+        let internal = self.as_ptr();
+        let tactic_sources = cpp!(unsafe [
+            internal as "const void*"
+        ] -> u32 as "std::uint32_t" {
+            return ((const IBuilderConfig*) internal)->getTacticSources();
+        });
+        TacticSources::from_bits(tactic_sources)
+    }
+
+    /// Enable or disable a preview feature.
+    ///
+    /// Preview features are experimental features of TensorRT that are not enabled by default.
+    /// Requires TensorRT 8.5 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Preview feature to enable or disable.
+    /// * `enable` - Whether or not to enable the feature.
+    ///
+    /// # Return value
+    ///
+    /// An error if the preview feature is not supported by the version of TensorRT in use.
+    pub fn set_preview_feature(&mut self, feature: PreviewFeature, enable: bool) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let feature_value = feature as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            feature_value as "std::int32_t",
+            enable as "bool"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 5)
+            PreviewFeature feature;
+            if (!previewFeatureFromRust(feature_value, &feature)) {
+                return false;
+            }
+            ((IBuilderConfig*) internal)->setPreviewFeature(feature, enable);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(format!(
+                "preview feature {feature:?} not supported by this version of TensorRT"
+            )))
+        }
+    }
+
+    /// Enable or disable a preview feature.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Preview feature to enable or disable.
+    /// * `enable` - Whether or not to enable the feature.
+    pub fn with_preview_feature(mut self, feature: PreviewFeature, enable: bool) -> Result<Self> {
+        self.set_preview_feature(feature, enable)?;
+        Ok(self)
+    }
+
+    /// Get whether a preview feature is enabled.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Preview feature to check.
+    ///
+    /// # Return value
+    ///
+    /// `true` if the feature is enabled. Preview features that are not supported by the version of
+    /// TensorRT in use are never enabled.
+    pub fn get_preview_feature(&self, feature: PreviewFeature) -> bool {
+        let internal = self.as_ptr();
+        let feature_value = feature as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            feature_value as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 5)
+            PreviewFeature feature;
+            if (!previewFeatureFromRust(feature_value, &feature)) {
+                return false;
+            }
+            return ((const IBuilderConfig*) internal)->getPreviewFeature(feature);
+            #else
+            return false;
+            #endif
+        })
+    }
+
+    /// Set the platform that the engine will be executed on.
+    ///
+    /// This allows building an engine on one platform for execution on another. Building for a
+    /// different platform requires TensorRT 10.2 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `runtime_platform` - Platform that the engine will be executed on.
+    ///
+    /// # Return value
+    ///
+    /// An error if the platform is not supported by the version of TensorRT in use.
+    pub fn set_runtime_platform(&mut self, runtime_platform: RuntimePlatform) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let runtime_platform_value = runtime_platform as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            runtime_platform_value as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 2)
+            ((IBuilderConfig*) internal)->setRuntimePlatform(
+                static_cast<RuntimePlatform>(runtime_platform_value)
+            );
+            return true;
+            #else
+            // Building for the same platform is the only behavior older versions support.
+            return runtime_platform_value == 0;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(format!(
+                "runtime platform {runtime_platform:?} not supported by this version of TensorRT"
+            )))
+        }
+    }
+
+    /// Set the platform that the engine will be executed on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `runtime_platform` - Platform that the engine will be executed on.
+    pub fn with_runtime_platform(mut self, runtime_platform: RuntimePlatform) -> Result<Self> {
+        self.set_runtime_platform(runtime_platform)?;
+        Ok(self)
+    }
+
+    /// Get the platform that the engine will be executed on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn get_runtime_platform(&self) -> RuntimePlatform {
+        let internal = self.as_ptr();
+        let runtime_platform = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 2)
+            return (std::int32_t) ((const IBuilderConfig*) internal)->getRuntimePlatform();
+            #else
+            return 0;
+            #endif
+        });
+        RuntimePlatform::from_i32(runtime_platform)
+    }
+
+    /// Add an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ab97fa40c85fa8afab65fc2659e38da82)
+    pub fn with_optimization_profile(
+        mut self,
+        optimization_profile: OptimizationProfile,
+    ) -> Result<Self> {
+        self.add_optimization_profile(optimization_profile)?;
+        Ok(self)
+    }
+
+    /// Add an optimization profile.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html#ab97fa40c85fa8afab65fc2659e38da82)
+    pub fn add_optimization_profile(
+        &mut self,
+        optimization_profile: OptimizationProfile,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let optimization_profile = optimization_profile.as_ptr();
+        let index = cpp!(unsafe [
+            internal as "void*",
+            optimization_profile as "const IOptimizationProfile*"
+        ] -> i32 as "std::int32_t" {
+           return ((IBuilderConfig*) internal)->addOptimizationProfile(optimization_profile);
+        });
+        if index >= 0 {
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Set the optimization profile used to determine the shapes of the input tensors during INT8
+    /// calibration of a network with dynamic shapes.
+    ///
+    /// The optimal dimensions of the profile are used for calibration.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `optimization_profile` - Optimization profile to use for calibration.
+    pub fn set_calibration_profile(
+        &mut self,
+        optimization_profile: OptimizationProfile,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let optimization_profile = optimization_profile.as_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            optimization_profile as "const IOptimizationProfile*"
+        ] -> bool as "bool" {
+            return ((IBuilderConfig*) internal)->setCalibrationProfile(optimization_profile);
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Set the optimization profile used to determine the shapes of the input tensors during INT8
+    /// calibration of a network with dynamic shapes.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `optimization_profile` - Optimization profile to use for calibration.
+    pub fn with_calibration_profile(
+        mut self,
+        optimization_profile: OptimizationProfile,
+    ) -> Result<Self> {
+        self.set_calibration_profile(optimization_profile)?;
+        Ok(self)
+    }
+
+    /// Whether or not a calibration profile has been set.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    pub fn has_calibration_profile(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((IBuilderConfig*) internal)->getCalibrationProfile() != nullptr;
+        })
+    }
+
+    /// Create a timing cache.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `serialized` - Serialized timing cache to load. Pass an empty slice to create an empty
+    ///   timing cache.
+    pub fn create_timing_cache(&self, serialized: &[u8]) -> Result<TimingCache> {
+        let internal = self.as_ptr();
+        let blob_ptr = if serialized.is_empty() {
+            std::ptr::null()
+        } else {
+            serialized.as_ptr() as *const std::ffi::c_void
+        };
+        let blob_size = serialized.len();
+        let timing_cache_internal = cpp!(unsafe [
+            internal as "const void*",
+            blob_ptr as "const void*",
+            blob_size as "std::size_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) ((const IBuilderConfig*) internal)->createTimingCache(blob_ptr, blob_size);
+        });
+        result!(
+            timing_cache_internal,
+            TimingCache::wrap(timing_cache_internal)
+        )
+    }
+
+    /// Set the progress monitor for the build.
+    ///
+    /// Progress monitoring requires TensorRT 10 or up. On older versions the monitor is never
+    /// called.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - Progress monitor to use.
+    pub fn set_progress_monitor(&mut self, monitor: impl ProgressMonitor + 'static) {
+        self.progress_monitor_bridge()
+            .set_monitor(Box::new(monitor));
+    }
+
+    /// Set the progress monitor for the build.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - Progress monitor to use.
+    pub fn with_progress_monitor(mut self, monitor: impl ProgressMonitor + 'static) -> Self {
+        self.set_progress_monitor(monitor);
+        self
+    }
+
+    /// Cancel the build once `cancelled` is set.
+    ///
+    /// Cancellation requires TensorRT 10 or up. On older versions this has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancelled` - Flag that signals the build must be cancelled.
+    pub(crate) fn set_cancellation_flag(
+        &mut self,
+        cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    ) {
+        self.progress_monitor_bridge()
+            .set_cancellation_flag(cancelled);
+    }
+
+    /// Get the progress monitor bridge, and create and install it if it does not exist yet.
+    fn progress_monitor_bridge(&mut self) -> &mut ProgressMonitorBridge {
+        if self.progress_monitor.is_none() {
+            let internal = self.as_mut_ptr();
+            let mut progress_monitor = ProgressMonitorBridge::new();
+            let progress_monitor_internal = progress_monitor.as_mut_ptr();
+            cpp!(unsafe [
+                internal as "void*",
+                progress_monitor_internal as "void*"
+            ] {
+                #if NV_TENSORRT_MAJOR >= 10
+                ((IBuilderConfig*) internal)->setProgressMonitor(
+                    (IProgressMonitor*) progress_monitor_internal
+                );
+                #endif
+            });
+            self.progress_monitor = Some(progress_monitor);
+        }
+        self.progress_monitor.as_mut().unwrap()
+    }
+
+    /// Set the algorithm selector for the build.
+    ///
+    /// The selector decides which algorithms the builder may choose from for each node, which can
+    /// be used to pin tactic choices across builds.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Algorithm selector to use.
+    pub fn set_algorithm_selector(&mut self, selector: impl AlgorithmSelector + 'static) {
+        let internal = self.as_mut_ptr();
+        let mut algorithm_selector = AlgorithmSelectorBridge::new(Box::new(selector));
+        let algorithm_selector_internal = algorithm_selector.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            algorithm_selector_internal as "void*"
+        ] {
+            ((IBuilderConfig*) internal)->setAlgorithmSelector(
+                (IAlgorithmSelector*) algorithm_selector_internal
+            );
+        });
+        // Replacing the previous bridge drops it, which is safe since TensorRT no longer refers to
+        // it after the new selector has been set.
+        self.algorithm_selector = Some(algorithm_selector);
+    }
+
+    /// Set the algorithm selector for the build.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `selector` - Algorithm selector to use.
+    pub fn with_algorithm_selector(mut self, selector: impl AlgorithmSelector + 'static) -> Self {
+        self.set_algorithm_selector(selector);
+        self
+    }
+
+    /// Set the calibrator for INT8 calibration.
+    ///
+    /// The calibrator is only used when the `kINT8` flag is set (see [`BuilderConfig::set_flag()`])
+    /// and the network does not use explicit quantization. Use
+    /// [`BuilderConfig::set_calibration_profile()`] to pick the input shapes for networks with
+    /// dynamic shapes.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `calibrator` - Calibrator to use.
+    pub fn set_int8_calibrator(&mut self, calibrator: impl Calibrator + 'static) {
+        let internal = self.as_mut_ptr();
+        let mut int8_calibrator = CalibratorBridge::new(Box::new(calibrator));
+        let int8_calibrator_internal = int8_calibrator.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            int8_calibrator_internal as "void*"
+        ] {
+            #pragma GCC diagnostic push
+            #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+            ((IBuilderConfig*) internal)->setInt8Calibrator(
+                (IInt8Calibrator*) int8_calibrator_internal
+            );
+            #pragma GCC diagnostic pop
+        });
+        // Replacing the previous bridge drops it, which is safe since TensorRT no longer refers to
+        // it after the new calibrator has been set.
+        self.int8_calibrator = Some(int8_calibrator);
+    }
+
+    /// Tell the INT8 calibrator, if any, the size of the data of each network input.
+    ///
+    /// Dynamic dimensions are resolved with the optimum dimensions of the calibration profile. The
+    /// calibrator rejects batches for inputs whose size remains unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition that is going to be built.
+    pub(crate) fn prepare_int8_calibrator(&mut self, network_definition: &NetworkDefinition) {
+        if self.int8_calibrator.is_none() {
+            return;
+        }
+        let input_sizes = network_definition
+            .inputs()
+            .into_iter()
+            .filter_map(|input| {
+                let name = input.name();
+                let mut dims = input.get_dimensions();
+                if dims.is_dynamic() {
+                    dims = self.calibration_profile_dimensions(&name)?;
+                }
+                let num_elements = dims.num_elements()?;
+                let data_type = input.data_type()?;
+                Some((name, data_type.num_bytes(num_elements)))
+            })
+            .collect();
+        let implicit_batch = network_definition.has_implicit_batch_dimension();
+        if let Some(int8_calibrator) = self.int8_calibrator.as_mut() {
+            int8_calibrator.set_input_sizes(input_sizes, implicit_batch);
+        }
+    }
+
+    /// Get the optimum dimensions of an input in the calibration profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    ///
+    /// # Return value
+    ///
+    /// Dimensions, or `None` if there is no calibration profile or it has no dimensions for the
+    /// input.
+    fn calibration_profile_dimensions(&self, input_name: &str) -> Option<Dims> {
+        let internal = self.as_ptr();
+        let input_name_cstr = std::ffi::CString::new(input_name).unwrap();
+        let input_name_ptr = input_name_cstr.as_ptr();
+        let mut dims = vec![0i64; 8];
+        let dims_ptr = dims.as_mut_ptr();
+        let num_dimensions = cpp!(unsafe [
+            internal as "const void*",
+            input_name_ptr as "const char*",
+            dims_ptr as "int64_t*"
+        ] -> i32 as "int32_t" {
+            const IOptimizationProfile* profile =
+                ((const IBuilderConfig*) internal)->getCalibrationProfile();
+            if (profile == nullptr) {
+                return -1;
+            }
+            auto dims = profile->getDimensions(input_name_ptr, OptProfileSelector::kOPT);
+            for (int i = 0; i < dims.nbDims; ++i) {
+                dims_ptr[i] = dims.d[i];
+            }
+            return dims.nbDims;
+        });
+        if num_dimensions >= 0 {
+            dims.truncate(num_dimensions as usize);
+            Some(Dims::new(dims))
+        } else {
+            None
+        }
+    }
+
+    /// Set the calibrator for INT8 calibration.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `calibrator` - Calibrator to use.
+    pub fn with_int8_calibrator(mut self, calibrator: impl Calibrator + 'static) -> Self {
+        self.set_int8_calibrator(calibrator);
+        self
+    }
+
+    /// Attach a timing cache.
+    ///
+    /// The builder updates the timing cache while building. The configuration keeps a reference to
+    /// the cache, so callers can hold on to a clone of `timing_cache` and serialize it after the
+    /// build has finished to persist newly collected timings.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `timing_cache` - Timing cache to attach.
+    /// * `ignore_mismatch` - Whether or not to allow using a cache created on a different device.
+    pub fn set_timing_cache(
+        &mut self,
+        timing_cache: std::sync::Arc<TimingCache>,
+        ignore_mismatch: bool,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let timing_cache_internal = timing_cache.as_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            timing_cache_internal as "const void*",
+            ignore_mismatch as "bool"
+        ] -> bool as "bool" {
+            return ((IBuilderConfig*) internal)->setTimingCache(
+                *((const ITimingCache*) timing_cache_internal),
+                ignore_mismatch
+            );
+        });
+        if success {
+            self.timing_cache = Some(timing_cache);
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Attach a timing cache.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `timing_cache` - Timing cache to attach.
+    /// * `ignore_mismatch` - Whether or not to allow using a cache created on a different device.
+    pub fn with_timing_cache(
+        mut self,
+        timing_cache: std::sync::Arc<TimingCache>,
+        ignore_mismatch: bool,
+    ) -> Result<Self> {
+        self.set_timing_cache(timing_cache, ignore_mismatch)?;
+        Ok(self)
+    }
+
+    /// Get the timing cache that was attached with [`BuilderConfig::set_timing_cache()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_builder_config.html)
+    #[inline(always)]
+    pub fn get_timing_cache(&self) -> Option<&TimingCache> {
+        self.timing_cache.as_deref()
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let BuilderConfig { internal, .. } = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let BuilderConfig { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for BuilderConfig {
+    fn drop(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            destroy((IBuilderConfig*) internal);
+        });
+    }
+}
+
+/// Flags for configuring the builder.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html#abdc74c40fe7a0c3d05d2caeccfbc29c1)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum BuilderFlag {
+    /// Enable FP16 layer selection.
+    Fp16 = 0,
+    /// Enable INT8 layer selection.
+    Int8 = 1,
+    /// Enable debugging of layers via synchronizing after every layer.
+    Debug = 2,
+    /// Enable layers marked to execute on GPU if layer cannot execute on DLA.
+    GpuFallback = 3,
+    /// Enable building a refittable engine.
+    Refit = 4,
+    /// Disable reuse of timing information across identical layers.
+    DisableTimingCache = 5,
+    /// Allow (but not require) computations on tensors of type `Float` to use TF32.
+    Tf32 = 6,
+    /// Allow the builder to examine weights and use optimized functions when weights have suitable
+    /// sparsity.
+    SparseWeights = 7,
+    /// Change the allowed parameters in the `EngineCapability::Standard` flow to match the
+    /// restrictions that `EngineCapability::Safety` checks against.
+    SafetyScope = 8,
+    /// Require that layers execute in specified precisions.
+    ObeyPrecisionConstraints = 9,
+    /// Prefer that layers execute in specified precisions.
+    PreferPrecisionConstraints = 10,
+    /// Require that no reformats be inserted between a layer and a network IO tensor.
+    DirectIo = 11,
+    /// Fail if `IAlgorithmSelector::selectAlgorithms` returns an empty set of algorithms.
+    RejectEmptyAlgorithms = 12,
+    /// Restrict to lean runtime operators to provide version forward compatibility.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    VersionCompatible = 13,
+    /// Exclude the lean runtime from the plan when version compatibility is enabled.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    ExcludeLeanRuntime = 14,
+    /// Enable FP8 layer selection.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    Fp8 = 15,
+    /// Emit error when a tactic being timed is not present in the timing cache.
+    ///
+    /// Requires TensorRT 10 or later.
+    ErrorOnTimingCacheMiss = 16,
+    /// Enable BF16 layer selection.
+    ///
+    /// Requires TensorRT 10 or later.
+    Bf16 = 17,
+    /// Disable caching of JIT-compilation results during engine build.
+    ///
+    /// Requires TensorRT 10 or later.
+    DisableCompilationCache = 18,
+    /// Strip the refittable weights from the engine plan file.
+    ///
+    /// Requires TensorRT 10 or later.
+    StripPlan = 19,
+    /// Create a refittable engine under the assumption that the refit weights will be identical to
+    /// those provided at build time.
+    ///
+    /// Requires TensorRT 10 or later.
+    RefitIdentical = 20,
+    /// Enable weight streaming for the current engine.
+    ///
+    /// Requires TensorRT 10 or later.
+    WeightStreaming = 21,
+    /// Enable plugins with INT4 input and output.
+    ///
+    /// Requires TensorRT 10.1 or later.
+    Int4 = 22,
+}
+
+/// Memory pool that the builder can limit.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum MemoryPoolType {
+    /// GPU memory used by the engine as scratch space at execution time.
+    Workspace = 0,
+    /// Fast software managed SRAM used by DLA to communicate within a layer.
+    DlaManagedSram = 1,
+    /// Host RAM used by DLA to share intermediate tensor data across operations.
+    DlaLocalDram = 2,
+    /// Host RAM used by DLA to store weights and metadata for execution.
+    DlaGlobalDram = 3,
+    /// Host RAM used by the builder to store tactic data during the build.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    TacticDram = 4,
+    /// GPU shared memory used by tactics to execute concurrently.
+    ///
+    /// Requires TensorRT 10 or later.
+    TacticSharedMemory = 5,
+}
+
+/// Flags for configuring INT8 quantization.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum QuantizationFlag {
+    /// Run INT8 calibration before layer fusion.
+    CalibrateBeforeFusion = 0,
+}
+
+/// Experimental feature of TensorRT that can be enabled with
+/// [`BuilderConfig::set_preview_feature()`].
+///
+/// The suffix of each feature is the TensorRT version that introduced it.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum PreviewFeature {
+    /// Optimize runtime dimensions with TensorRT's DL compiler.
+    ///
+    /// Only supported by TensorRT 8.5 and 8.6.
+    FasterDynamicShapes0805 = 0,
+    /// Disable usage of cuDNN, cuBLAS and cuBLAS LT tactics in the TensorRT core library.
+    ///
+    /// Only supported by TensorRT 8.5 and 8.6.
+    DisableExternalTacticSourcesForCore0805 = 1,
+    /// Share optimization profiles between execution contexts.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    ProfileSharing0806 = 2,
+    /// Allow plugins to alias input and output tensors.
+    ///
+    /// Requires TensorRT 10.3 or later.
+    AliasedPluginIo1003 = 3,
+}
+
+/// Platform that an engine is built to be executed on.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[repr(i32)]
+pub enum RuntimePlatform {
+    /// Same platform as the one the engine is built on.
+    #[default]
+    SameAsBuild = 0,
+    /// Windows on x86-64.
+    ///
+    /// Requires TensorRT 10.2 or later.
+    WindowsAmd64 = 1,
+}
+
+impl RuntimePlatform {
+    /// Convert from integer representation as used by TensorRT.
+    ///
+    /// Unknown values are mapped to [`RuntimePlatform::SameAsBuild`].
+    fn from_i32(value: i32) -> Self {
+        match value {
+            1 => RuntimePlatform::WindowsAmd64,
+            _ => RuntimePlatform::SameAsBuild,
+        }
+    }
+}
+
+/// Set of tactic sources that the builder may use.
+///
+/// Tactic sources can be combined with `|`, for example
+/// `TacticSources::CUBLAS | TacticSources::CUBLAS_LT`.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TacticSources(u32);
+
+impl TacticSources {
+    /// cuBLAS tactics.
+    pub const CUBLAS: TacticSources = TacticSources(1 << 0);
+    /// cuBLAS LT tactics.
+    pub const CUBLAS_LT: TacticSources = TacticSources(1 << 1);
+    /// cuDNN tactics.
+    pub const CUDNN: TacticSources = TacticSources(1 << 2);
+    /// Convolution tactics with edge mask table.
+    pub const EDGE_MASK_CONVOLUTIONS: TacticSources = TacticSources(1 << 3);
+    /// Convolution tactics that use JIT compilation.
+    ///
+    /// Requires TensorRT 8.5 or later.
+    pub const JIT_CONVOLUTIONS: TacticSources = TacticSources(1 << 4);
+
+    /// No tactic sources.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        TacticSources(0)
+    }
+
+    /// All tactic sources.
+    #[inline(always)]
+    pub const fn all() -> Self {
+        TacticSources(
+            Self::CUBLAS.0
+                | Self::CUBLAS_LT.0
+                | Self::CUDNN.0
+                | Self::EDGE_MASK_CONVOLUTIONS.0
+                | Self::JIT_CONVOLUTIONS.0,
+        )
+    }
+
+    /// Create tactic sources from raw bits as used by TensorRT.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - Bitmask with bit `1 << TacticSource` set for each enabled tactic source.
+    #[inline(always)]
+    pub const fn from_bits(bits: u32) -> Self {
+        TacticSources(bits)
+    }
+
+    /// Get raw bits as used by TensorRT.
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Whether or not all tactic sources in `other` are contained in this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Tactic sources to check.
+    #[inline(always)]
+    pub const fn contains(&self, other: TacticSources) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether or not the set is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for TacticSources {
+    type Output = TacticSources;
+
+    #[inline(always)]
+    fn bitor(self, rhs: TacticSources) -> TacticSources {
+        TacticSources(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for TacticSources {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: TacticSources) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for TacticSources {
+    type Output = TacticSources;
+
+    #[inline(always)]
+    fn bitand(self, rhs: TacticSources) -> TacticSources {
+        TacticSources(self.0 & rhs.0)
+    }
+}
+
+impl std::ops::Sub for TacticSources {
+    type Output = TacticSources;
+
+    /// Remove the tactic sources in `rhs` from the set.
+    #[inline(always)]
+    fn sub(self, rhs: TacticSources) -> TacticSources {
+        TacticSources(self.0 & !rhs.0)
+    }
+}
+
+/// Combination of builder settings for a common use case, applied with
+/// [`BuilderConfig::set_preset()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Preset {
+    /// Minimize the latency of a single inference.
+    ///
+    /// Uses all tactic sources and the highest optimization level, and lets TensorRT decide how
+    /// many auxiliary streams to use so that independent branches of the network run in parallel.
+    /// The workspace size is not limited.
+    LowLatency,
+    /// Maximize the number of inferences per second when running multiple execution contexts
+    /// concurrently.
+    ///
+    /// Uses all tactic sources and the highest optimization level, but disables auxiliary
+    /// streams, since concurrency comes from the execution contexts and auxiliary streams cost
+    /// extra device memory per context. The workspace size is not limited.
+    MaxThroughput,
+    /// Minimize device memory usage.
+    ///
+    /// Limits the workspace to 128 MiB, disables auxiliary streams and restricts tactics to the
+    /// ones built into TensorRT, so that cuBLAS and cuDNN do not have to be loaded. Uses the
+    /// default optimization level.
+    MinMemory,
+}
+
+/// Device that a layer or engine executes on.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum DeviceType {
+    /// GPU device.
+    Gpu = 0,
+    /// DLA core.
+    Dla = 1,
+}
+
+/// Highest builder optimization level.
+const MAX_BUILDER_OPTIMIZATION_LEVEL: i32 = 5;
+
+/// Workspace size of [`Preset::MinMemory`].
+const MIN_MEMORY_WORKSPACE_SIZE: usize = 128 << 20;
+
+/// Create error for builder flag that is not supported by the version of TensorRT in use.
+///
+/// # Arguments
+///
+/// * `flag` - Unsupported flag.
+fn unsupported_flag_error(flag: BuilderFlag) -> crate::error::Error {
+    crate::error::Error::InvalidArgument(format!(
+        "builder flag {flag:?} not supported by this version of TensorRT"
+    ))
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::ffi::algorithm_selector::{Algorithm, AlgorithmContext};
+    use crate::tests::utils::*;
+    use crate::Builder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_builder_config_set_and_clear_flag() {
+        let mut builder = Builder::new().await.unwrap();
+        let mut config = builder.config().await.with_refit();
+        assert!(config.get_flag(BuilderFlag::Refit));
+        assert!(!config.get_flag(BuilderFlag::Debug));
+        config.set_flag(BuilderFlag::Debug).unwrap();
+        assert!(config.get_flag(BuilderFlag::Debug));
+        config.clear_flag(BuilderFlag::Debug).unwrap();
+        assert!(!config.get_flag(BuilderFlag::Debug));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_dla_core() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder
+            .config()
+            .await
+            .with_default_device_type(DeviceType::Gpu)
+            .with_dla_core(0);
+        assert_eq!(config.get_dla_core(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_engine_capability() {
+        let mut builder = Builder::new().await.unwrap();
+        let mut config = builder.config().await;
+        assert_eq!(config.get_engine_capability(), EngineCapability::Standard);
+        config.set_engine_capability(EngineCapability::Safety);
+        assert_eq!(config.get_engine_capability(), EngineCapability::Safety);
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_max_aux_streams() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder.config().await.with_max_aux_streams(2).unwrap();
+        assert_eq!(config.get_max_aux_streams(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_fp8() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder.config().await.with_fp8().unwrap();
+        assert!(config.get_flag(BuilderFlag::Fp8));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_memory_pool_limit() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder
+            .config()
+            .await
+            .with_memory_pool_limit(MemoryPoolType::Workspace, 1 << 20)
+            .unwrap();
+        assert_eq!(
+            config.get_memory_pool_limit(MemoryPoolType::Workspace),
+            1 << 20
+        );
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_preview_feature() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder
+            .config()
+            .await
+            .with_preview_feature(PreviewFeature::ProfileSharing0806, true)
+            .unwrap();
+        assert!(config.get_preview_feature(PreviewFeature::ProfileSharing0806));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_runtime_platform() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder
+            .config()
+            .await
+            .with_runtime_platform(RuntimePlatform::SameAsBuild)
+            .unwrap();
+        assert_eq!(config.get_runtime_platform(), RuntimePlatform::SameAsBuild);
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_quantization_flag() {
+        let mut builder = Builder::new().await.unwrap();
+        let mut config = builder
+            .config()
+            .await
+            .with_quantization_flag(QuantizationFlag::CalibrateBeforeFusion);
+        assert!(config.get_quantization_flag(QuantizationFlag::CalibrateBeforeFusion));
+        config.clear_quantization_flag(QuantizationFlag::CalibrateBeforeFusion);
+        assert!(!config.get_quantization_flag(QuantizationFlag::CalibrateBeforeFusion));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_tactic_sources() {
+        let mut builder = Builder::new().await.unwrap();
+        let tactic_sources = TacticSources::CUBLAS | TacticSources::CUBLAS_LT;
+        let config = builder
+            .config()
+            .await
+            .with_tactic_sources(tactic_sources)
+            .unwrap();
+        let tactic_sources = config.get_tactic_sources();
+        assert!(tactic_sources.contains(TacticSources::CUBLAS_LT));
+        assert!(!tactic_sources.contains(TacticSources::CUDNN));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_builder_optimization_level() {
+        let mut builder = Builder::new().await.unwrap();
+        let mut config = builder
+            .config()
+            .await
+            .with_builder_optimization_level(1)
+            .unwrap();
+        assert_eq!(config.get_builder_optimization_level(), Some(1));
+        assert!(config.set_builder_optimization_level(6).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_avg_timing_iterations() {
+        let mut builder = Builder::new().await.unwrap();
+        let mut config = builder
+            .config()
+            .await
+            .with_avg_timing_iterations(2)
+            .unwrap();
+        assert_eq!(config.get_avg_timing_iterations(), 2);
+        assert!(config.set_avg_timing_iterations(0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_max_nb_tactics() {
+        let (mut builder, mut network) = simple_network!();
+        let config = builder.config().await.with_max_nb_tactics(1).unwrap();
+        assert_eq!(config.get_max_nb_tactics(), Some(1));
+        builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_preset() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder
+            .config()
+            .await
+            .with_preset(Preset::MinMemory)
+            .unwrap();
+        assert_eq!(
+            config.get_memory_pool_limit(MemoryPoolType::Workspace),
+            128 << 20
+        );
+        assert_eq!(config.get_max_aux_streams(), Some(0));
+        assert!(!config.get_tactic_sources().contains(TacticSources::CUDNN));
+        let config = builder
+            .config()
+            .await
+            .with_preset(Preset::LowLatency)
+            .unwrap();
+        assert_eq!(config.get_builder_optimization_level(), Some(5));
+        assert!(config.get_tactic_sources().contains(TacticSources::CUDNN));
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_algorithm_selector() {
+        #[derive(Default)]
+        struct Selections {
+            selected: usize,
+            reported: Vec<(AlgorithmContext, Algorithm)>,
+        }
+
+        struct FirstAlgorithmSelector(Arc<Mutex<Selections>>);
+
+        impl AlgorithmSelector for FirstAlgorithmSelector {
+            fn select_algorithms(
+                &mut self,
+                _context: &AlgorithmContext,
+                choices: &[Algorithm],
+            ) -> Vec<usize> {
+                assert!(!choices.is_empty());
+                self.0.lock().unwrap().selected += 1;
+                vec![0]
+            }
+
+            fn report_algorithms(&mut self, choices: &[(AlgorithmContext, Algorithm)]) {
+                self.0.lock().unwrap().reported = choices.to_vec();
+            }
+        }
+
+        let selections = Arc::new(Mutex::new(Selections::default()));
+        let (mut builder, mut network) = simple_network!();
+        let config = builder
+            .config()
+            .await
+            .with_algorithm_selector(FirstAlgorithmSelector(selections.clone()));
+        builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .unwrap();
+        let selections = selections.lock().unwrap();
+        assert!(selections.selected > 0);
+        assert!(!selections.reported.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_builder_config_progress_monitor() {
+        #[derive(Default)]
+        struct Phases {
+            started: Vec<String>,
+            finished: Vec<String>,
+        }
+
+        struct TestProgressMonitor(Arc<Mutex<Phases>>);
+
+        impl ProgressMonitor for TestProgressMonitor {
+            fn phase_start(&mut self, phase_name: &str, _parent_phase: Option<&str>, _: usize) {
+                self.0.lock().unwrap().started.push(phase_name.to_string());
+            }
+
+            fn step_complete(&mut self, _phase_name: &str, _step: usize) -> bool {
+                true
+            }
+
+            fn phase_finish(&mut self, phase_name: &str) {
+                self.0.lock().unwrap().finished.push(phase_name.to_string());
+            }
+        }
+
+        let phases = Arc::new(Mutex::new(Phases::default()));
+        let (mut builder, mut network) = simple_network!();
+        let config = builder
+            .config()
+            .await
+            .with_progress_monitor(TestProgressMonitor(phases.clone()));
+        builder
+            .build_serialized_network(&mut network, config)
+            .await
+            .unwrap();
+        let phases = phases.lock().unwrap();
+        assert_eq!(phases.started.len(), phases.finished.len());
     }
 }
@@ -0,0 +1,523 @@
+use cpp::cpp;
+
+use crate::ffi::memory::HostBuffer;
+
+cpp! {{
+    #ifndef ODDITY_FFI_CALIBRATOR
+    #define ODDITY_FFI_CALIBRATOR
+
+    #ifndef ODDITY_FFI_DISPATCH
+    // Functions that forward calibrator calls to the Rust calibrator state. The state is owned by
+    // the Rust side, the calibrators below only hold a pointer to it.
+    std::int32_t rustCalibratorGetBatchSize(void* state) noexcept {
+        return rust!(RustCalibrator_getBatchSize [
+            state : *const std::ffi::c_void as "void*"
+        ] -> i32 as "std::int32_t" {
+            get_batch_size_raw(state)
+        });
+    }
+
+    bool rustCalibratorGetBatch(
+        void* state,
+        void** bindings,
+        const char** names,
+        std::int32_t nbBindings
+    ) noexcept {
+        return rust!(RustCalibrator_getBatch [
+            state : *const std::ffi::c_void as "void*",
+            bindings : *mut *mut std::ffi::c_void as "void**",
+            names : *const *const std::os::raw::c_char as "const char**",
+            nbBindings : i32 as "std::int32_t"
+        ] -> bool as "bool" {
+            get_batch_raw(state, bindings, names, nbBindings)
+        });
+    }
+
+    const void* rustCalibratorReadCalibrationCache(void* state, std::size_t* length) noexcept {
+        return rust!(RustCalibrator_readCalibrationCache [
+            state : *const std::ffi::c_void as "void*",
+            length : *mut usize as "std::size_t*"
+        ] -> *const std::ffi::c_void as "const void*" {
+            read_calibration_cache_raw(state, length)
+        });
+    }
+
+    void rustCalibratorWriteCalibrationCache(
+        void* state,
+        const void* cache,
+        std::size_t length
+    ) noexcept {
+        rust!(RustCalibrator_writeCalibrationCache [
+            state : *const std::ffi::c_void as "void*",
+            cache : *const std::ffi::c_void as "const void*",
+            length : usize as "std::size_t"
+        ] {
+            write_calibration_cache_raw(state, cache, length);
+        });
+    }
+
+    // Note that implicit quantization, and with it the calibrator interfaces, is deprecated since
+    // TensorRT 10.1 in favor of explicit quantization.
+    #pragma GCC diagnostic push
+    #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+    class RustEntropyCalibrator : public IInt8EntropyCalibrator2
+    {
+    public:
+        RustEntropyCalibrator(void* state) : m_state(state) {}
+
+        std::int32_t getBatchSize() const noexcept override {
+            return rustCalibratorGetBatchSize(m_state);
+        }
+
+        bool getBatch(
+            void* bindings[],
+            const char* names[],
+            std::int32_t nbBindings
+        ) noexcept override {
+            return rustCalibratorGetBatch(m_state, bindings, names, nbBindings);
+        }
+
+        const void* readCalibrationCache(std::size_t& length) noexcept override {
+            return rustCalibratorReadCalibrationCache(m_state, &length);
+        }
+
+        void writeCalibrationCache(const void* cache, std::size_t length) noexcept override {
+            rustCalibratorWriteCalibrationCache(m_state, cache, length);
+        }
+
+    private:
+        void* m_state;
+    };
+
+    class RustMinMaxCalibrator : public IInt8MinMaxCalibrator
+    {
+    public:
+        RustMinMaxCalibrator(void* state) : m_state(state) {}
+
+        std::int32_t getBatchSize() const noexcept override {
+            return rustCalibratorGetBatchSize(m_state);
+        }
+
+        bool getBatch(
+            void* bindings[],
+            const char* names[],
+            std::int32_t nbBindings
+        ) noexcept override {
+            return rustCalibratorGetBatch(m_state, bindings, names, nbBindings);
+        }
+
+        const void* readCalibrationCache(std::size_t& length) noexcept override {
+            return rustCalibratorReadCalibrationCache(m_state, &length);
+        }
+
+        void writeCalibrationCache(const void* cache, std::size_t length) noexcept override {
+            rustCalibratorWriteCalibrationCache(m_state, cache, length);
+        }
+
+    private:
+        void* m_state;
+    };
+    #pragma GCC diagnostic pop
+    #endif
+
+    #endif // ODDITY_FFI_CALIBRATOR
+}}
+
+/// Algorithm used to compute the INT8 scale factors during calibration.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CalibrationAlgorithm {
+    /// Entropy calibration (`IInt8EntropyCalibrator2`). Recommended for CNN-based networks.
+    #[default]
+    Entropy,
+    /// MinMax calibration (`IInt8MinMaxCalibrator`). Recommended for NLP tasks.
+    MinMax,
+}
+
+/// Calibrator that provides batches of input data for INT8 calibration.
+///
+/// Install a calibrator with [`crate::BuilderConfig::set_int8_calibrator()`]. The calibrator is
+/// only used when the `kINT8` flag is set and the network does not use explicit quantization.
+///
+/// See [`crate::EntropyCalibrator`] and [`crate::MinMaxCalibrator`] for ready-made calibrators.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_int8_calibrator.html)
+pub trait Calibrator: Send {
+    /// Algorithm to calibrate with.
+    ///
+    /// This is queried once, when the calibrator is installed.
+    fn algorithm(&self) -> CalibrationAlgorithm {
+        CalibrationAlgorithm::default()
+    }
+
+    /// Batch size of the batches returned by [`Calibrator::next_batch()`].
+    ///
+    /// For networks with an explicit batch dimension this should be 1.
+    fn batch_size(&self) -> usize;
+
+    /// Get the next batch of input data.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_names` - Names of the network inputs to provide data for.
+    ///
+    /// # Return value
+    ///
+    /// Input data for each of the inputs in `input_names`, in the same order, or `None` if there
+    /// are no batches left.
+    fn next_batch(&mut self, input_names: &[&str]) -> Option<Vec<HostBuffer>>;
+
+    /// Read a previously written calibration cache.
+    ///
+    /// If this returns a cache, the builder uses it instead of running calibration.
+    ///
+    /// # Return value
+    ///
+    /// The calibration cache, or `None` if there is none.
+    fn read_calibration_cache(&mut self) -> Option<Vec<u8>>;
+
+    /// Write the calibration cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Calibration cache.
+    fn write_calibration_cache(&mut self, cache: &[u8]);
+}
+
+/// State shared between a [`CalibratorBridge`] and the C++ calibrator.
+struct CalibratorState {
+    calibrator: Box<dyn Calibrator>,
+    /// Device memory that batches are copied into, one per input.
+    device_memory: Vec<DeviceMemory>,
+    /// Expected size of the data of each input in bytes, per batch item if `implicit_batch`.
+    input_sizes: std::collections::HashMap<String, usize>,
+    /// Whether or not the network has an implicit batch dimension.
+    implicit_batch: bool,
+    /// Calibration cache returned to TensorRT, which must stay alive until the next read.
+    cache: Option<Vec<u8>>,
+}
+
+/// Bridge between a Rust [`Calibrator`] and a TensorRT `IInt8Calibrator`.
+///
+/// Owns both the Rust calibrator and the C++ object that forwards to it.
+pub(crate) struct CalibratorBridge {
+    internal: *mut std::ffi::c_void,
+    #[allow(dead_code)]
+    state: Box<std::sync::Mutex<CalibratorState>>,
+}
+
+/// Implements [`Send`] for [`CalibratorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust calibrator, which is [`Send`] itself.
+unsafe impl Send for CalibratorBridge {}
+
+/// Implements [`Sync`] for [`CalibratorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust calibrator, which is behind a mutex.
+unsafe impl Sync for CalibratorBridge {}
+
+impl CalibratorBridge {
+    /// Create a new bridge that forwards to `calibrator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `calibrator` - Calibrator.
+    pub(crate) fn new(calibrator: Box<dyn Calibrator>) -> Self {
+        let algorithm = calibrator.algorithm();
+        let state = Box::new(std::sync::Mutex::new(CalibratorState {
+            calibrator,
+            device_memory: Vec::new(),
+            input_sizes: std::collections::HashMap::new(),
+            implicit_batch: false,
+            cache: None,
+        }));
+        let state_ptr =
+            state.as_ref() as *const std::sync::Mutex<CalibratorState> as *const std::ffi::c_void;
+        let internal = match algorithm {
+            CalibrationAlgorithm::Entropy => cpp!(unsafe [
+                state_ptr as "const void*"
+            ] -> *mut std::ffi::c_void as "void*" {
+                #pragma GCC diagnostic push
+                #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+                return (void*) static_cast<IInt8Calibrator*>(
+                    new RustEntropyCalibrator((void*) state_ptr)
+                );
+                #pragma GCC diagnostic pop
+            }),
+            CalibrationAlgorithm::MinMax => cpp!(unsafe [
+                state_ptr as "const void*"
+            ] -> *mut std::ffi::c_void as "void*" {
+                #pragma GCC diagnostic push
+                #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+                return (void*) static_cast<IInt8Calibrator*>(
+                    new RustMinMaxCalibrator((void*) state_ptr)
+                );
+                #pragma GCC diagnostic pop
+            }),
+        };
+        CalibratorBridge { internal, state }
+    }
+
+    /// Set the expected size of the data of each network input.
+    ///
+    /// Batches for inputs without a known size, or with data of a different size, are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_sizes` - Size of the data of each input in bytes, per batch item if
+    ///   `implicit_batch` is set.
+    /// * `implicit_batch` - Whether or not the network has an implicit batch dimension.
+    pub(crate) fn set_input_sizes(
+        &mut self,
+        input_sizes: std::collections::HashMap<String, usize>,
+        implicit_batch: bool,
+    ) {
+        let state = self.state.get_mut().unwrap();
+        state.input_sizes = input_sizes;
+        state.implicit_batch = implicit_batch;
+    }
+
+    /// Get internal pointer to `IInt8Calibrator`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let CalibratorBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for CalibratorBridge {
+    fn drop(&mut self) {
+        let CalibratorBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #pragma GCC diagnostic push
+            #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+            delete ((IInt8Calibrator*) internal);
+            #pragma GCC diagnostic pop
+        });
+    }
+}
+
+/// Device memory that calibration batches are copied into.
+struct DeviceMemory {
+    internal: *mut std::ffi::c_void,
+    size: usize,
+}
+
+/// Implements [`Send`] for [`DeviceMemory`].
+///
+/// # Safety
+///
+/// The memory is only accessed through the calibrator state, which is behind a mutex.
+unsafe impl Send for DeviceMemory {}
+
+impl DeviceMemory {
+    /// Create empty device memory.
+    fn new() -> Self {
+        DeviceMemory {
+            internal: std::ptr::null_mut(),
+            size: 0,
+        }
+    }
+
+    /// Copy `data` into device memory, and grow the memory first if it is too small.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data to copy.
+    ///
+    /// # Return value
+    ///
+    /// `false` if allocating or copying failed.
+    fn copy_from_host(&mut self, data: &[u8]) -> bool {
+        let internal_ptr = &mut self.internal as *mut *mut std::ffi::c_void;
+        let size = self.size;
+        let data_ptr = data.as_ptr() as *const std::ffi::c_void;
+        let data_size = data.len();
+        let success = cpp!(unsafe [
+            internal_ptr as "void**",
+            size as "std::size_t",
+            data_ptr as "const void*",
+            data_size as "std::size_t"
+        ] -> bool as "bool" {
+            if (size < data_size) {
+                cudaFree(*internal_ptr);
+                *internal_ptr = nullptr;
+                if (cudaMalloc(internal_ptr, data_size) != cudaSuccess) {
+                    *internal_ptr = nullptr;
+                    return false;
+                }
+            }
+            return cudaMemcpy(
+                *internal_ptr,
+                data_ptr,
+                data_size,
+                cudaMemcpyHostToDevice
+            ) == cudaSuccess;
+        });
+        self.size = if self.internal.is_null() {
+            0
+        } else {
+            size.max(data_size)
+        };
+        success
+    }
+}
+
+impl Drop for DeviceMemory {
+    fn drop(&mut self) {
+        let DeviceMemory { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            cudaFree(internal);
+        });
+    }
+}
+
+/// Raw handler for `IInt8Calibrator::getBatchSize`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live calibrator state.
+unsafe fn get_batch_size_raw(state: *const std::ffi::c_void) -> i32 {
+    let state = &*(state as *const std::sync::Mutex<CalibratorState>);
+    let batch_size = state.lock().unwrap().calibrator.batch_size();
+    batch_size.min(i32::MAX as usize) as i32
+}
+
+/// Raw handler for `IInt8Calibrator::getBatch`.
+///
+/// # Return value
+///
+/// `false` if there are no batches left, if the batch does not match the size of the inputs, or
+/// if the batch could not be copied to the device.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live calibrator state, and that `bindings` and
+/// `names` point to `num_bindings` device pointers and valid C strings respectively.
+unsafe fn get_batch_raw(
+    state: *const std::ffi::c_void,
+    bindings: *mut *mut std::ffi::c_void,
+    names: *const *const std::os::raw::c_char,
+    num_bindings: i32,
+) -> bool {
+    let state = &*(state as *const std::sync::Mutex<CalibratorState>);
+    let mut state = state.lock().unwrap();
+    let num_bindings = num_bindings.max(0) as usize;
+    let names = (0..num_bindings)
+        .map(|index| {
+            std::ffi::CStr::from_ptr(*names.add(index))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect::<Vec<_>>();
+    let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+    let Some(batch) = state.calibrator.next_batch(&names) else {
+        return false;
+    };
+    if batch.len() != num_bindings {
+        tracing::error!(
+            num_inputs = num_bindings,
+            num_buffers = batch.len(),
+            "calibrator returned wrong number of input buffers"
+        );
+        return false;
+    }
+    let CalibratorState {
+        calibrator,
+        device_memory,
+        input_sizes,
+        implicit_batch,
+        ..
+    } = &mut *state;
+    let batch_size = if *implicit_batch {
+        calibrator.batch_size()
+    } else {
+        1
+    };
+    for (name, data) in names.iter().zip(batch.iter()) {
+        let expected_size = input_sizes.get(*name).map(|size| size * batch_size);
+        if expected_size != Some(data.len()) {
+            tracing::error!(
+                input = *name,
+                expected_size = ?expected_size,
+                size = data.len(),
+                "calibration batch has wrong size"
+            );
+            return false;
+        }
+    }
+    device_memory.resize_with(num_bindings, DeviceMemory::new);
+    for (index, (memory, data)) in device_memory.iter_mut().zip(batch.iter()).enumerate() {
+        if !memory.copy_from_host(data.as_bytes()) {
+            tracing::error!(
+                input = names[index],
+                "failed to copy calibration batch to device"
+            );
+            return false;
+        }
+        *bindings.add(index) = memory.internal;
+    }
+    true
+}
+
+/// Raw handler for `IInt8Calibrator::readCalibrationCache`.
+///
+/// # Return value
+///
+/// Pointer to the calibration cache, or null if there is none.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live calibrator state and that `length` is a
+/// valid pointer.
+unsafe fn read_calibration_cache_raw(
+    state: *const std::ffi::c_void,
+    length: *mut usize,
+) -> *const std::ffi::c_void {
+    let state = &*(state as *const std::sync::Mutex<CalibratorState>);
+    let mut state = state.lock().unwrap();
+    state.cache = state.calibrator.read_calibration_cache();
+    match state.cache.as_ref() {
+        Some(cache) if !cache.is_empty() => {
+            *length = cache.len();
+            cache.as_ptr() as *const std::ffi::c_void
+        }
+        _ => {
+            *length = 0;
+            std::ptr::null()
+        }
+    }
+}
+
+/// Raw handler for `IInt8Calibrator::writeCalibrationCache`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live calibrator state and that `cache` points
+/// to `length` bytes.
+unsafe fn write_calibration_cache_raw(
+    state: *const std::ffi::c_void,
+    cache: *const std::ffi::c_void,
+    length: usize,
+) {
+    let state = &*(state as *const std::sync::Mutex<CalibratorState>);
+    let cache = if length > 0 {
+        std::slice::from_raw_parts(cache as *const u8, length)
+    } else {
+        &[]
+    };
+    state
+        .lock()
+        .unwrap()
+        .calibrator
+        .write_calibration_cache(cache);
+}
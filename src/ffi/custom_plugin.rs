@@ -0,0 +1,984 @@
+use cpp::cpp;
+
+use crate::ffi::data_type::DataType;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+cpp! {{
+    #ifndef ODDITY_FFI_CUSTOM_PLUGIN
+    #define ODDITY_FFI_CUSTOM_PLUGIN
+
+    #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+    #include <vector>
+
+    // Plugin that forwards all calls to a Rust implementation of `Plugin`. The plugin owns the
+    // Rust plugin and drops it when it is deleted.
+    class RustPluginV3 :
+        public IPluginV3,
+        public IPluginV3OneCore,
+        public IPluginV3OneBuild,
+        public IPluginV3OneRuntime
+    {
+    public:
+        RustPluginV3(
+            void* plugin,
+            const std::string& name,
+            const std::string& version,
+            const std::string& pluginNamespace
+        ) : m_plugin(plugin), m_name(name), m_version(version), m_namespace(pluginNamespace) {}
+
+        ~RustPluginV3() override {
+            void* plugin = m_plugin;
+            rust!(RustPluginV3_drop [
+                plugin : *mut std::ffi::c_void as "void*"
+            ] {
+                drop_plugin_raw(plugin);
+            });
+        }
+
+        IPluginCapability* getCapabilityInterface(PluginCapabilityType type) noexcept override {
+            switch (type) {
+                case PluginCapabilityType::kCORE: return static_cast<IPluginV3OneCore*>(this);
+                case PluginCapabilityType::kBUILD: return static_cast<IPluginV3OneBuild*>(this);
+                case PluginCapabilityType::kRUNTIME: return static_cast<IPluginV3OneRuntime*>(this);
+            }
+            return nullptr;
+        }
+
+        IPluginV3* clone() noexcept override {
+            void* plugin = m_plugin;
+            void* cloned = rust!(RustPluginV3_clone [
+                plugin : *const std::ffi::c_void as "void*"
+            ] -> *mut std::ffi::c_void as "void*" {
+                clone_plugin_raw(plugin)
+            });
+            return new RustPluginV3(cloned, m_name, m_version, m_namespace);
+        }
+
+        AsciiChar const* getPluginName() const noexcept override {
+            return m_name.c_str();
+        }
+
+        AsciiChar const* getPluginVersion() const noexcept override {
+            return m_version.c_str();
+        }
+
+        AsciiChar const* getPluginNamespace() const noexcept override {
+            return m_namespace.c_str();
+        }
+
+        std::int32_t getNbOutputs() const noexcept override {
+            void* plugin = m_plugin;
+            return rust!(RustPluginV3_getNbOutputs [
+                plugin : *const std::ffi::c_void as "void*"
+            ] -> i32 as "std::int32_t" {
+                num_outputs_raw(plugin)
+            });
+        }
+
+        std::int32_t configurePlugin(
+            DynamicPluginTensorDesc const* in,
+            std::int32_t nbInputs,
+            DynamicPluginTensorDesc const* out,
+            std::int32_t nbOutputs
+        ) noexcept override {
+            return 0;
+        }
+
+        std::int32_t getOutputDataTypes(
+            DataType* outputTypes,
+            std::int32_t nbOutputs,
+            DataType const* inputTypes,
+            std::int32_t nbInputs
+        ) const noexcept override {
+            std::vector<std::int32_t> inputTypesRaw(nbInputs);
+            for (std::int32_t i = 0; i < nbInputs; i++) {
+                inputTypesRaw[i] = static_cast<std::int32_t>(inputTypes[i]);
+            }
+            std::vector<std::int32_t> outputTypesRaw(nbOutputs, 0);
+            void* plugin = m_plugin;
+            const std::int32_t* inputTypesPtr = inputTypesRaw.data();
+            std::int32_t* outputTypesPtr = outputTypesRaw.data();
+            bool success = rust!(RustPluginV3_getOutputDataTypes [
+                plugin : *const std::ffi::c_void as "void*",
+                inputTypesPtr : *const i32 as "const std::int32_t*",
+                nbInputs : i32 as "std::int32_t",
+                outputTypesPtr : *mut i32 as "std::int32_t*",
+                nbOutputs : i32 as "std::int32_t"
+            ] -> bool as "bool" {
+                output_data_types_raw(plugin, inputTypesPtr, nbInputs, outputTypesPtr, nbOutputs)
+            });
+            if (!success) {
+                return -1;
+            }
+            for (std::int32_t i = 0; i < nbOutputs; i++) {
+                outputTypes[i] = static_cast<DataType>(outputTypesRaw[i]);
+            }
+            return 0;
+        }
+
+        std::int32_t getOutputShapes(
+            DimsExprs const* inputs,
+            std::int32_t nbInputs,
+            DimsExprs const* shapeInputs,
+            std::int32_t nbShapeInputs,
+            DimsExprs* outputs,
+            std::int32_t nbOutputs,
+            IExprBuilder& exprBuilder
+        ) noexcept override {
+            // Only constant input dimensions are supported, so that the shape inference can be
+            // performed on plain integers on the Rust side.
+            std::vector<std::int32_t> inputNbDims(nbInputs);
+            std::vector<std::int64_t> inputDims(nbInputs * Dims::MAX_DIMS, 0);
+            for (std::int32_t i = 0; i < nbInputs; i++) {
+                inputNbDims[i] = inputs[i].nbDims;
+                for (std::int32_t j = 0; j < inputs[i].nbDims; j++) {
+                    if (!inputs[i].d[j]->isConstant()) {
+                        return -1;
+                    }
+                    inputDims[i * Dims::MAX_DIMS + j] = inputs[i].d[j]->getConstantValue();
+                }
+            }
+            std::vector<std::int32_t> outputNbDims(nbOutputs, 0);
+            std::vector<std::int64_t> outputDims(nbOutputs * Dims::MAX_DIMS, 0);
+            void* plugin = m_plugin;
+            const std::int32_t* inputNbDimsPtr = inputNbDims.data();
+            const std::int64_t* inputDimsPtr = inputDims.data();
+            std::int32_t* outputNbDimsPtr = outputNbDims.data();
+            std::int64_t* outputDimsPtr = outputDims.data();
+            bool success = rust!(RustPluginV3_getOutputShapes [
+                plugin : *const std::ffi::c_void as "void*",
+                inputNbDimsPtr : *const i32 as "const std::int32_t*",
+                inputDimsPtr : *const i64 as "const std::int64_t*",
+                nbInputs : i32 as "std::int32_t",
+                outputNbDimsPtr : *mut i32 as "std::int32_t*",
+                outputDimsPtr : *mut i64 as "std::int64_t*",
+                nbOutputs : i32 as "std::int32_t"
+            ] -> bool as "bool" {
+                output_shapes_raw(
+                    plugin,
+                    inputNbDimsPtr,
+                    inputDimsPtr,
+                    nbInputs,
+                    outputNbDimsPtr,
+                    outputDimsPtr,
+                    nbOutputs,
+                )
+            });
+            if (!success) {
+                return -1;
+            }
+            for (std::int32_t i = 0; i < nbOutputs; i++) {
+                outputs[i].nbDims = outputNbDims[i];
+                for (std::int32_t j = 0; j < outputNbDims[i]; j++) {
+                    outputs[i].d[j] = exprBuilder.constant(outputDims[i * Dims::MAX_DIMS + j]);
+                }
+            }
+            return 0;
+        }
+
+        bool supportsFormatCombination(
+            std::int32_t pos,
+            DynamicPluginTensorDesc const* inOut,
+            std::int32_t nbInputs,
+            std::int32_t nbOutputs
+        ) noexcept override {
+            if (inOut[pos].desc.format != TensorFormat::kLINEAR) {
+                return false;
+            }
+            void* plugin = m_plugin;
+            std::int32_t dataType = static_cast<std::int32_t>(inOut[pos].desc.type);
+            return rust!(RustPluginV3_supportsFormatCombination [
+                plugin : *const std::ffi::c_void as "void*",
+                pos : i32 as "std::int32_t",
+                dataType : i32 as "std::int32_t"
+            ] -> bool as "bool" {
+                supports_data_type_raw(plugin, pos, dataType)
+            });
+        }
+
+        std::int32_t onShapeChange(
+            PluginTensorDesc const* in,
+            std::int32_t nbInputs,
+            PluginTensorDesc const* out,
+            std::int32_t nbOutputs
+        ) noexcept override {
+            // `enqueue` does not receive the number of inputs and outputs, so we remember them.
+            m_nbInputs = nbInputs;
+            m_nbOutputs = nbOutputs;
+            return 0;
+        }
+
+        std::int32_t enqueue(
+            PluginTensorDesc const* inputDesc,
+            PluginTensorDesc const* outputDesc,
+            void const* const* inputs,
+            void* const* outputs,
+            void* workspace,
+            cudaStream_t stream
+        ) noexcept override {
+            std::int32_t nbInputs = m_nbInputs;
+            std::int32_t nbOutputs = m_nbOutputs;
+            std::vector<std::int32_t> types(nbInputs + nbOutputs);
+            std::vector<std::int32_t> nbDims(nbInputs + nbOutputs);
+            std::vector<std::int64_t> dims((nbInputs + nbOutputs) * Dims::MAX_DIMS, 0);
+            for (std::int32_t i = 0; i < nbInputs + nbOutputs; i++) {
+                const PluginTensorDesc& desc =
+                    i < nbInputs ? inputDesc[i] : outputDesc[i - nbInputs];
+                types[i] = static_cast<std::int32_t>(desc.type);
+                nbDims[i] = desc.dims.nbDims;
+                for (std::int32_t j = 0; j < desc.dims.nbDims; j++) {
+                    dims[i * Dims::MAX_DIMS + j] = desc.dims.d[j];
+                }
+            }
+            void* plugin = m_plugin;
+            const std::int32_t* typesPtr = types.data();
+            const std::int32_t* nbDimsPtr = nbDims.data();
+            const std::int64_t* dimsPtr = dims.data();
+            const void* streamPtr = (const void*) stream;
+            bool success = rust!(RustPluginV3_enqueue [
+                plugin : *mut std::ffi::c_void as "void*",
+                typesPtr : *const i32 as "const std::int32_t*",
+                nbDimsPtr : *const i32 as "const std::int32_t*",
+                dimsPtr : *const i64 as "const std::int64_t*",
+                inputs : *const *const std::ffi::c_void as "void const* const*",
+                nbInputs : i32 as "std::int32_t",
+                outputs : *const *mut std::ffi::c_void as "void* const*",
+                nbOutputs : i32 as "std::int32_t",
+                streamPtr : *const std::ffi::c_void as "const void*"
+            ] -> bool as "bool" {
+                enqueue_raw(
+                    plugin,
+                    typesPtr,
+                    nbDimsPtr,
+                    dimsPtr,
+                    inputs,
+                    nbInputs,
+                    outputs,
+                    nbOutputs,
+                    streamPtr,
+                )
+            });
+            return success ? 0 : -1;
+        }
+
+        IPluginV3* attachToContext(IPluginResourceContext* context) noexcept override {
+            return clone();
+        }
+
+        PluginFieldCollection const* getFieldsToSerialize() noexcept override {
+            void* plugin = m_plugin;
+            void* serialized = (void*) &m_serialized;
+            rust!(RustPluginV3_getFieldsToSerialize [
+                plugin : *const std::ffi::c_void as "void*",
+                serialized : *mut std::ffi::c_void as "void*"
+            ] {
+                serialize_raw(plugin, serialized);
+            });
+            m_field = PluginField(
+                "data",
+                m_serialized.data(),
+                PluginFieldType::kINT8,
+                static_cast<std::int32_t>(m_serialized.size())
+            );
+            m_fieldCollection.nbFields = 1;
+            m_fieldCollection.fields = &m_field;
+            return &m_fieldCollection;
+        }
+
+    private:
+        void* m_plugin;
+        std::string m_name;
+        std::string m_version;
+        std::string m_namespace;
+        std::int32_t m_nbInputs = 0;
+        std::int32_t m_nbOutputs = 0;
+        std::vector<std::uint8_t> m_serialized {};
+        PluginField m_field {};
+        PluginFieldCollection m_fieldCollection {};
+    };
+
+    // Plugin creator that forwards to a Rust implementation of `PluginCreator`. Plugin creators are
+    // registered in the global plugin registry and live for the remainder of the process.
+    class RustPluginCreatorV3 : public IPluginCreatorV3One
+    {
+    public:
+        RustPluginCreatorV3(
+            void* creator,
+            const std::string& name,
+            const std::string& version,
+            const std::string& pluginNamespace
+        ) : m_creator(creator), m_name(name), m_version(version), m_namespace(pluginNamespace) {
+            m_field = PluginField("data", nullptr, PluginFieldType::kINT8, 0);
+            m_fieldCollection.nbFields = 1;
+            m_fieldCollection.fields = &m_field;
+        }
+
+        AsciiChar const* getPluginName() const noexcept override {
+            return m_name.c_str();
+        }
+
+        AsciiChar const* getPluginVersion() const noexcept override {
+            return m_version.c_str();
+        }
+
+        AsciiChar const* getPluginNamespace() const noexcept override {
+            return m_namespace.c_str();
+        }
+
+        PluginFieldCollection const* getFieldNames() noexcept override {
+            return &m_fieldCollection;
+        }
+
+        IPluginV3* createPlugin(
+            AsciiChar const* name,
+            PluginFieldCollection const* fc,
+            TensorRTPhase phase
+        ) noexcept override {
+            const void* data = nullptr;
+            std::size_t size = 0;
+            if (fc != nullptr) {
+                for (std::int32_t i = 0; i < fc->nbFields; i++) {
+                    if (std::string(fc->fields[i].name) == "data") {
+                        data = fc->fields[i].data;
+                        size = static_cast<std::size_t>(fc->fields[i].length);
+                    }
+                }
+            }
+            void* creator = m_creator;
+            void* plugin = rust!(RustPluginCreatorV3_createPlugin [
+                creator : *const std::ffi::c_void as "void*",
+                data : *const u8 as "const void*",
+                size : usize as "std::size_t"
+            ] -> *mut std::ffi::c_void as "void*" {
+                create_plugin_raw(creator, data, size)
+            });
+            if (plugin == nullptr) {
+                return nullptr;
+            }
+            return new RustPluginV3(plugin, m_name, m_version, m_namespace);
+        }
+
+    private:
+        void* m_creator;
+        std::string m_name;
+        std::string m_version;
+        std::string m_namespace;
+        PluginField m_field {};
+        PluginFieldCollection m_fieldCollection {};
+    };
+    #endif
+
+    #endif // ODDITY_FFI_CUSTOM_PLUGIN
+}}
+
+/// Input tensor of a [`Plugin`] during execution.
+#[derive(Debug)]
+pub struct PluginInput {
+    /// Data type of tensor.
+    pub data_type: DataType,
+    /// Shape of tensor.
+    pub shape: Vec<i64>,
+    /// Device pointer to tensor data.
+    pub data: *const std::ffi::c_void,
+}
+
+/// Output tensor of a [`Plugin`] during execution.
+#[derive(Debug)]
+pub struct PluginOutput {
+    /// Data type of tensor.
+    pub data_type: DataType,
+    /// Shape of tensor.
+    pub shape: Vec<i64>,
+    /// Device pointer to tensor data.
+    pub data: *mut std::ffi::c_void,
+}
+
+/// Custom TensorRT plugin implemented in Rust.
+///
+/// Plugins are created by a [`PluginCreator`], which must be registered with
+/// [`register_plugin_creator()`] before the plugin is added to a network with
+/// [`crate::NetworkDefinition::add_plugin_v3()`], and before an engine that contains the plugin is
+/// deserialized.
+///
+/// Only plugins with linear tensor formats and output shapes that can be computed from constant
+/// input shapes are supported. Custom plugins require TensorRT 10 or up.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1v__1__0_1_1_i_plugin_v3.html)
+pub trait Plugin: Send {
+    /// Create a copy of the plugin.
+    fn clone_plugin(&self) -> Box<dyn Plugin>;
+
+    /// Get the number of outputs of the plugin.
+    fn num_outputs(&self) -> usize;
+
+    /// Get the data types of the outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_types` - Data types of the inputs.
+    fn output_data_types(&self, input_types: &[DataType]) -> Vec<DataType>;
+
+    /// Get the shapes of the outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_shapes` - Shapes of the inputs.
+    fn output_shapes(&self, input_shapes: &[Vec<i64>]) -> Vec<Vec<i64>>;
+
+    /// Whether or not the plugin supports a data type for an input or output.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Index of tensor, where inputs come before outputs.
+    /// * `data_type` - Data type to check.
+    fn supports_data_type(&self, position: usize, data_type: DataType) -> bool;
+
+    /// Serialize the plugin state.
+    ///
+    /// The serialized state is passed to [`PluginCreator::create_plugin()`] when the engine is
+    /// deserialized.
+    fn serialize(&self) -> Vec<u8>;
+
+    /// Execute the plugin.
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// The implementation must enqueue its work on `stream` and must not wait for it to finish.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input tensors.
+    /// * `outputs` - Output tensors.
+    /// * `stream` - Raw CUDA stream to execute on.
+    ///
+    /// # Return value
+    ///
+    /// `true` if the work was enqueued successfully.
+    fn enqueue(
+        &mut self,
+        inputs: &[PluginInput],
+        outputs: &[PluginOutput],
+        stream: *mut std::ffi::c_void,
+    ) -> bool;
+}
+
+/// Creator for a custom TensorRT plugin implemented in Rust.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_creator_v3_one.html)
+pub trait PluginCreator: Send + Sync {
+    /// Get the name of the plugin.
+    fn name(&self) -> &str;
+
+    /// Get the version of the plugin.
+    fn version(&self) -> &str;
+
+    /// Get the namespace of the plugin.
+    fn namespace(&self) -> &str {
+        ""
+    }
+
+    /// Create a plugin.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Plugin data, either passed to [`crate::NetworkDefinition::add_plugin_v3()`] or
+    ///   produced by [`Plugin::serialize()`].
+    ///
+    /// # Return value
+    ///
+    /// The plugin, or `None` if the data is invalid.
+    fn create_plugin(&self, data: &[u8]) -> Option<Box<dyn Plugin>>;
+}
+
+/// Register a plugin creator in the global plugin registry.
+///
+/// The creator stays registered for the remainder of the process.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+///
+/// # Arguments
+///
+/// * `creator` - Plugin creator to register.
+pub fn register_plugin_creator(creator: impl PluginCreator + 'static) -> Result<()> {
+    let name_cstr = plugin_cstring(creator.name())?;
+    let version_cstr = plugin_cstring(creator.version())?;
+    let namespace_cstr = plugin_cstring(creator.namespace())?;
+    let name_ptr = name_cstr.as_ptr();
+    let version_ptr = version_cstr.as_ptr();
+    let namespace_ptr = namespace_cstr.as_ptr();
+    // The plugin registry keeps a reference to the creator forever, so we leak it.
+    let creator: Box<Box<dyn PluginCreator>> = Box::new(Box::new(creator));
+    let creator_ptr = Box::into_raw(creator) as *const std::ffi::c_void;
+    let status = cpp!(unsafe [
+        creator_ptr as "const void*",
+        name_ptr as "const char*",
+        version_ptr as "const char*",
+        namespace_ptr as "const char*"
+    ] -> i32 as "std::int32_t" {
+        #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+        RustPluginCreatorV3* creator = new RustPluginCreatorV3(
+            (void*) creator_ptr,
+            name_ptr,
+            version_ptr,
+            namespace_ptr
+        );
+        return getPluginRegistry()->registerCreator(*creator, namespace_ptr) ? 1 : 0;
+        #else
+        return -1;
+        #endif
+    });
+    match status {
+        1 => Ok(()),
+        0 => Err(crate::error::last_error()),
+        _ => Err(unsupported_error()),
+    }
+}
+
+/// Owned TensorRT `IPluginV3`, deleted on drop.
+pub(crate) struct PluginV3(*mut std::ffi::c_void);
+
+/// Implements [`Send`] for [`PluginV3`].
+///
+/// # Safety
+///
+/// The plugin is only accessed by TensorRT, which is thread-safe with regards to plugins.
+unsafe impl Send for PluginV3 {}
+
+/// Implements [`Sync`] for [`PluginV3`].
+///
+/// # Safety
+///
+/// The plugin is only accessed by TensorRT, which is thread-safe with regards to plugins.
+unsafe impl Sync for PluginV3 {}
+
+impl PluginV3 {
+    /// Create a plugin through a plugin creator in the global plugin registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of plugin.
+    /// * `version` - Version of plugin.
+    /// * `namespace` - Namespace of plugin.
+    /// * `data` - Plugin data to pass to the creator.
+    pub(crate) fn create(name: &str, version: &str, namespace: &str, data: &[u8]) -> Result<Self> {
+        let name_cstr = plugin_cstring(name)?;
+        let version_cstr = plugin_cstring(version)?;
+        let namespace_cstr = plugin_cstring(namespace)?;
+        let name_ptr = name_cstr.as_ptr();
+        let version_ptr = version_cstr.as_ptr();
+        let namespace_ptr = namespace_cstr.as_ptr();
+        let data_ptr = data.as_ptr() as *const std::ffi::c_void;
+        let data_size = data.len() as i32;
+        let mut internal = std::ptr::null_mut::<std::ffi::c_void>();
+        let internal_ptr = &mut internal as *mut *mut std::ffi::c_void;
+        let status = cpp!(unsafe [
+            name_ptr as "const char*",
+            version_ptr as "const char*",
+            namespace_ptr as "const char*",
+            data_ptr as "const void*",
+            data_size as "std::int32_t",
+            internal_ptr as "void**"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+            IPluginCreatorInterface* creatorInterface = getPluginRegistry()->getCreator(
+                name_ptr,
+                version_ptr,
+                namespace_ptr
+            );
+            if (creatorInterface == nullptr) {
+                return 0;
+            }
+            if (std::string(creatorInterface->getInterfaceInfo().kind) != "PLUGIN CREATOR_V3ONE") {
+                return 0;
+            }
+            IPluginCreatorV3One* creator = static_cast<IPluginCreatorV3One*>(creatorInterface);
+            PluginField field("data", data_ptr, PluginFieldType::kINT8, data_size);
+            PluginFieldCollection fieldCollection;
+            fieldCollection.nbFields = 1;
+            fieldCollection.fields = &field;
+            *internal_ptr = (void*) creator->createPlugin(
+                name_ptr,
+                &fieldCollection,
+                TensorRTPhase::kBUILD
+            );
+            return *internal_ptr != nullptr ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        match status {
+            1 => Ok(PluginV3(internal)),
+            0 => Err(crate::error::Error::InvalidArgument(format!(
+                "failed to create plugin {name} (version {version}) from plugin registry"
+            ))),
+            _ => Err(unsupported_error()),
+        }
+    }
+
+    /// Get internal pointer to `IPluginV3`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let PluginV3(internal) = *self;
+        internal
+    }
+}
+
+impl Drop for PluginV3 {
+    fn drop(&mut self) {
+        let PluginV3(internal) = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+            delete ((IPluginV3*) internal);
+            #endif
+        });
+    }
+}
+
+/// Convert plugin name, version or namespace to C string.
+fn plugin_cstring(value: &str) -> Result<std::ffi::CString> {
+    std::ffi::CString::new(value)
+        .map_err(|_| crate::error::Error::InvalidArgument(format!("invalid plugin field: {value}")))
+}
+
+/// Create error for TensorRT versions that do not support custom plugins.
+fn unsupported_error() -> crate::error::Error {
+    crate::error::Error::InvalidArgument(
+        "custom plugins not supported by this version of TensorRT".to_string(),
+    )
+}
+
+/// Get the Rust plugin behind a raw plugin pointer.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`.
+#[inline]
+unsafe fn plugin_ref<'a>(plugin: *const std::ffi::c_void) -> &'a dyn Plugin {
+    (*(plugin as *const Box<dyn Plugin>)).as_ref()
+}
+
+/// Raw handler for dropping a plugin.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` was produced by [`Box::into_raw`] on a
+/// `Box<Box<dyn Plugin>>` and is not used afterwards.
+unsafe fn drop_plugin_raw(plugin: *mut std::ffi::c_void) {
+    drop(Box::from_raw(plugin as *mut Box<dyn Plugin>));
+}
+
+/// Raw handler for `IPluginV3::clone`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`.
+unsafe fn clone_plugin_raw(plugin: *const std::ffi::c_void) -> *mut std::ffi::c_void {
+    let cloned = Box::new(plugin_ref(plugin).clone_plugin());
+    Box::into_raw(cloned) as *mut std::ffi::c_void
+}
+
+/// Raw handler for `IPluginV3OneBuild::getNbOutputs`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`.
+unsafe fn num_outputs_raw(plugin: *const std::ffi::c_void) -> i32 {
+    plugin_ref(plugin).num_outputs() as i32
+}
+
+/// Raw handler for `IPluginV3OneBuild::getOutputDataTypes`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>` and that the input and
+/// output pointers point to `num_inputs` and `num_outputs` values respectively.
+unsafe fn output_data_types_raw(
+    plugin: *const std::ffi::c_void,
+    input_types: *const i32,
+    num_inputs: i32,
+    output_types: *mut i32,
+    num_outputs: i32,
+) -> bool {
+    let input_types = std::slice::from_raw_parts(input_types, num_inputs.max(0) as usize)
+        .iter()
+        .map(|data_type| DataType::from_i32(*data_type))
+        .collect::<Option<Vec<_>>>();
+    let Some(input_types) = input_types else {
+        return false;
+    };
+    let output_types_rust = plugin_ref(plugin).output_data_types(&input_types);
+    if output_types_rust.len() != num_outputs.max(0) as usize {
+        return false;
+    }
+    let output_types = std::slice::from_raw_parts_mut(output_types, output_types_rust.len());
+    for (output_type, output_type_rust) in output_types.iter_mut().zip(output_types_rust) {
+        *output_type = output_type_rust as i32;
+    }
+    true
+}
+
+/// Raw handler for `IPluginV3OneBuild::getOutputShapes`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`, that the number of
+/// dimensions pointers point to `num_inputs` and `num_outputs` values respectively, and that the
+/// dimensions pointers point to `MAX_DIMS` values per input and output.
+#[allow(clippy::too_many_arguments)]
+unsafe fn output_shapes_raw(
+    plugin: *const std::ffi::c_void,
+    input_num_dims: *const i32,
+    input_dims: *const i64,
+    num_inputs: i32,
+    output_num_dims: *mut i32,
+    output_dims: *mut i64,
+    num_outputs: i32,
+) -> bool {
+    let num_inputs = num_inputs.max(0) as usize;
+    let num_outputs = num_outputs.max(0) as usize;
+    let input_num_dims = std::slice::from_raw_parts(input_num_dims, num_inputs);
+    let input_dims = std::slice::from_raw_parts(input_dims, num_inputs * MAX_DIMS);
+    let input_shapes = input_num_dims
+        .iter()
+        .zip(input_dims.chunks(MAX_DIMS))
+        .map(|(num_dims, dims)| dims[..(*num_dims).max(0) as usize].to_vec())
+        .collect::<Vec<_>>();
+    let output_shapes = plugin_ref(plugin).output_shapes(&input_shapes);
+    if output_shapes.len() != num_outputs
+        || output_shapes.iter().any(|shape| shape.len() > MAX_DIMS)
+    {
+        return false;
+    }
+    let output_num_dims = std::slice::from_raw_parts_mut(output_num_dims, num_outputs);
+    let output_dims = std::slice::from_raw_parts_mut(output_dims, num_outputs * MAX_DIMS);
+    for (i, shape) in output_shapes.iter().enumerate() {
+        output_num_dims[i] = shape.len() as i32;
+        output_dims[i * MAX_DIMS..i * MAX_DIMS + shape.len()].copy_from_slice(shape);
+    }
+    true
+}
+
+/// Raw handler for `IPluginV3OneBuild::supportsFormatCombination`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`.
+unsafe fn supports_data_type_raw(
+    plugin: *const std::ffi::c_void,
+    position: i32,
+    data_type: i32,
+) -> bool {
+    match DataType::from_i32(data_type) {
+        Some(data_type) => {
+            plugin_ref(plugin).supports_data_type(position.max(0) as usize, data_type)
+        }
+        None => false,
+    }
+}
+
+/// Raw handler for `IPluginV3OneRuntime::enqueue`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>`, that the type and
+/// number of dimensions pointers point to one value per input and output, that the dimensions
+/// pointer points to `MAX_DIMS` values per input and output, and that the input and output
+/// pointers point to `num_inputs` and `num_outputs` device pointers respectively.
+#[allow(clippy::too_many_arguments)]
+unsafe fn enqueue_raw(
+    plugin: *mut std::ffi::c_void,
+    types: *const i32,
+    num_dims: *const i32,
+    dims: *const i64,
+    inputs: *const *const std::ffi::c_void,
+    num_inputs: i32,
+    outputs: *const *mut std::ffi::c_void,
+    num_outputs: i32,
+    stream: *const std::ffi::c_void,
+) -> bool {
+    let num_inputs = num_inputs.max(0) as usize;
+    let num_outputs = num_outputs.max(0) as usize;
+    let num_tensors = num_inputs + num_outputs;
+    let types = std::slice::from_raw_parts(types, num_tensors);
+    let num_dims = std::slice::from_raw_parts(num_dims, num_tensors);
+    let dims = std::slice::from_raw_parts(dims, num_tensors * MAX_DIMS);
+    let descs = types
+        .iter()
+        .zip(num_dims)
+        .zip(dims.chunks(MAX_DIMS))
+        .map(|((data_type, num_dims), dims)| {
+            DataType::from_i32(*data_type)
+                .map(|data_type| (data_type, dims[..(*num_dims).max(0) as usize].to_vec()))
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(mut descs) = descs else {
+        return false;
+    };
+    let output_descs = descs.split_off(num_inputs);
+    let inputs = std::slice::from_raw_parts(inputs, num_inputs)
+        .iter()
+        .zip(descs)
+        .map(|(data, (data_type, shape))| PluginInput {
+            data_type,
+            shape,
+            data: *data,
+        })
+        .collect::<Vec<_>>();
+    let outputs = std::slice::from_raw_parts(outputs, num_outputs)
+        .iter()
+        .zip(output_descs)
+        .map(|(data, (data_type, shape))| PluginOutput {
+            data_type,
+            shape,
+            data: *data,
+        })
+        .collect::<Vec<_>>();
+    let plugin = &mut *(plugin as *mut Box<dyn Plugin>);
+    plugin.enqueue(&inputs, &outputs, stream as *mut std::ffi::c_void)
+}
+
+/// Raw handler for `IPluginV3OneRuntime::getFieldsToSerialize`.
+///
+/// # Safety
+///
+/// The caller must ensure that `plugin` points to a live `Box<dyn Plugin>` and that `serialized`
+/// points to a `std::vector<std::uint8_t>`.
+unsafe fn serialize_raw(plugin: *const std::ffi::c_void, serialized: *mut std::ffi::c_void) {
+    let data = plugin_ref(plugin).serialize();
+    let data_ptr = data.as_ptr();
+    let data_size = data.len();
+    cpp!(unsafe [
+        serialized as "void*",
+        data_ptr as "const std::uint8_t*",
+        data_size as "std::size_t"
+    ] {
+        ((std::vector<std::uint8_t>*) serialized)->assign(data_ptr, data_ptr + data_size);
+    });
+}
+
+/// Raw handler for `IPluginCreatorV3One::createPlugin`.
+///
+/// # Safety
+///
+/// The caller must ensure that `creator` points to a live `Box<dyn PluginCreator>` and that `data`
+/// is either null or points to `size` bytes.
+unsafe fn create_plugin_raw(
+    creator: *const std::ffi::c_void,
+    data: *const u8,
+    size: usize,
+) -> *mut std::ffi::c_void {
+    let creator = &*(creator as *const Box<dyn PluginCreator>);
+    let data = if !data.is_null() && size > 0 {
+        std::slice::from_raw_parts(data, size)
+    } else {
+        &[]
+    };
+    match creator.create_plugin(data) {
+        Some(plugin) => Box::into_raw(Box::new(plugin)) as *mut std::ffi::c_void,
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Maximum number of dimensions of a tensor in TensorRT.
+const MAX_DIMS: usize = 8;
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+
+    use crate::tests::utils::*;
+
+    #[derive(Clone)]
+    struct IdentityPlugin;
+
+    impl Plugin for IdentityPlugin {
+        fn clone_plugin(&self) -> Box<dyn Plugin> {
+            Box::new(self.clone())
+        }
+
+        fn num_outputs(&self) -> usize {
+            1
+        }
+
+        fn output_data_types(&self, input_types: &[DataType]) -> Vec<DataType> {
+            input_types.to_vec()
+        }
+
+        fn output_shapes(&self, input_shapes: &[Vec<i64>]) -> Vec<Vec<i64>> {
+            input_shapes.to_vec()
+        }
+
+        fn supports_data_type(&self, _position: usize, data_type: DataType) -> bool {
+            data_type == DataType::Float
+        }
+
+        fn serialize(&self) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn enqueue(
+            &mut self,
+            inputs: &[PluginInput],
+            outputs: &[PluginOutput],
+            stream: *mut std::ffi::c_void,
+        ) -> bool {
+            let src = inputs[0].data;
+            let dst = outputs[0].data;
+            let size = inputs[0].shape.iter().product::<i64>() as usize * 4;
+            let ret = cpp!(unsafe [
+                dst as "void*",
+                src as "const void*",
+                size as "std::size_t",
+                stream as "void*"
+            ] -> i32 as "std::int32_t" {
+                return cudaMemcpyAsync(
+                    dst,
+                    src,
+                    size,
+                    cudaMemcpyDeviceToDevice,
+                    (cudaStream_t) stream
+                );
+            });
+            ret == 0
+        }
+    }
+
+    struct IdentityPluginCreator;
+
+    impl PluginCreator for IdentityPluginCreator {
+        fn name(&self) -> &str {
+            "IdentityRust"
+        }
+
+        fn version(&self) -> &str {
+            "1"
+        }
+
+        fn create_plugin(&self, _data: &[u8]) -> Option<Box<dyn Plugin>> {
+            Some(Box::new(IdentityPlugin))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_plugin_v3() {
+        register_plugin_creator(IdentityPluginCreator).unwrap();
+        let (_builder, network) = simple_network!();
+        let input = network.input(0);
+        let layer = network
+            .add_plugin_v3(&[&input], "IdentityRust", "1", "", &[])
+            .unwrap();
+        assert_eq!(layer.num_outputs(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_plugin_v3_unknown() {
+        let (_builder, network) = simple_network!();
+        let input = network.input(0);
+        assert!(network
+            .add_plugin_v3(&[&input], "DoesNotExist", "1", "", &[])
+            .is_err());
+    }
+}
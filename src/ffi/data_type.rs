@@ -0,0 +1,78 @@
+/// Type of data in a tensor or weights.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum DataType {
+    /// 32-bit floating point format.
+    Float = 0,
+    /// IEEE 16-bit floating-point format.
+    Half = 1,
+    /// Signed 8-bit integer representing a quantized floating-point value.
+    Int8 = 2,
+    /// Signed 32-bit integer format.
+    Int32 = 3,
+    /// 8-bit boolean.
+    Bool = 4,
+    /// Unsigned 8-bit integer format.
+    Uint8 = 5,
+    /// Signed 8-bit floating point with 1 sign bit, 4 exponent bits, 3 mantissa bits.
+    Fp8 = 6,
+    /// Brain float with 1 sign bit, 8 exponent bits, 7 mantissa bits.
+    Bf16 = 7,
+    /// Signed 64-bit integer type.
+    Int64 = 8,
+    /// Signed 4-bit integer type.
+    Int4 = 9,
+}
+
+impl DataType {
+    /// Create [`DataType`] from `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of data type.
+    pub(crate) fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(DataType::Float),
+            1 => Some(DataType::Half),
+            2 => Some(DataType::Int8),
+            3 => Some(DataType::Int32),
+            4 => Some(DataType::Bool),
+            5 => Some(DataType::Uint8),
+            6 => Some(DataType::Fp8),
+            7 => Some(DataType::Bf16),
+            8 => Some(DataType::Int64),
+            9 => Some(DataType::Int4),
+            _ => None,
+        }
+    }
+
+    /// Size of a single element of this data type in bytes.
+    ///
+    /// Elements of [`DataType::Int4`] are packed two to a byte, so its size is rounded up to one
+    /// byte.
+    pub fn size(&self) -> usize {
+        match self {
+            DataType::Float | DataType::Int32 => 4,
+            DataType::Half | DataType::Bf16 => 2,
+            DataType::Int8 | DataType::Bool | DataType::Uint8 | DataType::Fp8 | DataType::Int4 => 1,
+            DataType::Int64 => 8,
+        }
+    }
+
+    /// Number of bytes required to store `num_elements` elements of this data type.
+    ///
+    /// Unlike [`DataType::size()`], this takes into account that elements of [`DataType::Int4`]
+    /// are packed two to a byte.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_elements` - Number of elements.
+    pub fn num_bytes(&self, num_elements: usize) -> usize {
+        match self {
+            DataType::Int4 => num_elements.div_ceil(2),
+            data_type => num_elements * data_type.size(),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use cpp::cpp;
+
+use crate::ffi::data_type::DataType;
+use crate::ffi::sync::engine::TensorLocation;
+
+cpp! {{
+    #ifndef ODDITY_FFI_DEBUG_LISTENER
+    #define ODDITY_FFI_DEBUG_LISTENER
+
+    #if NV_TENSORRT_MAJOR >= 10
+    // Debug listener that forwards all calls to a Rust implementation of `DebugListener`. The Rust
+    // listener is owned by the Rust side, this class only holds a pointer to it.
+    class RustDebugListener : public IDebugListener
+    {
+    public:
+        RustDebugListener(void* listener) : m_listener(listener) {}
+
+        bool processDebugTensor(
+            const void* addr,
+            TensorLocation location,
+            DataType type,
+            const Dims& shape,
+            const char* name,
+            cudaStream_t stream
+        ) noexcept override {
+            void* listener = m_listener;
+            std::int32_t locationRaw = static_cast<std::int32_t>(location);
+            std::int32_t typeRaw = static_cast<std::int32_t>(type);
+            std::int32_t numDims = shape.nbDims;
+            std::int64_t dimsRaw[Dims::MAX_DIMS];
+            for (std::int32_t i = 0; i < numDims; i++) {
+                dimsRaw[i] = (std::int64_t) shape.d[i];
+            }
+            const std::int64_t* dimsPtr = dimsRaw;
+            void* streamPtr = (void*) stream;
+            return rust!(RustDebugListener_processDebugTensor [
+                listener : *mut std::ffi::c_void as "void*",
+                addr : *const std::ffi::c_void as "const void*",
+                locationRaw : i32 as "std::int32_t",
+                typeRaw : i32 as "std::int32_t",
+                dimsPtr : *const i64 as "const std::int64_t*",
+                numDims : i32 as "std::int32_t",
+                name : *const std::os::raw::c_char as "const char*",
+                streamPtr : *mut std::ffi::c_void as "void*"
+            ] -> bool as "bool" {
+                process_debug_tensor_raw(
+                    listener,
+                    addr,
+                    locationRaw,
+                    typeRaw,
+                    dimsPtr,
+                    numDims,
+                    name,
+                    streamPtr,
+                )
+            });
+        }
+
+    private:
+        void* m_listener;
+    };
+    #endif
+
+    #endif // ODDITY_FFI_DEBUG_LISTENER
+}}
+
+/// Debug tensor as passed to a [`DebugListener`].
+#[derive(Debug)]
+pub struct DebugTensor<'a> {
+    /// Name of tensor.
+    pub name: &'a str,
+    /// Pointer to tensor data.
+    pub data: *const std::ffi::c_void,
+    /// Location of tensor data.
+    pub location: TensorLocation,
+    /// Data type of tensor.
+    pub data_type: Option<DataType>,
+    /// Shape of tensor.
+    pub shape: &'a [i64],
+}
+
+/// Listener for the values of debug tensors during inference.
+///
+/// Tensors are marked as debug tensors with [`crate::NetworkDefinition::mark_debug()`] before the
+/// engine is built. At runtime, install a listener with
+/// [`crate::ExecutionContext::set_debug_listener()`] and enable debug tensors with
+/// [`crate::ExecutionContext::set_tensor_debug_state()`]. Debug tensors require TensorRT 10 or up.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_debug_listener.html)
+pub trait DebugListener: Send {
+    /// Called when the value of a debug tensor is available.
+    ///
+    /// # Stream ordered semantics
+    ///
+    /// The tensor data is only valid in stream order on `stream`. To read it on the host, copy it
+    /// on `stream` and synchronize the stream before reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Debug tensor.
+    /// * `stream` - Raw CUDA stream that inference executes on.
+    ///
+    /// # Return value
+    ///
+    /// `true` on success.
+    fn process_debug_tensor(&mut self, tensor: DebugTensor, stream: *mut std::ffi::c_void) -> bool;
+}
+
+/// Bridge between a Rust [`DebugListener`] and a TensorRT `IDebugListener`.
+///
+/// Owns both the Rust listener and the C++ object that forwards to it. The C++ object is only
+/// available on TensorRT 10 and up, on older versions the internal pointer is null.
+pub(crate) struct DebugListenerBridge {
+    internal: *mut std::ffi::c_void,
+    _listener: Box<Box<dyn DebugListener>>,
+}
+
+/// Implements [`Send`] for [`DebugListenerBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust listener, which is [`Send`] itself.
+unsafe impl Send for DebugListenerBridge {}
+
+/// Implements [`Sync`] for [`DebugListenerBridge`].
+///
+/// # Safety
+///
+/// The bridge exposes no operations through a shared reference.
+unsafe impl Sync for DebugListenerBridge {}
+
+impl DebugListenerBridge {
+    /// Create a new bridge for the given listener.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Rust debug listener to forward to.
+    pub(crate) fn new(listener: Box<dyn DebugListener>) -> Self {
+        // The listener is boxed twice so that we can hand a thin pointer to C++.
+        let mut listener = Box::new(listener);
+        let listener_ptr =
+            listener.as_mut() as *mut Box<dyn DebugListener> as *mut std::ffi::c_void;
+        let internal = cpp!(unsafe [
+            listener_ptr as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return (void*) new RustDebugListener(listener_ptr);
+            #else
+            return nullptr;
+            #endif
+        });
+        DebugListenerBridge {
+            internal,
+            _listener: listener,
+        }
+    }
+
+    /// Get internal pointer to `IDebugListener`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let DebugListenerBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for DebugListenerBridge {
+    fn drop(&mut self) {
+        let DebugListenerBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #if NV_TENSORRT_MAJOR >= 10
+            delete ((RustDebugListener*) internal);
+            #endif
+        });
+    }
+}
+
+/// Raw handler for `IDebugListener::processDebugTensor`.
+///
+/// # Safety
+///
+/// The caller must ensure that `listener` points to a live `Box<dyn DebugListener>`, that `dims`
+/// points to `num_dims` values and that `name` is a valid pointer to a C string.
+#[allow(clippy::too_many_arguments)]
+unsafe fn process_debug_tensor_raw(
+    listener: *mut std::ffi::c_void,
+    data: *const std::ffi::c_void,
+    location: i32,
+    data_type: i32,
+    dims: *const i64,
+    num_dims: i32,
+    name: *const std::os::raw::c_char,
+    stream: *mut std::ffi::c_void,
+) -> bool {
+    let listener = &mut *(listener as *mut Box<dyn DebugListener>);
+    let name = std::ffi::CStr::from_ptr(name).to_string_lossy();
+    let shape = std::slice::from_raw_parts(dims, num_dims.max(0) as usize);
+    listener.process_debug_tensor(
+        DebugTensor {
+            name: &name,
+            data,
+            location: TensorLocation::from_i32(location),
+            data_type: DataType::from_i32(data_type),
+            shape,
+        },
+        stream,
+    )
+}
@@ -0,0 +1,221 @@
+/// Dimensions of a tensor.
+///
+/// Dimensions are stored as `i64` to match `Dims64` in TensorRT 10, so that tensors with more than
+/// 2^31 elements can be described. Dynamic dimensions are represented by `-1`.
+///
+/// [`Dims`] can be created from slices and arrays of `i64`, `i32` and `usize`, and dereferences to
+/// `[i64]`.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_dims64.html)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Dims(Vec<i64>);
+
+impl Dims {
+    /// Value of a dynamic dimension.
+    pub const DYNAMIC: i64 = -1;
+
+    /// Create new [`Dims`].
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - Dimensions.
+    #[inline]
+    pub fn new(dims: Vec<i64>) -> Self {
+        Self(dims)
+    }
+
+    /// Get dimensions as slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[i64] {
+        &self.0
+    }
+
+    /// Turn into [`Vec`] of dimensions.
+    #[inline]
+    pub fn into_vec(self) -> Vec<i64> {
+        self.0
+    }
+
+    /// Whether any of the dimensions is dynamic.
+    #[inline]
+    pub fn is_dynamic(&self) -> bool {
+        self.0.iter().any(|dim| *dim < 0)
+    }
+
+    /// Get the total number of elements of a tensor with these dimensions.
+    ///
+    /// # Return value
+    ///
+    /// Number of elements, or `None` if any of the dimensions is dynamic or the number of elements
+    /// does not fit in a `usize`.
+    pub fn num_elements(&self) -> Option<usize> {
+        self.0.iter().try_fold(1usize, |num_elements, dim| {
+            num_elements.checked_mul(usize::try_from(*dim).ok()?)
+        })
+    }
+}
+
+impl std::ops::Deref for Dims {
+    type Target = [i64];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Dims {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl std::fmt::Display for Dims {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (index, dim) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{dim}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl From<Vec<i64>> for Dims {
+    #[inline]
+    fn from(dims: Vec<i64>) -> Self {
+        Self(dims)
+    }
+}
+
+impl From<&Dims> for Dims {
+    #[inline]
+    fn from(dims: &Dims) -> Self {
+        dims.clone()
+    }
+}
+
+impl From<Dims> for Vec<i64> {
+    #[inline]
+    fn from(dims: Dims) -> Self {
+        dims.0
+    }
+}
+
+impl From<&[i64]> for Dims {
+    #[inline]
+    fn from(dims: &[i64]) -> Self {
+        Self(dims.to_vec())
+    }
+}
+
+impl From<&Vec<i64>> for Dims {
+    #[inline]
+    fn from(dims: &Vec<i64>) -> Self {
+        Self(dims.clone())
+    }
+}
+
+impl<const N: usize> From<[i64; N]> for Dims {
+    #[inline]
+    fn from(dims: [i64; N]) -> Self {
+        Self(dims.to_vec())
+    }
+}
+
+impl<const N: usize> From<&[i64; N]> for Dims {
+    #[inline]
+    fn from(dims: &[i64; N]) -> Self {
+        Self(dims.to_vec())
+    }
+}
+
+impl From<&[i32]> for Dims {
+    #[inline]
+    fn from(dims: &[i32]) -> Self {
+        Self(dims.iter().map(|dim| *dim as i64).collect())
+    }
+}
+
+impl<const N: usize> From<&[i32; N]> for Dims {
+    #[inline]
+    fn from(dims: &[i32; N]) -> Self {
+        Self::from(dims.as_slice())
+    }
+}
+
+impl From<&[usize]> for Dims {
+    #[inline]
+    fn from(dims: &[usize]) -> Self {
+        Self(dims.iter().map(|dim| *dim as i64).collect())
+    }
+}
+
+impl<const N: usize> From<&[usize; N]> for Dims {
+    #[inline]
+    fn from(dims: &[usize; N]) -> Self {
+        Self::from(dims.as_slice())
+    }
+}
+
+impl PartialEq<[i64]> for Dims {
+    #[inline]
+    fn eq(&self, other: &[i64]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&[i64]> for Dims {
+    #[inline]
+    fn eq(&self, other: &&[i64]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<[i64; N]> for Dims {
+    #[inline]
+    fn eq(&self, other: &[i64; N]) -> bool {
+        self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[i64; N]> for Dims {
+    #[inline]
+    fn eq(&self, other: &&[i64; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Vec<i64>> for Dims {
+    #[inline]
+    fn eq(&self, other: &Vec<i64>) -> bool {
+        &self.0 == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dims_conversions() {
+        assert_eq!(Dims::from(&[1i32, -1, 3]), &[1, -1, 3]);
+        assert_eq!(Dims::from(&[1usize, 2, 3]), &[1, 2, 3]);
+        assert_eq!(Dims::from(vec![1i64 << 40, 2]), &[1 << 40, 2]);
+        assert_eq!(Vec::from(Dims::from([4, 5])), vec![4, 5]);
+        assert_eq!(Dims::from(&[1, 2]).to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn test_dims_num_elements() {
+        assert_eq!(Dims::from(&[2, 3, 4]).num_elements(), Some(24));
+        assert_eq!(Dims::default().num_elements(), Some(1));
+        assert_eq!(Dims::from(&[Dims::DYNAMIC, 3]).num_elements(), None);
+        assert!(Dims::from(&[Dims::DYNAMIC, 3]).is_dynamic());
+        assert!(!Dims::from(&[2, 3]).is_dynamic());
+        assert_eq!(Dims::from(&[i64::MAX, 2]).num_elements(), None);
+    }
+}
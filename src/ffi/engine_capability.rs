@@ -0,0 +1,30 @@
+/// Engine capability, which determines the restrictions of a network during build and what
+/// runtime it targets.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum EngineCapability {
+    /// Standard engine, supports all TensorRT features.
+    Standard = 0,
+    /// Safety-scoped engine, restricted to the safety certified subset of TensorRT and targets the
+    /// safety runtime.
+    Safety = 1,
+    /// Standalone DLA engine, targets NvMediaDLA or cuDLA instead of the TensorRT runtime.
+    DlaStandalone = 2,
+}
+
+impl EngineCapability {
+    /// Create [`EngineCapability`] from `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of engine capability.
+    pub(crate) fn from_i32(value: i32) -> Self {
+        match value {
+            1 => EngineCapability::Safety,
+            2 => EngineCapability::DlaStandalone,
+            _ => EngineCapability::Standard,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use cpp::cpp;
 
+use crate::ffi::ErrorCode;
+
 /// Get last error message produced by TensorRT.
 ///
 /// # Thread-safety
@@ -34,3 +36,28 @@ pub fn get_last_error_message() -> String {
         "unknown error".to_string()
     }
 }
+
+/// Take all errors recorded by the TensorRT error recorder.
+///
+/// This clears the error recorder.
+///
+/// # Thread-safety
+///
+/// The error recorder is shared by all TensorRT objects, so this function could return errors
+/// produced by functions that executed on a different thread.
+///
+/// # Return value
+///
+/// Recorded errors, in the order in which they were reported.
+pub fn take_recorded_errors() -> Vec<(ErrorCode, String)> {
+    let mut errors: Vec<(ErrorCode, String)> = Vec::new();
+    let errors_ptr = &mut errors as *mut Vec<(ErrorCode, String)> as *mut std::ffi::c_void;
+    // SAFETY: This is safe because the recorder only pushes into `errors` for the duration of this
+    // call, and `errors` lives until the end of the function.
+    cpp!(unsafe [
+        errors_ptr as "void*"
+    ] {
+        GLOBAL_ERROR_RECORDER.takeErrors(errors_ptr);
+    });
+    errors
+}
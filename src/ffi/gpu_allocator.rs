@@ -0,0 +1,254 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_GPU_ALLOCATOR
+    #define ODDITY_FFI_GPU_ALLOCATOR
+
+    // GPU allocator that forwards all calls to a Rust implementation of `GpuAllocator`. The Rust
+    // allocator is owned by the Rust side, this class only holds a pointer to it.
+    //
+    // Note that the synchronous allocation functions are deprecated since TensorRT 10 in favor of
+    // their asynchronous variants, which by default forward to them.
+    #pragma GCC diagnostic push
+    #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+    class RustGpuAllocator : public IGpuAllocator
+    {
+    public:
+        RustGpuAllocator(void* allocator) : m_allocator(allocator) {}
+
+        void* allocate(
+            std::uint64_t size,
+            std::uint64_t alignment,
+            AllocatorFlags flags
+        ) noexcept override {
+            void* allocator = m_allocator;
+            return rust!(RustGpuAllocator_allocate [
+                allocator : *const std::ffi::c_void as "void*",
+                size : u64 as "std::uint64_t",
+                alignment : u64 as "std::uint64_t"
+            ] -> *mut std::ffi::c_void as "void*" {
+                allocate_raw(allocator, size, alignment)
+            });
+        }
+
+        #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 5)
+        void* reallocate(
+            void* baseAddr,
+            std::uint64_t alignment,
+            std::uint64_t newSize
+        ) noexcept override {
+            void* allocator = m_allocator;
+            return rust!(RustGpuAllocator_reallocate [
+                allocator : *const std::ffi::c_void as "void*",
+                baseAddr : *mut std::ffi::c_void as "void*",
+                alignment : u64 as "std::uint64_t",
+                newSize : u64 as "std::uint64_t"
+            ] -> *mut std::ffi::c_void as "void*" {
+                reallocate_raw(allocator, baseAddr, alignment, newSize)
+            });
+        }
+        #endif
+
+        bool deallocate(void* memory) noexcept
+        #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 5)
+        override
+        #endif
+        {
+            void* allocator = m_allocator;
+            return rust!(RustGpuAllocator_deallocate [
+                allocator : *const std::ffi::c_void as "void*",
+                memory : *mut std::ffi::c_void as "void*"
+            ] -> bool as "bool" {
+                deallocate_raw(allocator, memory)
+            });
+        }
+
+        #if NV_TENSORRT_MAJOR < 10
+        void free(void* memory) noexcept override {
+            deallocate(memory);
+        }
+        #endif
+
+    private:
+        void* m_allocator;
+    };
+    #pragma GCC diagnostic pop
+
+    #endif // ODDITY_FFI_GPU_ALLOCATOR
+}}
+
+/// Allocator for all GPU memory that TensorRT allocates.
+///
+/// Install a GPU allocator with [`crate::Runtime::set_gpu_allocator()`] or
+/// [`crate::Builder::set_gpu_allocator()`] to route TensorRT allocations through a custom memory
+/// pool. TensorRT may call the allocator from multiple threads at the same time.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_gpu_allocator.html)
+pub trait GpuAllocator: Send + Sync {
+    /// Allocate device memory.
+    ///
+    /// Returning a null pointer signals that allocation failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Number of bytes to allocate.
+    /// * `alignment` - Required alignment in bytes.
+    fn allocate(&self, size: usize, alignment: usize) -> *mut std::ffi::c_void;
+
+    /// Resize previously allocated device memory.
+    ///
+    /// The default implementation does not support reallocation and returns a null pointer, in
+    /// which case TensorRT falls back to allocating new memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - Memory previously returned by [`GpuAllocator::allocate()`].
+    /// * `alignment` - Required alignment in bytes.
+    /// * `new_size` - New size in bytes.
+    fn reallocate(
+        &self,
+        _memory: *mut std::ffi::c_void,
+        _alignment: usize,
+        _new_size: usize,
+    ) -> *mut std::ffi::c_void {
+        std::ptr::null_mut()
+    }
+
+    /// Free device memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - Memory previously returned by [`GpuAllocator::allocate()`].
+    ///
+    /// # Return value
+    ///
+    /// `true` if the memory was freed.
+    fn deallocate(&self, memory: *mut std::ffi::c_void) -> bool;
+}
+
+impl<T: GpuAllocator + ?Sized> GpuAllocator for std::sync::Arc<T> {
+    #[inline]
+    fn allocate(&self, size: usize, alignment: usize) -> *mut std::ffi::c_void {
+        (**self).allocate(size, alignment)
+    }
+
+    #[inline]
+    fn reallocate(
+        &self,
+        memory: *mut std::ffi::c_void,
+        alignment: usize,
+        new_size: usize,
+    ) -> *mut std::ffi::c_void {
+        (**self).reallocate(memory, alignment, new_size)
+    }
+
+    #[inline]
+    fn deallocate(&self, memory: *mut std::ffi::c_void) -> bool {
+        (**self).deallocate(memory)
+    }
+}
+
+/// Bridge between a Rust [`GpuAllocator`] and a TensorRT `IGpuAllocator`.
+///
+/// Owns both the Rust allocator and the C++ object that forwards to it.
+pub(crate) struct GpuAllocatorBridge {
+    internal: *mut std::ffi::c_void,
+    _allocator: Box<Box<dyn GpuAllocator>>,
+}
+
+/// Implements [`Send`] for [`GpuAllocatorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust allocator, which is [`Send`] itself.
+unsafe impl Send for GpuAllocatorBridge {}
+
+/// Implements [`Sync`] for [`GpuAllocatorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust allocator, which is [`Sync`] itself.
+unsafe impl Sync for GpuAllocatorBridge {}
+
+impl GpuAllocatorBridge {
+    /// Create a new bridge for the given allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `allocator` - Rust GPU allocator to forward to.
+    pub(crate) fn new(allocator: Box<dyn GpuAllocator>) -> Self {
+        // The allocator is boxed twice so that we can hand a thin pointer to C++.
+        let allocator = Box::new(allocator);
+        let allocator_ptr =
+            allocator.as_ref() as *const Box<dyn GpuAllocator> as *const std::ffi::c_void;
+        let internal = cpp!(unsafe [
+            allocator_ptr as "const void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) new RustGpuAllocator((void*) allocator_ptr);
+        });
+        GpuAllocatorBridge {
+            internal,
+            _allocator: allocator,
+        }
+    }
+
+    /// Get internal pointer to `IGpuAllocator`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let GpuAllocatorBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for GpuAllocatorBridge {
+    fn drop(&mut self) {
+        let GpuAllocatorBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            delete ((RustGpuAllocator*) internal);
+        });
+    }
+}
+
+/// Raw handler for `IGpuAllocator::allocate`.
+///
+/// # Safety
+///
+/// The caller must ensure that `allocator` points to a live `Box<dyn GpuAllocator>`.
+unsafe fn allocate_raw(
+    allocator: *const std::ffi::c_void,
+    size: u64,
+    alignment: u64,
+) -> *mut std::ffi::c_void {
+    let allocator = &*(allocator as *const Box<dyn GpuAllocator>);
+    allocator.allocate(size as usize, alignment as usize)
+}
+
+/// Raw handler for `IGpuAllocator::reallocate`.
+///
+/// # Safety
+///
+/// The caller must ensure that `allocator` points to a live `Box<dyn GpuAllocator>`.
+unsafe fn reallocate_raw(
+    allocator: *const std::ffi::c_void,
+    memory: *mut std::ffi::c_void,
+    alignment: u64,
+    new_size: u64,
+) -> *mut std::ffi::c_void {
+    let allocator = &*(allocator as *const Box<dyn GpuAllocator>);
+    allocator.reallocate(memory, alignment as usize, new_size as usize)
+}
+
+/// Raw handler for `IGpuAllocator::deallocate`.
+///
+/// # Safety
+///
+/// The caller must ensure that `allocator` points to a live `Box<dyn GpuAllocator>`.
+unsafe fn deallocate_raw(
+    allocator: *const std::ffi::c_void,
+    memory: *mut std::ffi::c_void,
+) -> bool {
+    let allocator = &*(allocator as *const Box<dyn GpuAllocator>);
+    allocator.deallocate(memory)
+}
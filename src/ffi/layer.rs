@@ -0,0 +1,412 @@
+use cpp::cpp;
+
+use crate::element::{as_bytes, TensorElement};
+use crate::ffi::data_type::DataType;
+use crate::ffi::network::Tensor;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// A layer in a [`crate::NetworkDefinition`].
+///
+/// Like the network it belongs to, a layer may not be shared with or sent to other threads.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+pub struct Layer<'parent> {
+    internal: *mut std::ffi::c_void,
+    _phantom: std::marker::PhantomData<&'parent ()>,
+}
+
+impl<'parent> Layer<'parent> {
+    /// Wrap internal pointer as [`Layer`].
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid `ILayer` object.
+    #[inline]
+    pub(crate) fn wrap(internal: *mut std::ffi::c_void) -> Self {
+        Self {
+            internal,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Get the layer name.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn name(&self) -> String {
+        let internal = self.as_ptr();
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const ILayer*) internal)->getName();
+        });
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+    }
+
+    /// Set the layer name.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name to set.
+    pub fn set_name(&mut self, name: &str) {
+        let internal = self.as_mut_ptr();
+        let name_ffi = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name_ffi.as_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            name_ptr as "const char*"
+        ] {
+            ((ILayer*) internal)->setName(name_ptr);
+        });
+    }
+
+    /// Get the number of inputs of the layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn num_inputs(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_inputs = cpp!(unsafe [
+            internal as "const void*"
+        ] -> std::os::raw::c_int as "int" {
+            return ((const ILayer*) internal)->getNbInputs();
+        });
+        num_inputs as usize
+    }
+
+    /// Get the layer input at given index.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Input index.
+    pub fn input(&self, index: usize) -> Tensor<'parent> {
+        let internal = self.as_ptr();
+        let index = index as std::os::raw::c_int;
+        let tensor_internal = cpp!(unsafe [
+            internal as "const void*",
+            index as "int"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((const ILayer*) internal)->getInput(index);
+        });
+        Tensor::wrap(tensor_internal)
+    }
+
+    /// Get the number of outputs of the layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn num_outputs(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_outputs = cpp!(unsafe [
+            internal as "const void*"
+        ] -> std::os::raw::c_int as "int" {
+            return ((const ILayer*) internal)->getNbOutputs();
+        });
+        num_outputs as usize
+    }
+
+    /// Get the layer output at given index.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Output index.
+    pub fn output(&self, index: usize) -> Tensor<'parent> {
+        let internal = self.as_ptr();
+        let index = index as std::os::raw::c_int;
+        let tensor_internal = cpp!(unsafe [
+            internal as "const void*",
+            index as "int"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((const ILayer*) internal)->getOutput(index);
+        });
+        Tensor::wrap(tensor_internal)
+    }
+
+    /// Set the computational precision of this layer.
+    ///
+    /// Whether or not the builder respects the precision depends on the
+    /// `kOBEY_PRECISION_CONSTRAINTS` and `kPREFER_PRECISION_CONSTRAINTS` builder flags.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - Computational precision.
+    pub fn set_precision(&mut self, data_type: DataType) {
+        let internal = self.as_mut_ptr();
+        let data_type = data_type as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            data_type as "std::int32_t"
+        ] {
+            ((ILayer*) internal)->setPrecision(static_cast<DataType>(data_type));
+        });
+    }
+
+    /// Get the computational precision of this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn precision(&self) -> Option<DataType> {
+        let internal = self.as_ptr();
+        let data_type = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ILayer*) internal)->getPrecision();
+        });
+        DataType::from_i32(data_type)
+    }
+
+    /// Whether or not the computational precision has been set for this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn precision_is_set(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((const ILayer*) internal)->precisionIsSet();
+        })
+    }
+
+    /// Reset the computational precision of this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    pub fn reset_precision(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            ((ILayer*) internal)->resetPrecision();
+        });
+    }
+
+    /// Set the output type of an output of this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Output index.
+    /// * `data_type` - Output type.
+    pub fn set_output_type(&mut self, index: usize, data_type: DataType) {
+        let internal = self.as_mut_ptr();
+        let index = index as i32;
+        let data_type = data_type as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            index as "std::int32_t",
+            data_type as "std::int32_t"
+        ] {
+            ((ILayer*) internal)->setOutputType(index, static_cast<DataType>(data_type));
+        });
+    }
+
+    /// Get the output type of an output of this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Output index.
+    pub fn output_type(&self, index: usize) -> Option<DataType> {
+        let internal = self.as_ptr();
+        let index = index as i32;
+        let data_type = cpp!(unsafe [
+            internal as "const void*",
+            index as "std::int32_t"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ILayer*) internal)->getOutputType(index);
+        });
+        DataType::from_i32(data_type)
+    }
+
+    /// Whether or not the output type has been set for an output of this layer.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_layer.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Output index.
+    pub fn output_type_is_set(&self, index: usize) -> bool {
+        let internal = self.as_ptr();
+        let index = index as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            index as "std::int32_t"
+        ] -> bool as "bool" {
+            return ((const ILayer*) internal)->outputTypeIsSet(index);
+        })
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let Layer { internal, .. } = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let Layer { internal, .. } = *self;
+        internal
+    }
+}
+
+/// Weights for a layer.
+///
+/// The values are copied into the weights object, which is then kept alive by the network
+/// definition for as long as TensorRT might access it.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_weights.html)
+pub struct Weights {
+    data_type: DataType,
+    values: Vec<u8>,
+    count: i64,
+}
+
+impl Weights {
+    /// Create new [`Weights`].
+    ///
+    /// The size of `T` must match the size of `data_type`. Elements of a different type with the
+    /// same size, such as `u8` for [`DataType::Bool`], are allowed. Packed [`DataType::Int4`]
+    /// weights are not supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - Data type of the values.
+    /// * `values` - Weight values.
+    pub fn new<T: TensorElement>(data_type: DataType, values: &[T]) -> Result<Self> {
+        if data_type == DataType::Int4 || std::mem::size_of::<T>() != data_type.size() {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "weights of type {data_type:?} cannot hold elements of {} bytes",
+                std::mem::size_of::<T>()
+            )));
+        }
+        Ok(Self {
+            data_type,
+            values: as_bytes(values).to_vec(),
+            count: values.len() as i64,
+        })
+    }
+
+    /// Create empty [`Weights`], for example to omit the bias of a layer.
+    pub fn empty() -> Self {
+        Self {
+            data_type: DataType::Float,
+            values: Vec::new(),
+            count: 0,
+        }
+    }
+
+    /// Get data type of weights.
+    #[inline(always)]
+    pub fn data_type(&self) -> DataType {
+        self.data_type
+    }
+
+    /// Get number of weight values.
+    #[inline(always)]
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+
+    /// Get pointer to weight values, or null if there are none.
+    #[inline]
+    pub(crate) fn values_ptr(&self) -> *const std::ffi::c_void {
+        if self.values.is_empty() {
+            std::ptr::null()
+        } else {
+            self.values.as_ptr() as *const std::ffi::c_void
+        }
+    }
+}
+
+/// Type of activation function.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ActivationType {
+    /// Rectified linear activation.
+    Relu = 0,
+    /// Sigmoid activation.
+    Sigmoid = 1,
+    /// TanH activation.
+    Tanh = 2,
+    /// LeakyRelu activation: `x >= 0 ? x : alpha * x`.
+    LeakyRelu = 3,
+    /// Elu activation: `x >= 0 ? x : alpha * (exp(x) - 1)`.
+    Elu = 4,
+    /// Selu activation: `x > 0 ? beta * x : beta * (alpha * exp(x) - alpha)`.
+    Selu = 5,
+    /// Softsign activation: `x / (1 + |x|)`.
+    Softsign = 6,
+    /// Parametric softplus activation: `alpha * log(exp(beta * x) + 1)`.
+    Softplus = 7,
+    /// Clip activation: `max(alpha, min(beta, x))`.
+    Clip = 8,
+    /// Hard sigmoid activation: `max(0, min(1, alpha * x + beta))`.
+    HardSigmoid = 9,
+    /// Scaled tanh activation: `alpha * tanh(beta * x)`.
+    ScaledTanh = 10,
+    /// Thresholded ReLU activation: `x > alpha ? x : 0`.
+    ThresholdedRelu = 11,
+}
+
+/// Binary operation for an elementwise layer.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ElementWiseOperation {
+    /// Sum of the two elements.
+    Sum = 0,
+    /// Product of the two elements.
+    Prod = 1,
+    /// Maximum of the two elements.
+    Max = 2,
+    /// Minimum of the two elements.
+    Min = 3,
+    /// Subtract the second element from the first.
+    Sub = 4,
+    /// Divide the first element by the second.
+    Div = 5,
+    /// The first element to the power of the second element.
+    Pow = 6,
+    /// Floor division of the first element by the second.
+    FloorDiv = 7,
+    /// Logical AND of two elements.
+    And = 8,
+    /// Logical OR of two elements.
+    Or = 9,
+    /// Logical XOR of two elements.
+    Xor = 10,
+    /// Check if two elements are equal.
+    Equal = 11,
+    /// Check if element in first tensor is greater than corresponding element in second tensor.
+    Greater = 12,
+    /// Check if element in first tensor is less than corresponding element in second tensor.
+    Less = 13,
+}
+
+/// Operation to apply to an input of a matrix multiply layer.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum MatrixOperation {
+    /// Treat tensor as collection of matrices.
+    None = 0,
+    /// Like `None`, but transpose the matrix dimensions.
+    Transpose = 1,
+    /// Treat tensor as collection of vectors.
+    Vector = 2,
+}
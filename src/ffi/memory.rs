@@ -1,6 +1,14 @@
 use cpp::cpp;
 
-pub struct HostBuffer(*mut std::ffi::c_void);
+pub struct HostBuffer(Inner);
+
+/// Memory backing a [`HostBuffer`].
+enum Inner {
+    /// Memory owned by TensorRT through `IHostMemory`.
+    TensorRt(*mut std::ffi::c_void),
+    /// Memory owned by Rust.
+    Owned(Vec<u8>),
+}
 
 /// Implements [`Send`] for [`HostBuffer`].
 ///
@@ -27,18 +35,54 @@ impl HostBuffer {
     /// The pointer must point to a valid `IHostMemory` object.
     #[inline]
     pub(crate) fn wrap(internal: *mut std::ffi::c_void) -> Self {
-        HostBuffer(internal)
+        HostBuffer(Inner::TensorRt(internal))
     }
 
     /// Get data slice pointing to the host buffer.
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        let data = self.data() as *const u8;
-        let size = self.size();
-        // SAFETY: This is safe because:
-        // * The pointer is valid because we just got it from TensorRT.
-        // * The pointer will remain valid as long as `HostBuffer` remains around.
-        unsafe { std::slice::from_raw_parts(data, size) }
+        match &self.0 {
+            Inner::TensorRt(_) => {
+                let data = self.data() as *const u8;
+                let size = self.size();
+                if size == 0 {
+                    return &[];
+                }
+                // SAFETY: This is safe because:
+                // * The pointer is valid because we just got it from TensorRT.
+                // * The pointer will remain valid as long as `HostBuffer` remains around.
+                unsafe { std::slice::from_raw_parts(data, size) }
+            }
+            Inner::Owned(bytes) => bytes,
+        }
+    }
+
+    /// Get data slice pointing to the host buffer.
+    ///
+    /// Same as [`HostBuffer::as_bytes()`].
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    /// Copy host buffer data into a [`Vec`].
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Get size of host buffer data in bytes.
+    ///
+    /// Same as [`HostBuffer::size()`].
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    /// Whether or not the host buffer is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
     }
 
     /// Get readonly pointer to host buffer data.
@@ -46,12 +90,17 @@ impl HostBuffer {
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_host_memory.html#a95d49ae9b0a5479af9433cb101a26782)
     #[inline]
     pub fn data(&self) -> *const std::ffi::c_void {
-        let internal = self.as_ptr();
-        cpp!(unsafe [
-            internal as "const void*"
-        ] -> *mut std::ffi::c_void as "void*" {
-            return ((const IHostMemory*) internal)->data();
-        })
+        match &self.0 {
+            Inner::TensorRt(internal) => {
+                let internal = *internal;
+                cpp!(unsafe [
+                    internal as "const void*"
+                ] -> *mut std::ffi::c_void as "void*" {
+                    return ((const IHostMemory*) internal)->data();
+                })
+            }
+            Inner::Owned(bytes) => bytes.as_ptr() as *const std::ffi::c_void,
+        }
     }
 
     /// Get size of host buffer data.
@@ -59,44 +108,94 @@ impl HostBuffer {
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_host_memory.html#adede91569ebccd258b357f29ba706e8e)
     #[inline]
     pub fn size(&self) -> usize {
-        let internal = self.as_ptr();
-        cpp!(unsafe [
-            internal as "const void*"
-        ] -> usize as "std::size_t" {
-            return ((const IHostMemory*) internal)->size();
-        })
+        match &self.0 {
+            Inner::TensorRt(internal) => {
+                let internal = *internal;
+                cpp!(unsafe [
+                    internal as "const void*"
+                ] -> usize as "std::size_t" {
+                    return ((const IHostMemory*) internal)->size();
+                })
+            }
+            Inner::Owned(bytes) => bytes.len(),
+        }
     }
 
     /// Get internal readonly pointer.
+    ///
+    /// Returns a null pointer if the buffer was created from Rust bytes and is not backed by
+    /// `IHostMemory`.
     #[inline(always)]
     pub fn as_ptr(&self) -> *const std::ffi::c_void {
-        let HostBuffer(internal) = *self;
-        internal
+        match &self.0 {
+            Inner::TensorRt(internal) => *internal,
+            Inner::Owned(_) => std::ptr::null(),
+        }
     }
 
     /// Get internal mutable pointer.
+    ///
+    /// Returns a null pointer if the buffer was created from Rust bytes and is not backed by
+    /// `IHostMemory`.
     #[inline(always)]
     pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
-        let HostBuffer(internal) = *self;
-        internal
+        match &self.0 {
+            Inner::TensorRt(internal) => *internal,
+            Inner::Owned(_) => std::ptr::null_mut(),
+        }
+    }
+}
+
+impl std::ops::Deref for HostBuffer {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for HostBuffer {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Vec<u8>> for HostBuffer {
+    /// Create host buffer from owned bytes, for example a plan that was read from disk.
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        HostBuffer(Inner::Owned(bytes))
+    }
+}
+
+impl From<&[u8]> for HostBuffer {
+    /// Create host buffer by copying bytes.
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        HostBuffer(Inner::Owned(bytes.to_vec()))
     }
 }
 
 impl Drop for HostBuffer {
     fn drop(&mut self) {
-        let internal = self.as_mut_ptr();
-        cpp!(unsafe [
-            internal as "void*"
-        ] {
-            destroy((IHostMemory*) internal);
-        });
+        if let Inner::TensorRt(internal) = self.0 {
+            cpp!(unsafe [
+                internal as "void*"
+            ] {
+                destroy((IHostMemory*) internal);
+            });
+        }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "async"))]
 mod tests {
     use crate::tests::utils::*;
 
+    use super::*;
+
     #[tokio::test]
     async fn test_host_buffer_data_and_size() {
         let network_plan = simple_network_plan!();
@@ -106,4 +205,19 @@ mod tests {
         assert_eq!(unsafe { *(network_plan.data() as *const u8) }, bytes[0]);
         assert_eq!(network_plan.size(), bytes.len());
     }
+
+    #[tokio::test]
+    async fn test_host_buffer_from_vec() {
+        let network_plan = simple_network_plan!();
+        let host_buffer = HostBuffer::from(network_plan.to_vec());
+        assert_eq!(host_buffer.len(), network_plan.len());
+        assert_eq!(host_buffer.as_slice(), network_plan.as_slice());
+        assert_eq!(&host_buffer[..4], &network_plan[..4]);
+        assert!(host_buffer.as_ptr().is_null());
+        let runtime = crate::Runtime::new().await;
+        assert!(runtime
+            .deserialize_engine_from_plan(&host_buffer)
+            .await
+            .is_ok());
+    }
 }
@@ -6,16 +6,50 @@ mod pre {
     mod includes;
     mod shims;
     mod helpers;
-    mod logger;
+    pub(super) mod logger;
+    pub(super) mod error_recorder;
 }
 
+#[cfg(not(feature = "dispatch"))]
+pub mod algorithm_selector;
+#[cfg(not(feature = "dispatch"))]
 pub mod builder_config;
+#[cfg(not(feature = "dispatch"))]
+pub mod calibrator;
+#[cfg(not(feature = "dispatch"))]
+pub mod custom_plugin;
+pub mod data_type;
+pub mod debug_listener;
+pub mod dims;
+pub mod engine_capability;
 pub mod error;
+pub mod gpu_allocator;
+#[cfg(not(feature = "dispatch"))]
+pub mod layer;
 pub mod memory;
+#[cfg(not(feature = "dispatch"))]
 pub mod network;
+#[cfg(not(feature = "dispatch"))]
 pub mod optimization_profile;
-pub mod parser;
+pub mod output_allocator;
+#[cfg(not(feature = "dispatch"))]
+pub mod parsers;
+#[cfg(not(feature = "dispatch"))]
+pub mod plugin;
+#[cfg(not(feature = "dispatch"))]
+pub mod progress_monitor;
+pub mod stream_reader;
 pub mod sync;
+#[cfg(not(feature = "dispatch"))]
+pub mod timing_cache;
+pub mod version;
+
+/// Former location of [`parsers::onnx`], kept so that existing paths such as
+/// `ffi::parser::Parser` keep working.
+#[cfg(feature = "onnx")]
+pub use parsers::onnx as parser;
+pub use pre::error_recorder::ErrorCode;
+pub use pre::logger::Severity;
 
 /// Convenience macro for turning TensorRT error code into a `std::result::Result`.
 ///
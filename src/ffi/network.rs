@@ -1,15 +1,30 @@
 use cpp::cpp;
 
-use crate::ffi::parser::Parser;
+use crate::ffi::custom_plugin::PluginV3;
+use crate::ffi::data_type::DataType;
+use crate::ffi::dims::Dims;
+use crate::ffi::layer::{ActivationType, ElementWiseOperation, Layer, MatrixOperation, Weights};
+#[cfg(feature = "onnx")]
+use crate::ffi::parsers::onnx::Parser;
+use crate::ffi::result;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
 
 /// Defined in `NvInferRuntimeBase.h`
 const MAX_DIMS: usize = 8;
 
 /// A network definition for input to the builder.
 ///
+/// Layers are added through a shared reference, so that tensors and layers that borrow from the
+/// network can be passed to other methods of the network. Since `INetworkDefinition` itself is
+/// not synchronized, [`NetworkDefinition`] is not [`Sync`].
+///
 /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
 pub struct NetworkDefinition {
     internal: *mut std::ffi::c_void,
+    weights: std::sync::Mutex<Vec<Weights>>,
+    plugins: std::sync::Mutex<Vec<PluginV3>>,
+    #[cfg(feature = "onnx")]
     pub(crate) _parser: Option<Parser>,
 }
 
@@ -20,13 +35,6 @@ pub struct NetworkDefinition {
 /// The TensorRT API is thread-safe with regards to all operations on [`NetworkDefinition`].
 unsafe impl Send for NetworkDefinition {}
 
-/// Implements [`Sync`] for [`NetworkDefinition`].
-///
-/// # Safety
-///
-/// The TensorRT API is thread-safe with regards to all operations on [`NetworkDefinition`].
-unsafe impl Sync for NetworkDefinition {}
-
 impl NetworkDefinition {
     /// Wrap internal pointer as [`NetworkDefinition`].
     ///
@@ -36,10 +44,50 @@ impl NetworkDefinition {
     pub(crate) fn wrap(internal: *mut std::ffi::c_void) -> Self {
         Self {
             internal,
+            weights: Default::default(),
+            plugins: Default::default(),
+            #[cfg(feature = "onnx")]
             _parser: None,
         }
     }
 
+    /// Get the name of the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    pub fn name(&self) -> String {
+        let internal = self.as_ptr();
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const INetworkDefinition*) internal)->getName();
+        });
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+    }
+
+    /// Set the name of the network.
+    ///
+    /// The name is used as the name of the engine that is built from the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of network.
+    pub fn set_name(&mut self, name: &str) {
+        let internal = self.as_mut_ptr();
+        let name_ffi = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name_ffi.as_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            name_ptr as "const char*"
+        ] {
+            ((INetworkDefinition*) internal)->setName(name_ptr);
+        });
+    }
+
     /// Get network inputs.
     pub fn inputs(&self) -> Vec<Tensor> {
         let mut inputs = Vec::with_capacity(self.num_inputs());
@@ -122,6 +170,562 @@ impl NetworkDefinition {
         Tensor::wrap(tensor_internal)
     }
 
+    /// Get network layers.
+    pub fn layers(&self) -> Vec<Layer<'_>> {
+        let mut layers = Vec::with_capacity(self.num_layers());
+        for index in 0..self.num_layers() {
+            layers.push(self.layer(index));
+        }
+        layers
+    }
+
+    /// Get number of layers.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    pub fn num_layers(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_layers = cpp!(unsafe [
+            internal as "const void*"
+        ] -> std::os::raw::c_int as "int" {
+            return ((const INetworkDefinition*) internal)->getNbLayers();
+        });
+        num_layers as usize
+    }
+
+    /// Get network layer at given index.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Layer index.
+    pub fn layer(&self, index: usize) -> Layer<'_> {
+        let internal = self.as_ptr();
+        let index = index as std::os::raw::c_int;
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            index as "int"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((const INetworkDefinition*) internal)->getLayer(index);
+        });
+        Layer::wrap(layer_internal)
+    }
+
+    /// Add an input tensor to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the input tensor.
+    /// * `data_type` - Data type of the input tensor.
+    /// * `dims` - Dimensions of the input tensor. Use `-1` for dynamic dimensions.
+    pub fn add_input(
+        &self,
+        name: &str,
+        data_type: DataType,
+        dims: impl Into<Dims>,
+    ) -> Result<Tensor<'_>> {
+        let dims = dims.into();
+        if dims.len() > MAX_DIMS {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "input {name} has more than 8 dimensions"
+            )));
+        }
+        let internal = self.as_ptr();
+        let name_ffi = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name_ffi.as_ptr();
+        let data_type = data_type as i32;
+        let dims_ptr = dims.as_ptr();
+        let nb_dims = dims.len() as i32;
+        let tensor_internal = cpp!(unsafe [
+            internal as "const void*",
+            name_ptr as "const char*",
+            data_type as "std::int32_t",
+            dims_ptr as "const int64_t*",
+            nb_dims as "int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            Dims dims;
+            dims.nbDims = nb_dims;
+            for (int i = 0; i < nb_dims; ++i) {
+                dims.d[i] = dims_ptr[i];
+            }
+            return ((INetworkDefinition*) internal)->addInput(
+                name_ptr,
+                static_cast<DataType>(data_type),
+                dims
+            );
+        });
+        result!(tensor_internal, Tensor::wrap(tensor_internal))
+    }
+
+    /// Add a multi-dimension convolution layer to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor to the convolution.
+    /// * `num_output_maps` - Number of output feature maps.
+    /// * `kernel_size` - Dimensions of the convolution kernel.
+    /// * `kernel_weights` - Kernel weights.
+    /// * `bias_weights` - Bias weights. Use [`Weights::empty()`] to omit the bias.
+    pub fn add_convolution_nd(
+        &self,
+        input: &Tensor,
+        num_output_maps: i64,
+        kernel_size: impl Into<Dims>,
+        kernel_weights: Weights,
+        bias_weights: Weights,
+    ) -> Result<Layer<'_>> {
+        let kernel_size = kernel_size.into();
+        if kernel_size.len() > MAX_DIMS {
+            return Err(crate::error::Error::InvalidArgument(
+                "kernel size has more than 8 dimensions".to_string(),
+            ));
+        }
+        let internal = self.as_ptr();
+        let input_internal = input.as_ptr();
+        let kernel_size_ptr = kernel_size.as_ptr();
+        let nb_kernel_size = kernel_size.len() as i32;
+        let kernel_data_type = kernel_weights.data_type() as i32;
+        let kernel_values_ptr = kernel_weights.values_ptr();
+        let kernel_count = kernel_weights.count();
+        let bias_data_type = bias_weights.data_type() as i32;
+        let bias_values_ptr = bias_weights.values_ptr();
+        let bias_count = bias_weights.count();
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            input_internal as "const void*",
+            num_output_maps as "std::int64_t",
+            kernel_size_ptr as "const int64_t*",
+            nb_kernel_size as "int32_t",
+            kernel_data_type as "std::int32_t",
+            kernel_values_ptr as "const void*",
+            kernel_count as "std::int64_t",
+            bias_data_type as "std::int32_t",
+            bias_values_ptr as "const void*",
+            bias_count as "std::int64_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            Dims kernelSize;
+            kernelSize.nbDims = nb_kernel_size;
+            for (int i = 0; i < nb_kernel_size; ++i) {
+                kernelSize.d[i] = kernel_size_ptr[i];
+            }
+            Weights kernelWeights;
+            kernelWeights.type = static_cast<DataType>(kernel_data_type);
+            kernelWeights.values = kernel_values_ptr;
+            kernelWeights.count = kernel_count;
+            Weights biasWeights;
+            biasWeights.type = static_cast<DataType>(bias_data_type);
+            biasWeights.values = bias_values_ptr;
+            biasWeights.count = bias_count;
+            return ((INetworkDefinition*) internal)->addConvolutionNd(
+                *((ITensor*) input_internal),
+                num_output_maps,
+                kernelSize,
+                kernelWeights,
+                biasWeights
+            );
+        });
+        self.keep_weights(kernel_weights);
+        self.keep_weights(bias_weights);
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Add an activation layer to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor to the layer.
+    /// * `activation_type` - Type of activation function to apply.
+    pub fn add_activation(
+        &self,
+        input: &Tensor,
+        activation_type: ActivationType,
+    ) -> Result<Layer<'_>> {
+        let internal = self.as_ptr();
+        let input_internal = input.as_ptr();
+        let activation_type = activation_type as i32;
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            input_internal as "const void*",
+            activation_type as "std::int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((INetworkDefinition*) internal)->addActivation(
+                *((ITensor*) input_internal),
+                static_cast<ActivationType>(activation_type)
+            );
+        });
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Add an elementwise layer to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input1` - First input tensor to the layer.
+    /// * `input2` - Second input tensor to the layer.
+    /// * `operation` - Binary operation that the layer applies.
+    pub fn add_elementwise(
+        &self,
+        input1: &Tensor,
+        input2: &Tensor,
+        operation: ElementWiseOperation,
+    ) -> Result<Layer<'_>> {
+        let internal = self.as_ptr();
+        let input1_internal = input1.as_ptr();
+        let input2_internal = input2.as_ptr();
+        let operation = operation as i32;
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            input1_internal as "const void*",
+            input2_internal as "const void*",
+            operation as "std::int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((INetworkDefinition*) internal)->addElementWise(
+                *((ITensor*) input1_internal),
+                *((ITensor*) input2_internal),
+                static_cast<ElementWiseOperation>(operation)
+            );
+        });
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Add a matrix multiply layer to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input0` - First input tensor to the layer.
+    /// * `operation0` - Operation to apply to the first input.
+    /// * `input1` - Second input tensor to the layer.
+    /// * `operation1` - Operation to apply to the second input.
+    pub fn add_matrix_multiply(
+        &self,
+        input0: &Tensor,
+        operation0: MatrixOperation,
+        input1: &Tensor,
+        operation1: MatrixOperation,
+    ) -> Result<Layer<'_>> {
+        let internal = self.as_ptr();
+        let input0_internal = input0.as_ptr();
+        let input1_internal = input1.as_ptr();
+        let operation0 = operation0 as i32;
+        let operation1 = operation1 as i32;
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            input0_internal as "const void*",
+            operation0 as "std::int32_t",
+            input1_internal as "const void*",
+            operation1 as "std::int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return ((INetworkDefinition*) internal)->addMatrixMultiply(
+                *((ITensor*) input0_internal),
+                static_cast<MatrixOperation>(operation0),
+                *((ITensor*) input1_internal),
+                static_cast<MatrixOperation>(operation1)
+            );
+        });
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Add a shuffle layer to the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Input tensor to the layer.
+    /// * `reshape_dims` - Dimensions to reshape the input to, if any.
+    pub fn add_shuffle(&self, input: &Tensor, reshape_dims: Option<Dims>) -> Result<Layer<'_>> {
+        if reshape_dims
+            .as_ref()
+            .is_some_and(|reshape_dims| reshape_dims.len() > MAX_DIMS)
+        {
+            return Err(crate::error::Error::InvalidArgument(
+                "reshape dimensions have more than 8 dimensions".to_string(),
+            ));
+        }
+        let internal = self.as_ptr();
+        let input_internal = input.as_ptr();
+        let (reshape_dims_ptr, nb_reshape_dims) = match &reshape_dims {
+            Some(reshape_dims) => (reshape_dims.as_ptr(), reshape_dims.len() as i32),
+            None => (std::ptr::null(), -1),
+        };
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            input_internal as "const void*",
+            reshape_dims_ptr as "const int64_t*",
+            nb_reshape_dims as "int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            IShuffleLayer* layer = ((INetworkDefinition*) internal)->addShuffle(
+                *((ITensor*) input_internal)
+            );
+            if (layer != nullptr && nb_reshape_dims >= 0) {
+                Dims reshapeDims;
+                reshapeDims.nbDims = nb_reshape_dims;
+                for (int i = 0; i < nb_reshape_dims; ++i) {
+                    reshapeDims.d[i] = reshape_dims_ptr[i];
+                }
+                layer->setReshapeDimensions(reshapeDims);
+            }
+            return layer;
+        });
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Add a plugin layer to the network.
+    ///
+    /// The plugin is created by the plugin creator with the given name, version and namespace,
+    /// which must have been registered with [`crate::register_plugin_creator()`]. Requires TensorRT
+    /// 10 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input tensors to the layer.
+    /// * `plugin_name` - Name of plugin.
+    /// * `plugin_version` - Version of plugin.
+    /// * `plugin_namespace` - Namespace of plugin.
+    /// * `data` - Plugin data to pass to the plugin creator.
+    pub fn add_plugin_v3(
+        &self,
+        inputs: &[&Tensor],
+        plugin_name: &str,
+        plugin_version: &str,
+        plugin_namespace: &str,
+        data: &[u8],
+    ) -> Result<Layer<'_>> {
+        let mut plugin = PluginV3::create(plugin_name, plugin_version, plugin_namespace, data)?;
+        let internal = self.as_ptr();
+        let plugin_internal = plugin.as_mut_ptr();
+        let inputs_internal = inputs
+            .iter()
+            .map(|input| input.as_ptr())
+            .collect::<Vec<_>>();
+        let inputs_ptr = inputs_internal.as_ptr();
+        let nb_inputs = inputs_internal.len() as i32;
+        let layer_internal = cpp!(unsafe [
+            internal as "const void*",
+            plugin_internal as "void*",
+            inputs_ptr as "const void* const*",
+            nb_inputs as "int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((INetworkDefinition*) internal)->addPluginV3(
+                (ITensor* const*) inputs_ptr,
+                nb_inputs,
+                nullptr,
+                0,
+                *((IPluginV3*) plugin_internal)
+            );
+            #else
+            return nullptr;
+            #endif
+        });
+        // TensorRT does not take ownership of the plugin, so it must outlive the network.
+        self.plugins.lock().unwrap().push(plugin);
+        result!(layer_internal, Layer::wrap(layer_internal))
+    }
+
+    /// Mark a tensor as a network output.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to mark as output.
+    pub fn mark_output(&self, tensor: &Tensor) {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] {
+            ((INetworkDefinition*) internal)->markOutput(*((ITensor*) tensor_internal));
+        });
+    }
+
+    /// Unmark a tensor as a network output.
+    ///
+    /// This can be used to prune outputs of a parsed model that are not needed, such as auxiliary
+    /// training outputs, before building. Layers that only contribute to pruned outputs are
+    /// removed from the engine.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to unmark as output.
+    pub fn unmark_output(&self, tensor: &Tensor) {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] {
+            ((INetworkDefinition*) internal)->unmarkOutput(*((ITensor*) tensor_internal));
+        });
+    }
+
+    /// Remove a tensor from the network definition.
+    ///
+    /// The tensor must not be used by any layer, or be a network input or output. Since the
+    /// tensor is destroyed, this consumes it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to remove.
+    pub fn remove_tensor(&self, tensor: Tensor) {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] {
+            ((INetworkDefinition*) internal)->removeTensor(*((ITensor*) tensor_internal));
+        });
+    }
+
+    /// Mark a tensor as a debug tensor.
+    ///
+    /// The values of debug tensors can be inspected at runtime with a [`crate::DebugListener`].
+    /// Requires TensorRT 10 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to mark as debug tensor.
+    pub fn mark_debug(&self, tensor: &Tensor) -> Result<()> {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        let status = cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((INetworkDefinition*) internal)->markDebug(*((ITensor*) tensor_internal))
+                ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        debug_status_result(status)
+    }
+
+    /// Unmark a tensor as a debug tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to unmark as debug tensor.
+    pub fn unmark_debug(&self, tensor: &Tensor) -> Result<()> {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        let status = cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((INetworkDefinition*) internal)->unmarkDebug(*((ITensor*) tensor_internal))
+                ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        debug_status_result(status)
+    }
+
+    /// Whether or not a tensor is marked as a debug tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - Tensor to check.
+    pub fn is_debug_tensor(&self, tensor: &Tensor) -> bool {
+        let internal = self.as_ptr();
+        let tensor_internal = tensor.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_internal as "const void*"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((const INetworkDefinition*) internal)->isDebugTensor(
+                *((const ITensor*) tensor_internal)
+            );
+            #else
+            return false;
+            #endif
+        })
+    }
+
+    /// Get the ONNX parser that the network definition was parsed with, if any.
+    #[cfg(feature = "onnx")]
+    #[inline(always)]
+    pub fn onnx_parser(&self) -> Option<&Parser> {
+        self._parser.as_ref()
+    }
+
+    /// Get the flags the network definition was created with.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    pub fn flags(&self) -> NetworkDefinitionCreationFlags {
+        let internal = self.as_ptr();
+        let flags = cpp!(unsafe [
+            internal as "const void*"
+        ] -> u32 as "std::uint32_t" {
+            return ((const INetworkDefinition*) internal)->getFlags();
+        });
+        NetworkDefinitionCreationFlags::from_bits(flags)
+    }
+
+    /// Whether or not the network is strongly typed.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_network_definition.html)
+    #[inline(always)]
+    pub fn is_strongly_typed(&self) -> bool {
+        self.flags()
+            .contains(NetworkDefinitionCreationFlags::STRONGLY_TYPED)
+    }
+
+    /// Whether or not the network has an implicit batch dimension.
+    ///
+    /// Networks always have an explicit batch dimension since TensorRT 10.
+    pub(crate) fn has_implicit_batch_dimension(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return false;
+            #else
+            return ((const INetworkDefinition*) internal)->hasImplicitBatchDimension();
+            #endif
+        })
+    }
+
+    /// Keep weights alive for as long as the network definition lives, since TensorRT does not copy
+    /// weights until the engine is built.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Weights to keep.
+    fn keep_weights(&self, weights: Weights) {
+        self.weights.lock().unwrap().push(weights);
+    }
+
     /// Get internal readonly pointer.
     #[inline(always)]
     pub fn as_ptr(&self) -> *const std::ffi::c_void {
@@ -148,38 +752,129 @@ impl Drop for NetworkDefinition {
     }
 }
 
+/// Convert status of marking debug tensors into result.
+///
+/// # Arguments
+///
+/// * `status` - `1` on success, `0` on failure and `-1` if not supported.
+fn debug_status_result(status: i32) -> Result<()> {
+    match status {
+        1 => Ok(()),
+        0 => Err(crate::error::last_error()),
+        _ => Err(crate::error::Error::InvalidArgument(
+            "debug tensors not supported by this version of TensorRT".to_string(),
+        )),
+    }
+}
+
 /// Specifies immutable properties of [`NetworkDefinition`] expressed at creation time.
 ///
+/// Flags can be combined with `|`, for example `NetworkDefinitionCreationFlags::STRONGLY_TYPED |
+/// NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE`.
+///
 /// [TensorRT documentation of `NetworkDefinitionCreationFlags`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html#a77b643e855bcc302b30348276fa36504)
 /// [TensorRT documentation of `NetworkDefinitionCreationFlag`](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html#aa8f406be96c14b7dbea548cf19f09a08a85b8fdd336af67a4aa147b3430064945)
-#[derive(Copy, Clone)]
-pub enum NetworkDefinitionCreationFlags {
-    None,
-    ExplicitBatchSize,
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NetworkDefinitionCreationFlags(u32);
+
+impl NetworkDefinitionCreationFlags {
+    /// Mark the network to be an explicit batch network.
+    ///
+    /// TensorRT 10 and later only support explicit batch networks and ignore this flag.
+    pub const EXPLICIT_BATCH_SIZE: NetworkDefinitionCreationFlags =
+        NetworkDefinitionCreationFlags(1 << 0);
+    /// Mark the network to be strongly typed. The data types of all tensors in the network are
+    /// inferred from the network inputs and layer specifications, and the builder does not change
+    /// them.
+    ///
+    /// Requires TensorRT 10 or later. On older versions this flag is ignored.
+    pub const STRONGLY_TYPED: NetworkDefinitionCreationFlags =
+        NetworkDefinitionCreationFlags(1 << 1);
+
+    /// No flags.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        NetworkDefinitionCreationFlags(0)
+    }
+
+    /// Create flags from raw bits as used by TensorRT.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - Bitmask with bit `1 << NetworkDefinitionCreationFlag` set for each flag.
+    #[inline(always)]
+    pub const fn from_bits(bits: u32) -> Self {
+        NetworkDefinitionCreationFlags(bits)
+    }
+
+    /// Get raw bits as used by TensorRT.
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Whether or not all flags in `other` are contained in this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Flags to check.
+    #[inline(always)]
+    pub const fn contains(&self, other: NetworkDefinitionCreationFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether or not the set is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for NetworkDefinitionCreationFlags {
+    type Output = NetworkDefinitionCreationFlags;
+
+    #[inline(always)]
+    fn bitor(self, rhs: NetworkDefinitionCreationFlags) -> NetworkDefinitionCreationFlags {
+        NetworkDefinitionCreationFlags(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for NetworkDefinitionCreationFlags {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: NetworkDefinitionCreationFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for NetworkDefinitionCreationFlags {
+    type Output = NetworkDefinitionCreationFlags;
+
+    #[inline(always)]
+    fn bitand(self, rhs: NetworkDefinitionCreationFlags) -> NetworkDefinitionCreationFlags {
+        NetworkDefinitionCreationFlags(self.0 & rhs.0)
+    }
+}
+
+impl std::ops::Sub for NetworkDefinitionCreationFlags {
+    type Output = NetworkDefinitionCreationFlags;
+
+    /// Remove the flags in `rhs` from the set.
+    #[inline(always)]
+    fn sub(self, rhs: NetworkDefinitionCreationFlags) -> NetworkDefinitionCreationFlags {
+        NetworkDefinitionCreationFlags(self.0 & !rhs.0)
+    }
 }
 
 /// A tensor in a [`NetworkDefinition`].
 ///
+/// Like the network it belongs to, a tensor may not be shared with or sent to other threads.
+///
 /// [TensorRT documenation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
 pub struct Tensor<'parent> {
     internal: *mut std::ffi::c_void,
     _phantom: std::marker::PhantomData<&'parent ()>,
 }
 
-/// Implements [`Send`] for [`Tensor`].
-///
-/// # Safety
-///
-/// The TensorRT API is thread-safe with regards to all operations on [`Tensor`].
-unsafe impl<'parent> Send for Tensor<'parent> {}
-
-/// Implements [`Sync`] for [`Tensor`].
-///
-/// # Safety
-///
-/// The TensorRT API is thread-safe with regards to all operations on [`Tensor`].
-unsafe impl<'parent> Sync for Tensor<'parent> {}
-
 impl<'parent> Tensor<'parent> {
     /// Wrap internal pointer as [`Tensor`].
     ///
@@ -232,14 +927,14 @@ impl<'parent> Tensor<'parent> {
     /// Get the dimensions of a tensor.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html#aefa740255768fbe234730577cb24fac9)
-    pub fn get_dimensions(&self) -> Vec<i32> {
+    pub fn get_dimensions(&self) -> Dims {
         let internal = self.as_ptr();
         let mut dims = Vec::with_capacity(MAX_DIMS);
         let dims_ptr = dims.as_mut_ptr();
 
         let num_dimensions = cpp!(unsafe [
             internal as "void*",
-            dims_ptr as "int32_t*"
+            dims_ptr as "int64_t*"
         ] -> i32 as "int32_t" {
             auto dims = ((const ITensor*) internal)->getDimensions();
             if (dims.nbDims > 0) {
@@ -255,7 +950,209 @@ impl<'parent> Tensor<'parent> {
                 dims.set_len(num_dimensions as usize);
             }
         }
-        dims
+        Dims::new(dims)
+    }
+
+    /// Set the dimensions of a tensor.
+    ///
+    /// This only applies to network inputs. It can be used to make the dimensions of a parsed
+    /// network dynamic, for example to replace a fixed batch size with `-1`.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `dims` - Dimensions of the tensor. Use `-1` for dynamic dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If there are more than 8 dimensions.
+    pub fn set_dimensions(&mut self, dims: impl Into<Dims>) {
+        let dims = dims.into();
+        assert!(dims.len() <= MAX_DIMS, "tensor has more than 8 dimensions");
+        let internal = self.as_mut_ptr();
+        let dims_ptr = dims.as_ptr();
+        let nb_dims = dims.len() as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            dims_ptr as "const int64_t*",
+            nb_dims as "int32_t"
+        ] {
+            Dims dims;
+            dims.nbDims = nb_dims;
+            for (int i = 0; i < nb_dims; ++i) {
+                dims.d[i] = dims_ptr[i];
+            }
+            ((ITensor*) internal)->setDimensions(dims);
+        });
+    }
+
+    /// Get the data type of a tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Return value
+    ///
+    /// Data type of tensor, or `None` if the data type is not known to this crate.
+    pub fn data_type(&self) -> Option<DataType> {
+        let internal = self.as_ptr();
+        let data_type = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ITensor*) internal)->getType();
+        });
+        DataType::from_i32(data_type)
+    }
+
+    /// Set the data type of a tensor.
+    ///
+    /// For network inputs, this changes the type of data that must be supplied at runtime.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `data_type` - Data type of the tensor.
+    pub fn set_type(&mut self, data_type: DataType) {
+        let internal = self.as_mut_ptr();
+        let data_type = data_type as i32;
+        cpp!(unsafe [
+            internal as "void*",
+            data_type as "std::int32_t"
+        ] {
+            ((ITensor*) internal)->setType(static_cast<DataType>(data_type));
+        });
+    }
+
+    /// Whether or not the tensor is a shape tensor.
+    ///
+    /// Shape tensors hold values that describe the shape of other tensors, and are computed on the
+    /// host at runtime.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    pub fn is_shape_tensor(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            #pragma GCC diagnostic push
+            #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+            bool isShapeTensor = ((const ITensor*) internal)->isShapeTensor();
+            #pragma GCC diagnostic pop
+            return isShapeTensor;
+        })
+    }
+
+    /// Whether or not the tensor is an input of the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    pub fn is_network_input(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((const ITensor*) internal)->isNetworkInput();
+        })
+    }
+
+    /// Whether or not the tensor is an output of the network.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    pub fn is_network_output(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((const ITensor*) internal)->isNetworkOutput();
+        })
+    }
+
+    /// Set the dynamic range of the tensor.
+    ///
+    /// This can be used to apply externally computed quantization ranges when building an INT8
+    /// engine without a calibrator.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Minimum of the dynamic range.
+    /// * `max` - Maximum of the dynamic range.
+    ///
+    /// # Return value
+    ///
+    /// `true` if the dynamic range was set successfully, `false` if the range is invalid.
+    pub fn set_dynamic_range(&mut self, min: f32, max: f32) -> bool {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            min as "float",
+            max as "float"
+        ] -> bool as "bool" {
+            return ((ITensor*) internal)->setDynamicRange(min, max);
+        })
+    }
+
+    /// Whether or not the dynamic range has been set.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    pub fn dynamic_range_is_set(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((const ITensor*) internal)->dynamicRangeIsSet();
+        })
+    }
+
+    /// Get the minimum of the dynamic range.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Return value
+    ///
+    /// Minimum of the dynamic range if it has been set.
+    pub fn get_dynamic_range_min(&self) -> Option<f32> {
+        if !self.dynamic_range_is_set() {
+            return None;
+        }
+        let internal = self.as_ptr();
+        Some(cpp!(unsafe [
+            internal as "const void*"
+        ] -> f32 as "float" {
+            return ((const ITensor*) internal)->getDynamicRangeMin();
+        }))
+    }
+
+    /// Get the maximum of the dynamic range.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    ///
+    /// # Return value
+    ///
+    /// Maximum of the dynamic range if it has been set.
+    pub fn get_dynamic_range_max(&self) -> Option<f32> {
+        if !self.dynamic_range_is_set() {
+            return None;
+        }
+        let internal = self.as_ptr();
+        Some(cpp!(unsafe [
+            internal as "const void*"
+        ] -> f32 as "float" {
+            return ((const ITensor*) internal)->getDynamicRangeMax();
+        }))
+    }
+
+    /// Reset the dynamic range of the tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_tensor.html)
+    pub fn reset_dynamic_range(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            ((ITensor*) internal)->resetDynamicRange();
+        });
     }
 
     /// Get internal readonly pointer.
@@ -273,10 +1170,12 @@ impl<'parent> Tensor<'parent> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "async"))]
 mod tests {
     use crate::tests::utils::*;
 
+    use super::*;
+
     #[tokio::test]
     async fn test_network_inputs_and_outputs() {
         let (_, network) = simple_network!();
@@ -288,6 +1187,140 @@ mod tests {
         let outputs = network.outputs();
         let output = outputs.first().unwrap();
         assert_eq!(output.name(), "Y");
+        assert_eq!(input.data_type(), Some(DataType::Float));
+        assert_eq!(input.get_dimensions(), &[1, 2]);
+        assert!(input.is_network_input());
+        assert!(!input.is_shape_tensor());
+        assert!(output.is_network_output());
+        assert!(!output.is_network_input());
+    }
+
+    #[tokio::test]
+    async fn test_network_unmark_and_mark_output() {
+        let (_, network) = simple_network!();
+        let output = network.output(0);
+        network.unmark_output(&output);
+        assert_eq!(network.num_outputs(), 0);
+        assert!(!output.is_network_output());
+        network.mark_output(&output);
+        assert_eq!(network.num_outputs(), 1);
+        assert!(output.is_network_output());
+    }
+
+    #[tokio::test]
+    async fn test_network_input_set_dimensions_and_type() {
+        let (_, network) = simple_network!();
+        let mut input = network.input(0);
+        input.set_dimensions(&[-1, 2]);
+        input.set_type(DataType::Half);
+        assert_eq!(input.get_dimensions(), &[-1, 2]);
+        assert_eq!(input.data_type(), Some(DataType::Half));
+    }
+
+    #[tokio::test]
+    async fn test_network_strongly_typed() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let network = builder.network_definition(NetworkDefinitionCreationFlags::STRONGLY_TYPED);
+        assert!(network.is_strongly_typed());
+        let network = builder.network_definition(NetworkDefinitionCreationFlags::empty());
+        assert!(!network.is_strongly_typed());
+    }
+
+    #[tokio::test]
+    async fn test_network_add_layers() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let input = network.add_input("X", DataType::Float, &[1, 4]).unwrap();
+        let relu = network
+            .add_activation(&input, ActivationType::Relu)
+            .unwrap();
+        let sum = network
+            .add_elementwise(&input, &relu.output(0), ElementWiseOperation::Sum)
+            .unwrap();
+        let shuffle = network
+            .add_shuffle(&sum.output(0), Some(Dims::from(&[2, 2])))
+            .unwrap();
+        let mut output = shuffle.output(0);
+        output.set_name("Y");
+        network.mark_output(&output);
+        assert_eq!(relu.num_inputs(), 1);
+        assert_eq!(sum.num_inputs(), 2);
+        assert_eq!(network.num_inputs(), 1);
+        assert_eq!(network.num_outputs(), 1);
+        assert_eq!(network.outputs()[0].name(), "Y");
+        assert_eq!(network.outputs()[0].get_dimensions(), &[2, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_network_add_convolution_nd() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let input = network
+            .add_input("X", DataType::Float, &[1, 1, 4, 4])
+            .unwrap();
+        let kernel_weights = Weights::new(DataType::Float, &[1.0f32; 4]).unwrap();
+        let bias_weights = Weights::new(DataType::Float, &[0.0f32]).unwrap();
+        let convolution = network
+            .add_convolution_nd(&input, 1, &[2, 2], kernel_weights, bias_weights)
+            .unwrap();
+        assert_eq!(convolution.num_inputs(), 1);
+    }
+
+    #[test]
+    fn test_weights_element_size_mismatch() {
+        assert!(Weights::new(DataType::Float, &[0u8; 4]).is_err());
+        assert!(Weights::new(DataType::Half, &[0.0f32]).is_err());
+        assert!(Weights::new(DataType::Int4, &[0u8]).is_err());
+        assert!(Weights::new(DataType::Bool, &[1u8]).is_ok());
+        assert_eq!(
+            Weights::new(DataType::Int32, &[1i32, 2]).unwrap().count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_network_add_layers_too_many_dims() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let input = network.add_input("X", DataType::Float, &[1, 4]).unwrap();
+        assert!(matches!(
+            network.add_shuffle(&input, Some(Dims::from(&[1; 9]))),
+            Err(crate::Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            network.add_convolution_nd(&input, 1, &[1; 9], Weights::empty(), Weights::empty()),
+            Err(crate::Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_network_layer_precision() {
+        let (_, network) = simple_network!();
+        assert!(network.num_layers() > 0);
+        for mut layer in network.layers() {
+            assert!(!layer.precision_is_set());
+            layer.set_precision(DataType::Float);
+            assert!(layer.precision_is_set());
+            assert_eq!(layer.precision(), Some(DataType::Float));
+            layer.set_output_type(0, DataType::Float);
+            assert!(layer.output_type_is_set(0));
+            assert_eq!(layer.output_type(0), Some(DataType::Float));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tensor_dynamic_range() {
+        let (_, network) = simple_network!();
+        let mut input = network.input(0);
+        assert_eq!(input.get_dynamic_range_min(), None);
+        assert!(input.set_dynamic_range(-2.0, 2.0));
+        assert_eq!(input.get_dynamic_range_min(), Some(-2.0));
+        assert_eq!(input.get_dynamic_range_max(), Some(2.0));
+        input.reset_dynamic_range();
+        assert!(!input.dynamic_range_is_set());
     }
 
     #[tokio::test]
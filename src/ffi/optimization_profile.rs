@@ -2,8 +2,11 @@ use std::marker::PhantomData;
 
 use cpp::cpp;
 
+use crate::ffi::dims::Dims;
+use crate::ffi::pre::logger::capture_log;
 use crate::ffi::result;
 use crate::ffi::sync::builder::Builder;
+use crate::ffi::ErrorCode;
 
 /// Defined in `NvInferRuntimeBase.h`
 const MAX_DIMS: usize = 8;
@@ -61,7 +64,22 @@ impl<'builder> OptimizationProfile<'builder> {
 
     /// Set the minimum values for an input shape tensor.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ad89508bb5e59d46d106cb74d701934850
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `values` - Shape values.
+    #[inline]
+    pub fn try_set_min_shape_values(&mut self, input_name: &str, values: &[i32]) -> Result<()> {
+        self.set_shape_values(input_name, OptimizationProfileSelector::Min as i32, values)
+    }
+
+    /// Set the minimum values for an input shape tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
     ///
     /// # Arguments
     ///
@@ -71,14 +89,33 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_min_shape_values`, which reports what is inconsistent"
+    )]
     #[inline]
     pub fn set_min_shape_values(&mut self, input_name: &str, values: &[i32]) -> bool {
-        self.set_shape_values(input_name, OptimizationProfileSelector::Min as i32, values)
+        self.try_set_min_shape_values(input_name, values).is_ok()
     }
 
-    /// Set the optimium values for an input shape tensor.
+    /// Set the optimum values for an input shape tensor.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ad89508bb5e59d46d106cb74d701934850
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `values` - Shape values.
+    #[inline]
+    pub fn try_set_opt_shape_values(&mut self, input_name: &str, values: &[i32]) -> Result<()> {
+        self.set_shape_values(input_name, OptimizationProfileSelector::Opt as i32, values)
+    }
+
+    /// Set the optimum values for an input shape tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
     ///
     /// # Arguments
     ///
@@ -88,14 +125,33 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_opt_shape_values`, which reports what is inconsistent"
+    )]
     #[inline]
     pub fn set_opt_shape_values(&mut self, input_name: &str, values: &[i32]) -> bool {
-        self.set_shape_values(input_name, OptimizationProfileSelector::Opt as i32, values)
+        self.try_set_opt_shape_values(input_name, values).is_ok()
     }
 
     /// Set the maximum values for an input shape tensor.
     ///
-    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ad89508bb5e59d46d106cb74d701934850
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `values` - Shape values.
+    #[inline]
+    pub fn try_set_max_shape_values(&mut self, input_name: &str, values: &[i32]) -> Result<()> {
+        self.set_shape_values(input_name, OptimizationProfileSelector::Max as i32, values)
+    }
+
+    /// Set the maximum values for an input shape tensor.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html)
     ///
     /// # Arguments
     ///
@@ -105,9 +161,13 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_max_shape_values`, which reports what is inconsistent"
+    )]
     #[inline]
     pub fn set_max_shape_values(&mut self, input_name: &str, values: &[i32]) -> bool {
-        self.set_shape_values(input_name, OptimizationProfileSelector::Max as i32, values)
+        self.try_set_max_shape_values(input_name, values).is_ok()
     }
 
     /// Set the minimum / optimum / maximum values for an input shape tensor.
@@ -119,26 +179,31 @@ impl<'builder> OptimizationProfile<'builder> {
     /// * `input_name` - Name of input tensor.
     /// * `select` - Optimization profile selector as integer.
     /// * `values` - Shape values.
-    ///
-    /// # Return value
-    ///
-    /// `false` if an inconsistency was detected.
-    fn set_shape_values(&mut self, input_name: &str, select: i32, values: &[i32]) -> bool {
+    fn set_shape_values(&mut self, input_name: &str, select: i32, values: &[i32]) -> Result<()> {
         let internal = self.as_mut_ptr();
         let input_name_cstr = std::ffi::CString::new(input_name).unwrap();
         let input_name_ptr = input_name_cstr.as_ptr();
         let nb_values = values.len() as i32;
         let values_ptr = values.as_ptr();
-        let res = cpp!(unsafe [
-            internal as "void*",
-            input_name_ptr as "const char*",
-            select as "OptProfileSelector",
-            values_ptr as "const int32_t*",
-            nb_values as "int32_t"
-        ] -> bool as "bool" {
-            return ((IOptimizationProfile*) internal)->setShapeValues(input_name_ptr, select, values_ptr, nb_values);
+        let (success, log) = capture_log(|| {
+            cpp!(unsafe [
+                internal as "void*",
+                input_name_ptr as "const char*",
+                select as "OptProfileSelector",
+                values_ptr as "const int32_t*",
+                nb_values as "int32_t"
+            ] -> bool as "bool" {
+                return ((IOptimizationProfile*) internal)->setShapeValues(input_name_ptr, select, values_ptr, nb_values);
+            })
         });
-        res
+        if success {
+            Ok(())
+        } else {
+            Err(rejected(
+                format!("invalid shape values for input {input_name}: {values:?}"),
+                log,
+            ))
+        }
     }
 
     /// Get the minimum values for an input shape tensor.
@@ -231,6 +296,28 @@ impl<'builder> OptimizationProfile<'builder> {
         Ok(Some(values))
     }
 
+    /// Set the minimum dimensions for a dynamic input tensor.
+    ///
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ab723695382d6b03d4a0463b8cbe2b19f)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `dims` - Dimensions.
+    pub fn try_set_min_dimensions(
+        &mut self,
+        input_name: &str,
+        dims: impl Into<Dims>,
+    ) -> Result<()> {
+        self.set_dimensions(
+            input_name,
+            OptimizationProfileSelector::Min as i32,
+            &dims.into(),
+        )
+    }
+
     /// Set the minimum dimensions for a dynamic input tensor.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ab723695382d6b03d4a0463b8cbe2b19f)
@@ -243,8 +330,34 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
-    pub fn set_min_dimensions(&mut self, input_name: &str, dims: &[i32]) -> bool {
-        self.set_dimensions(input_name, OptimizationProfileSelector::Min as i32, dims)
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_min_dimensions`, which reports what is inconsistent"
+    )]
+    pub fn set_min_dimensions(&mut self, input_name: &str, dims: impl Into<Dims>) -> bool {
+        self.try_set_min_dimensions(input_name, dims).is_ok()
+    }
+
+    /// Set the optimum dimensions for a dynamic input tensor.
+    ///
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ab723695382d6b03d4a0463b8cbe2b19f)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `dims` - Dimensions.
+    pub fn try_set_opt_dimensions(
+        &mut self,
+        input_name: &str,
+        dims: impl Into<Dims>,
+    ) -> Result<()> {
+        self.set_dimensions(
+            input_name,
+            OptimizationProfileSelector::Opt as i32,
+            &dims.into(),
+        )
     }
 
     /// Set the optimum dimensions for a dynamic input tensor.
@@ -259,8 +372,34 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
-    pub fn set_opt_dimensions(&mut self, input_name: &str, dims: &[i32]) -> bool {
-        self.set_dimensions(input_name, OptimizationProfileSelector::Opt as i32, dims)
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_opt_dimensions`, which reports what is inconsistent"
+    )]
+    pub fn set_opt_dimensions(&mut self, input_name: &str, dims: impl Into<Dims>) -> bool {
+        self.try_set_opt_dimensions(input_name, dims).is_ok()
+    }
+
+    /// Set the maximum dimensions for a dynamic input tensor.
+    ///
+    /// If TensorRT detects an inconsistency, the returned error holds the messages it logged.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#ab723695382d6b03d4a0463b8cbe2b19f)
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `dims` - Dimensions.
+    pub fn try_set_max_dimensions(
+        &mut self,
+        input_name: &str,
+        dims: impl Into<Dims>,
+    ) -> Result<()> {
+        self.set_dimensions(
+            input_name,
+            OptimizationProfileSelector::Max as i32,
+            &dims.into(),
+        )
     }
 
     /// Set the maximum dimensions for a dynamic input tensor.
@@ -275,8 +414,12 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `false` if an inconsistency was detected.
-    pub fn set_max_dimensions(&mut self, input_name: &str, dims: &[i32]) -> bool {
-        self.set_dimensions(input_name, OptimizationProfileSelector::Max as i32, dims)
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_max_dimensions`, which reports what is inconsistent"
+    )]
+    pub fn set_max_dimensions(&mut self, input_name: &str, dims: impl Into<Dims>) -> bool {
+        self.try_set_max_dimensions(input_name, dims).is_ok()
     }
 
     /// Set the minimum / optimum / maximum dimensions for a dynamic input tensor.
@@ -288,33 +431,43 @@ impl<'builder> OptimizationProfile<'builder> {
     /// * `input_name` - Name of input tensor.
     /// * `select` - Optimization profile selector as integer.
     /// * `dims` - Dimensions.
-    ///
-    /// # Return value
-    ///
-    /// `false` if an inconsistency was detected.
-    fn set_dimensions(&mut self, input_name: &str, select: i32, dims: &[i32]) -> bool {
+    fn set_dimensions(&mut self, input_name: &str, select: i32, dims: &Dims) -> Result<()> {
+        if dims.len() > MAX_DIMS {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "dimensions {dims} for input {input_name} exceed maximum of {MAX_DIMS} dimensions"
+            )));
+        }
         let internal = self.as_mut_ptr();
         let input_name_cstr = std::ffi::CString::new(input_name).unwrap();
         let input_name_ptr = input_name_cstr.as_ptr();
         let nb_dims = dims.len() as i32;
         let dims_ptr = dims.as_ptr();
 
-        let res = cpp!(unsafe [
-            internal as "void*",
-            input_name_ptr as "const char*",
-            select as "OptProfileSelector",
-            dims_ptr as "const int32_t*",
-            nb_dims as "int32_t"
-        ] -> bool as "bool" {
-            nvinfer1::Dims xdims;
-            xdims.nbDims = nb_dims;
-            for (int i = 0; i < xdims.nbDims; ++i) {
-                xdims.d[i] = dims_ptr[i];
-            }
+        let (success, log) = capture_log(|| {
+            cpp!(unsafe [
+                internal as "void*",
+                input_name_ptr as "const char*",
+                select as "OptProfileSelector",
+                dims_ptr as "const int64_t*",
+                nb_dims as "int32_t"
+            ] -> bool as "bool" {
+                nvinfer1::Dims xdims;
+                xdims.nbDims = nb_dims;
+                for (int i = 0; i < xdims.nbDims; ++i) {
+                    xdims.d[i] = dims_ptr[i];
+                }
 
-            return ((IOptimizationProfile*) internal)->setDimensions(input_name_ptr, select, xdims);
+                return ((IOptimizationProfile*) internal)->setDimensions(input_name_ptr, select, xdims);
+            })
         });
-        res
+        if success {
+            Ok(())
+        } else {
+            Err(rejected(
+                format!("invalid dimensions for input {input_name}: {dims}"),
+                log,
+            ))
+        }
     }
 
     /// Get the minimum dimensions for a dynamic input tensor.
@@ -329,7 +482,7 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// Dimensions if they have been previously set.
-    pub fn get_min_dimensions(&self, input_name: &str) -> Option<Vec<i32>> {
+    pub fn get_min_dimensions(&self, input_name: &str) -> Option<Dims> {
         self.get_dimensions(input_name, OptimizationProfileSelector::Min as i32)
     }
 
@@ -345,7 +498,7 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// Dimensions if they have been previously set.
-    pub fn get_opt_dimensions(&self, input_name: &str) -> Option<Vec<i32>> {
+    pub fn get_opt_dimensions(&self, input_name: &str) -> Option<Dims> {
         self.get_dimensions(input_name, OptimizationProfileSelector::Opt as i32)
     }
 
@@ -361,7 +514,7 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// Dimensions if they have been previously set.
-    pub fn get_max_dimensions(&self, input_name: &str) -> Option<Vec<i32>> {
+    pub fn get_max_dimensions(&self, input_name: &str) -> Option<Dims> {
         self.get_dimensions(input_name, OptimizationProfileSelector::Max as i32)
     }
 
@@ -378,7 +531,7 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// Dimensions if they have been previously set.
-    fn get_dimensions(&self, input_name: &str, select: i32) -> Option<Vec<i32>> {
+    fn get_dimensions(&self, input_name: &str, select: i32) -> Option<Dims> {
         let internal = self.as_ptr();
         let input_name_cstr = std::ffi::CString::new(input_name).unwrap();
         let input_name_ptr = input_name_cstr.as_ptr();
@@ -389,7 +542,7 @@ impl<'builder> OptimizationProfile<'builder> {
             internal as "void*",
             input_name_ptr as "const char*",
             select as "OptProfileSelector",
-            dims_ptr as "int32_t*"
+            dims_ptr as "int64_t*"
         ] -> i32 as "int32_t" {
             auto dims = ((const IOptimizationProfile*) internal)->getDimensions(input_name_ptr, select);
             if (dims.nbDims > 0) {
@@ -404,12 +557,41 @@ impl<'builder> OptimizationProfile<'builder> {
             unsafe {
                 dims.set_len(num_dimensions as usize);
             }
-            Some(dims)
+            Some(Dims::new(dims))
         } else {
             None
         }
     }
 
+    /// Set a target for extra GPU memory that may be used by this profile.
+    ///
+    /// The target must be between 0 and 1 inclusive, otherwise an error is returned.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#abc9215e02ad6b5d911b35d45d59236e7)
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Additional memory that the builder should aim to maximally allocate for this profile, as a fraction of the memory it would use if the user did not impose any constraints on memory.
+    pub fn try_set_extra_memory_target(&mut self, target: f32) -> Result<()> {
+        let internal = self.as_ptr();
+        let (success, log) = capture_log(|| {
+            cpp!(unsafe [
+                internal as "const void*",
+                target as "float"
+            ] -> bool as "bool" {
+                return ((IOptimizationProfile*) internal)->setExtraMemoryTarget(target);
+            })
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(rejected(
+                format!("extra memory target {target} is not between 0 and 1"),
+                log,
+            ))
+        }
+    }
+
     /// Set a target for extra GPU memory that may be used by this profile.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_optimization_profile.html#abc9215e02ad6b5d911b35d45d59236e7)
@@ -421,14 +603,12 @@ impl<'builder> OptimizationProfile<'builder> {
     /// # Return value
     ///
     /// `true` if the input is in the valid range (between 0 and 1 inclusive), else `false`.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `try_set_extra_memory_target`, which returns an error for an invalid target"
+    )]
     pub fn set_extra_memory_target(&mut self, target: f32) -> bool {
-        let internal = self.as_ptr();
-        cpp!(unsafe [
-            internal as "const void*",
-            target as "float"
-        ] -> bool as "bool" {
-            return ((IOptimizationProfile*) internal)->setExtraMemoryTarget(target);
-        })
+        self.try_set_extra_memory_target(target).is_ok()
     }
 
     /// Get the extra memory target that has been defined for this profile.
@@ -469,3 +649,222 @@ impl<'builder> OptimizationProfile<'builder> {
         internal
     }
 }
+
+/// Create the error for a setter of [`OptimizationProfile`] that TensorRT rejected.
+///
+/// # Arguments
+///
+/// * `message` - Description of what was rejected.
+/// * `log` - Messages that TensorRT logged while the setter ran.
+fn rejected(message: String, log: Vec<String>) -> crate::error::Error {
+    let message = if log.is_empty() {
+        message
+    } else {
+        format!("{message} ({})", log.join("; "))
+    };
+    crate::error::Error::TensorRt(ErrorCode::InvalidArgument, message, Vec::new())
+}
+
+/// Builder for an [`OptimizationProfile`] that validates the shape ranges of its inputs.
+///
+/// TensorRT only reports an inconsistent shape range by returning `false` from the setters of
+/// [`OptimizationProfile`], without saying what is wrong. This builder collects the shape range of
+/// every input first, checks them in Rust and reports the offending input and dimension, and only
+/// then applies them to an [`OptimizationProfile`].
+#[derive(Debug, Clone, Default)]
+pub struct OptimizationProfileBuilder {
+    inputs: Vec<(String, Dims, Dims, Dims)>,
+}
+
+impl OptimizationProfileBuilder {
+    /// Create a new empty [`OptimizationProfileBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the shape range of a dynamic input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `min` - Minimum dimensions.
+    /// * `opt` - Dimensions to optimize for.
+    /// * `max` - Maximum dimensions.
+    pub fn add_input(
+        &mut self,
+        input_name: &str,
+        min: impl Into<Dims>,
+        opt: impl Into<Dims>,
+        max: impl Into<Dims>,
+    ) {
+        self.inputs
+            .push((input_name.to_string(), min.into(), opt.into(), max.into()));
+    }
+
+    /// Set the shape range of a dynamic input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `min` - Minimum dimensions.
+    /// * `opt` - Dimensions to optimize for.
+    /// * `max` - Maximum dimensions.
+    pub fn with_input(
+        mut self,
+        input_name: &str,
+        min: impl Into<Dims>,
+        opt: impl Into<Dims>,
+        max: impl Into<Dims>,
+    ) -> Self {
+        self.add_input(input_name, min, opt, max);
+        self
+    }
+
+    /// Check the shape ranges of all inputs.
+    ///
+    /// For every input, `min`, `opt` and `max` must have the same number of dimensions (at most
+    /// 8), no dimension may be dynamic, and every dimension must satisfy `min <= opt <= max`. Each
+    /// input may only be added once.
+    ///
+    /// # Return value
+    ///
+    /// [`crate::Error::InvalidArgument`] naming the offending input if any of the checks fails.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |input_name: &str, message: String| {
+            Err(crate::error::Error::InvalidArgument(format!(
+                "invalid shape range for input {input_name}: {message}"
+            )))
+        };
+        let mut input_names = std::collections::HashSet::new();
+        for (input_name, min, opt, max) in &self.inputs {
+            if !input_names.insert(input_name.as_str()) {
+                return invalid(input_name, "input was added more than once".to_string());
+            }
+            if min.len() != opt.len() || opt.len() != max.len() {
+                return invalid(
+                    input_name,
+                    format!("number of dimensions differs: min {min}, opt {opt}, max {max}"),
+                );
+            }
+            if min.len() > MAX_DIMS {
+                return invalid(
+                    input_name,
+                    format!("{} dimensions exceeds maximum of {MAX_DIMS}", min.len()),
+                );
+            }
+            if min.is_dynamic() || opt.is_dynamic() || max.is_dynamic() {
+                return invalid(
+                    input_name,
+                    format!("dimensions must not be dynamic: min {min}, opt {opt}, max {max}"),
+                );
+            }
+            for (index, ((min_dim, opt_dim), max_dim)) in
+                min.iter().zip(opt.iter()).zip(max.iter()).enumerate()
+            {
+                if !(min_dim <= opt_dim && opt_dim <= max_dim) {
+                    return invalid(
+                        input_name,
+                        format!(
+                            "dimension {index} must satisfy min <= opt <= max, but min is \
+                             {min_dim}, opt is {opt_dim} and max is {max_dim}"
+                        ),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the shape ranges and apply them to an existing optimization profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `optimization_profile` - Optimization profile to apply shape ranges to.
+    pub fn apply(&self, optimization_profile: &mut OptimizationProfile) -> Result<()> {
+        self.validate()?;
+        for (input_name, min, opt, max) in &self.inputs {
+            optimization_profile.try_set_min_dimensions(input_name, min)?;
+            optimization_profile.try_set_opt_dimensions(input_name, opt)?;
+            optimization_profile.try_set_max_dimensions(input_name, max)?;
+        }
+        Ok(())
+    }
+
+    /// Validate the shape ranges and create an optimization profile with them.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - Builder to create optimization profile with.
+    #[cfg(feature = "async")]
+    pub fn build<'builder>(
+        &self,
+        builder: &'builder mut crate::builder::Builder,
+    ) -> Result<OptimizationProfile<'builder>> {
+        let mut optimization_profile = builder.optimization_profile()?;
+        self.apply(&mut optimization_profile)?;
+        Ok(optimization_profile)
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_optimization_profile_builder() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let optimization_profile = OptimizationProfileBuilder::new()
+            .with_input("X", &[1, 2], &[2, 2], &[4, 2])
+            .build(&mut builder)
+            .unwrap();
+        assert_eq!(
+            optimization_profile.get_opt_dimensions("X"),
+            Some(Dims::from(&[2, 2]))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_optimization_profile_try_set() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let mut optimization_profile = builder.optimization_profile().unwrap();
+        optimization_profile
+            .try_set_min_dimensions("X", &[1, 2])
+            .unwrap();
+        assert!(optimization_profile
+            .try_set_min_dimensions("X", &[1, 2, 3, 4, 5, 6, 7, 8, 9])
+            .is_err());
+        optimization_profile
+            .try_set_extra_memory_target(0.5)
+            .unwrap();
+        assert!(matches!(
+            optimization_profile.try_set_extra_memory_target(2.0),
+            Err(crate::error::Error::TensorRt(
+                ErrorCode::InvalidArgument,
+                ..
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_optimization_profile_builder_validate() {
+        assert!(OptimizationProfileBuilder::new()
+            .with_input("X", &[1, 2], &[2, 2], &[4, 2])
+            .validate()
+            .is_ok());
+        for builder in [
+            OptimizationProfileBuilder::new().with_input("X", &[4, 2], &[2, 2], &[4, 2]),
+            OptimizationProfileBuilder::new().with_input("X", &[1, 2], &[2, 2], &[4, 2, 1]),
+            OptimizationProfileBuilder::new().with_input("X", &[-1, 2], &[2, 2], &[4, 2]),
+            OptimizationProfileBuilder::new()
+                .with_input("X", &[1, 2], &[2, 2], &[4, 2])
+                .with_input("X", &[1, 2], &[2, 2], &[4, 2]),
+        ] {
+            match builder.validate() {
+                Err(crate::error::Error::InvalidArgument(message)) => {
+                    assert!(message.contains("input X"), "{message}");
+                }
+                result => panic!("expected invalid argument, got {result:?}"),
+            }
+        }
+    }
+}
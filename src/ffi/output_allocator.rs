@@ -0,0 +1,205 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_OUTPUT_ALLOCATOR
+    #define ODDITY_FFI_OUTPUT_ALLOCATOR
+
+    // Output allocator that forwards all calls to a Rust implementation of `OutputAllocator`. The
+    // Rust allocator is owned by the Rust side, this class only holds a pointer to it.
+    //
+    // Note that `reallocateOutput` is deprecated since TensorRT 10 in favor of its asynchronous
+    // variant, which by default forwards to it.
+    #pragma GCC diagnostic push
+    #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+    class RustOutputAllocator : public IOutputAllocator
+    {
+    public:
+        RustOutputAllocator(void* allocator) : m_allocator(allocator) {}
+
+        void* reallocateOutput(
+            const char* tensorName,
+            void* currentMemory,
+            std::uint64_t size,
+            std::uint64_t alignment
+        ) noexcept override {
+            void* allocator = m_allocator;
+            return rust!(RustOutputAllocator_reallocateOutput [
+                allocator : *mut std::ffi::c_void as "void*",
+                tensorName : *const std::os::raw::c_char as "const char*",
+                currentMemory : *mut std::ffi::c_void as "void*",
+                size : u64 as "std::uint64_t",
+                alignment : u64 as "std::uint64_t"
+            ] -> *mut std::ffi::c_void as "void*" {
+                reallocate_output_raw(allocator, tensorName, currentMemory, size, alignment)
+            });
+        }
+
+        void notifyShape(const char* tensorName, const Dims& dims) noexcept override {
+            void* allocator = m_allocator;
+            std::int32_t numDims = dims.nbDims;
+            std::int64_t dimsRaw[Dims::MAX_DIMS];
+            for (std::int32_t i = 0; i < numDims; i++) {
+                dimsRaw[i] = (std::int64_t) dims.d[i];
+            }
+            const std::int64_t* dimsPtr = dimsRaw;
+            rust!(RustOutputAllocator_notifyShape [
+                allocator : *mut std::ffi::c_void as "void*",
+                tensorName : *const std::os::raw::c_char as "const char*",
+                dimsPtr : *const i64 as "const std::int64_t*",
+                numDims : i32 as "std::int32_t"
+            ] {
+                notify_shape_raw(allocator, tensorName, dimsPtr, numDims);
+            });
+        }
+
+    private:
+        void* m_allocator;
+    };
+    #pragma GCC diagnostic pop
+
+    #endif // ODDITY_FFI_OUTPUT_ALLOCATOR
+}}
+
+/// Allocator for output tensors with data-dependent shapes.
+///
+/// Some layers (such as `NonMaxSuppression`) produce outputs whose size is only known after
+/// inference has started. For such outputs, TensorRT asks the output allocator for memory once
+/// the size is known, and reports the final shape through [`OutputAllocator::notify_shape()`].
+///
+/// Install an output allocator with [`crate::ExecutionContext::set_output_allocator()`] and do not
+/// bind a buffer for the output tensor when enqueueing.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_output_allocator.html)
+pub trait OutputAllocator: Send {
+    /// Return a pointer to device memory for an output tensor.
+    ///
+    /// The returned memory must be at least `size` bytes large and aligned to `alignment`. If
+    /// `current_memory` is already large enough, it may be returned as-is. Returning a null pointer
+    /// signals that allocation failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `current_memory` - Memory that was previously returned for this tensor, or null.
+    /// * `size` - Number of bytes required.
+    /// * `alignment` - Required alignment in bytes.
+    fn reallocate_output(
+        &mut self,
+        tensor_name: &str,
+        current_memory: *mut std::ffi::c_void,
+        size: usize,
+        alignment: usize,
+    ) -> *mut std::ffi::c_void;
+
+    /// Called by TensorRT with the final shape of an output tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `dims` - Dimensions of output tensor.
+    fn notify_shape(&mut self, tensor_name: &str, dims: &[i64]);
+}
+
+/// Bridge between a Rust [`OutputAllocator`] and a TensorRT `IOutputAllocator`.
+///
+/// Owns both the Rust allocator and the C++ object that forwards to it.
+pub(crate) struct OutputAllocatorBridge {
+    internal: *mut std::ffi::c_void,
+    _allocator: Box<Box<dyn OutputAllocator>>,
+}
+
+/// Implements [`Send`] for [`OutputAllocatorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust allocator, which is [`Send`] itself.
+unsafe impl Send for OutputAllocatorBridge {}
+
+/// Implements [`Sync`] for [`OutputAllocatorBridge`].
+///
+/// # Safety
+///
+/// The bridge exposes no operations through a shared reference.
+unsafe impl Sync for OutputAllocatorBridge {}
+
+impl OutputAllocatorBridge {
+    /// Create a new bridge for the given allocator.
+    ///
+    /// # Arguments
+    ///
+    /// * `allocator` - Rust output allocator to forward to.
+    pub(crate) fn new(allocator: Box<dyn OutputAllocator>) -> Self {
+        // The allocator is boxed twice so that we can hand a thin pointer to C++.
+        let mut allocator = Box::new(allocator);
+        let allocator_ptr =
+            allocator.as_mut() as *mut Box<dyn OutputAllocator> as *mut std::ffi::c_void;
+        let internal = cpp!(unsafe [
+            allocator_ptr as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) new RustOutputAllocator(allocator_ptr);
+        });
+        OutputAllocatorBridge {
+            internal,
+            _allocator: allocator,
+        }
+    }
+
+    /// Get internal pointer to `IOutputAllocator`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let OutputAllocatorBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for OutputAllocatorBridge {
+    fn drop(&mut self) {
+        let OutputAllocatorBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            delete ((RustOutputAllocator*) internal);
+        });
+    }
+}
+
+/// Raw handler for `IOutputAllocator::reallocateOutput`.
+///
+/// # Safety
+///
+/// The caller must ensure that `allocator` points to a live `Box<dyn OutputAllocator>` and that
+/// `tensor_name` is a valid pointer to a C string.
+unsafe fn reallocate_output_raw(
+    allocator: *mut std::ffi::c_void,
+    tensor_name: *const std::os::raw::c_char,
+    current_memory: *mut std::ffi::c_void,
+    size: u64,
+    alignment: u64,
+) -> *mut std::ffi::c_void {
+    let allocator = &mut *(allocator as *mut Box<dyn OutputAllocator>);
+    let tensor_name = std::ffi::CStr::from_ptr(tensor_name).to_string_lossy();
+    allocator.reallocate_output(
+        &tensor_name,
+        current_memory,
+        size as usize,
+        alignment as usize,
+    )
+}
+
+/// Raw handler for `IOutputAllocator::notifyShape`.
+///
+/// # Safety
+///
+/// The caller must ensure that `allocator` points to a live `Box<dyn OutputAllocator>`, that
+/// `tensor_name` is a valid pointer to a C string and that `dims` points to `num_dims` values.
+unsafe fn notify_shape_raw(
+    allocator: *mut std::ffi::c_void,
+    tensor_name: *const std::os::raw::c_char,
+    dims: *const i64,
+    num_dims: i32,
+) {
+    let allocator = &mut *(allocator as *mut Box<dyn OutputAllocator>);
+    let tensor_name = std::ffi::CStr::from_ptr(tensor_name).to_string_lossy();
+    let dims = std::slice::from_raw_parts(dims, num_dims.max(0) as usize);
+    allocator.notify_shape(&tensor_name, dims);
+}
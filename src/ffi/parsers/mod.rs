@@ -0,0 +1,7 @@
+//! Parsers that import models into a TensorRT network definition.
+//!
+//! Each parser is behind a feature flag so that deployments that only deserialize engines do not
+//! need to link the parser libraries.
+
+#[cfg(feature = "onnx")]
+pub mod onnx;
@@ -0,0 +1,758 @@
+use cpp::cpp;
+
+use crate::error::last_error;
+use crate::ffi::network::NetworkDefinition;
+use crate::ffi::sync::refitter::Refitter;
+use crate::ffi::Severity;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// For parsing an ONNX model into a TensorRT network definition ([`crate::NetworkDefinition`]).
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+pub struct Parser(*mut std::ffi::c_void);
+
+impl Parser {
+    /// Create new parser, parse ONNX file and return a [`crate::NetworkDefinition`].
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html#a973ac2ed682f18c4c6258ed93fc8efa3)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition to use.
+    /// * `path` - Path to file to parse.
+    ///
+    /// # Return value
+    ///
+    /// Parsed network definition.
+    pub fn parse_network_definition_from_file(
+        network_definition: NetworkDefinition,
+        path: &impl AsRef<std::path::Path>,
+    ) -> Result<NetworkDefinition> {
+        Self::parse_network_definition_from_file_with_verbosity(
+            network_definition,
+            path,
+            Severity::Verbose,
+        )
+    }
+
+    /// Create new parser, parse ONNX file with the given verbosity and return a
+    /// [`crate::NetworkDefinition`].
+    ///
+    /// The ONNX file is parsed directly from disk, so external weights that the model refers to by
+    /// relative path are resolved relative to the directory of the model file.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html#a973ac2ed682f18c4c6258ed93fc8efa3)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition to use.
+    /// * `path` - Path to file to parse.
+    /// * `verbosity` - Least severe level of messages that the parser reports.
+    ///
+    /// # Return value
+    ///
+    /// Parsed network definition.
+    pub fn parse_network_definition_from_file_with_verbosity(
+        network_definition: NetworkDefinition,
+        path: &impl AsRef<std::path::Path>,
+        verbosity: Severity,
+    ) -> Result<NetworkDefinition> {
+        Self::parse_network_definition_from_file_with_flags(
+            network_definition,
+            path,
+            verbosity,
+            &[],
+        )
+    }
+
+    /// Create new parser with the given flags set, parse ONNX file with the given verbosity and
+    /// return a [`crate::NetworkDefinition`].
+    ///
+    /// The parser stays attached to the network definition, and can be retrieved with
+    /// [`crate::NetworkDefinition::onnx_parser()`], for example to query the plugin libraries that
+    /// a version compatible engine requires.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition to use.
+    /// * `path` - Path to file to parse.
+    /// * `verbosity` - Least severe level of messages that the parser reports.
+    /// * `flags` - Parser flags to set before parsing.
+    ///
+    /// # Return value
+    ///
+    /// Parsed network definition.
+    pub fn parse_network_definition_from_file_with_flags(
+        mut network_definition: NetworkDefinition,
+        path: &impl AsRef<std::path::Path>,
+        verbosity: Severity,
+        flags: &[ParserFlag],
+    ) -> Result<NetworkDefinition> {
+        // SAFETY: The call to `Parser::new` is unsafe because we must ensure that the new parser
+        // outlives `network_definition`. We manually make sure of that here by putting the parser
+        // inside `NetworkDefinition` and such it will only be destroyed when `network_definition`
+        // is.
+        unsafe {
+            let mut parser = Self::new(&mut network_definition);
+            for flag in flags {
+                parser.set_flag(*flag)?;
+            }
+            parser.parse_from_file(path, verbosity)?;
+            // Put parser object in `network_definition` because destroying the parser before the
+            // network definition is not allowed.
+            network_definition._parser = Some(parser);
+        }
+        Ok(network_definition)
+    }
+
+    /// Create new parser, parse serialized ONNX model and return a [`crate::NetworkDefinition`].
+    ///
+    /// Since there is no model file, the model cannot refer to external weights.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition to use.
+    /// * `buffer` - Serialized ONNX model.
+    ///
+    /// # Return value
+    ///
+    /// Parsed network definition.
+    pub fn parse_network_definition_from_bytes(
+        mut network_definition: NetworkDefinition,
+        buffer: &[u8],
+    ) -> Result<NetworkDefinition> {
+        // SAFETY: The parser is put inside `network_definition` so that it is only destroyed when
+        // `network_definition` is. See `parse_network_definition_from_file_with_flags`.
+        unsafe {
+            let mut parser = Self::new(&mut network_definition);
+            parser.parse_from_bytes(buffer)?;
+            network_definition._parser = Some(parser);
+        }
+        Ok(network_definition)
+    }
+
+    /// Check whether TensorRT supports a particular ONNX model.
+    ///
+    /// The model is parsed into the given network definition, which is consumed and discarded
+    /// afterwards. Callers should pass a fresh network definition that is not used for anything
+    /// else.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `network_definition` - Network definition to use.
+    /// * `buffer` - Serialized ONNX model.
+    ///
+    /// # Return value
+    ///
+    /// Whether or not the model is supported and the subgraphs that the model was partitioned in,
+    /// each of which indicates whether or not it is supported.
+    pub fn supports_model(
+        mut network_definition: NetworkDefinition,
+        buffer: &[u8],
+    ) -> (bool, Vec<SubgraphSupport>) {
+        // SAFETY: The call to `Parser::new` is unsafe because we must ensure that the new parser
+        // outlives `network_definition`. We make sure of that by explicitly dropping the network
+        // definition before the parser.
+        let parser = unsafe { Self::new(&mut network_definition) };
+        let internal = parser.as_ptr();
+        let buffer_ptr = buffer.as_ptr() as *const std::ffi::c_void;
+        let buffer_size = buffer.len();
+        let mut subgraphs: Vec<SubgraphSupport> = Vec::new();
+        let subgraphs_ptr = &mut subgraphs as *mut Vec<SubgraphSupport>;
+        let supported = cpp!(unsafe [
+            internal as "const void*",
+            buffer_ptr as "const void*",
+            buffer_size as "std::size_t",
+            subgraphs_ptr as "void*"
+        ] -> bool as "bool" {
+            #ifdef ODDITY_FFI_ONNX
+            SubGraphCollection_t subgraphCollection;
+            bool supported = ((IParser*) internal)->supportsModel(
+                buffer_ptr,
+                buffer_size,
+                subgraphCollection
+            );
+            for (const auto& subgraph : subgraphCollection) {
+                const std::size_t* node_indices_ptr = subgraph.first.data();
+                std::size_t num_node_indices = subgraph.first.size();
+                bool subgraph_supported = subgraph.second;
+                rust!(Parser_pushSubgraphSupport [
+                    subgraphs_ptr : *mut Vec<SubgraphSupport> as "void*",
+                    node_indices_ptr : *const usize as "const std::size_t*",
+                    num_node_indices : usize as "std::size_t",
+                    subgraph_supported : bool as "bool"
+                ] {
+                    let node_indices = if num_node_indices > 0 {
+                        std::slice::from_raw_parts(node_indices_ptr, num_node_indices).to_vec()
+                    } else {
+                        Vec::new()
+                    };
+                    (*subgraphs_ptr).push(SubgraphSupport {
+                        node_indices,
+                        supported: subgraph_supported,
+                    });
+                });
+            }
+            return supported;
+            #else
+            return false;
+            #endif
+        });
+        drop(network_definition);
+        drop(parser);
+        (supported, subgraphs)
+    }
+
+    /// Set a parser flag.
+    ///
+    /// Flags must be set before parsing to have an effect. Requires TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to set.
+    ///
+    /// # Return value
+    ///
+    /// An error if the flag is not supported by the version of TensorRT in use.
+    pub fn set_flag(&mut self, flag: ParserFlag) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && (NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6))
+            ((IParser*) internal)->setFlag((OnnxParserFlag) flag_value);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(unsupported_flag_error(flag))
+        }
+    }
+
+    /// Clear a parser flag.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to clear.
+    ///
+    /// # Return value
+    ///
+    /// An error if the flag is not supported by the version of TensorRT in use.
+    pub fn clear_flag(&mut self, flag: ParserFlag) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let flag_value = flag as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && (NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6))
+            ((IParser*) internal)->clearFlag((OnnxParserFlag) flag_value);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(unsupported_flag_error(flag))
+        }
+    }
+
+    /// Get whether a parser flag is set.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to check.
+    ///
+    /// # Return value
+    ///
+    /// `true` if the flag is set. Flags that are not supported by the version of TensorRT in use
+    /// are never set.
+    pub fn get_flag(&self, flag: ParserFlag) -> bool {
+        let internal = self.as_ptr();
+        let flag_value = flag as i32;
+        cpp!(unsafe [
+            internal as "const void*",
+            flag_value as "std::int32_t"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && (NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6))
+            return ((const IParser*) internal)->getFlag((OnnxParserFlag) flag_value);
+            #else
+            return false;
+            #endif
+        })
+    }
+
+    /// Get the plugin libraries that the parsed model requires when it is built as a version
+    /// compatible engine.
+    ///
+    /// These libraries must be shipped with the engine and loaded at runtime with
+    /// [`crate::Runtime::load_library()`]. Requires TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Return value
+    ///
+    /// Paths of the plugin libraries.
+    pub fn get_used_vc_plugin_libraries(&self) -> Result<Vec<String>> {
+        let internal = self.as_ptr();
+        let mut libraries: Vec<String> = Vec::new();
+        let libraries_ptr = &mut libraries as *mut Vec<String>;
+        let success = cpp!(unsafe [
+            internal as "const void*",
+            libraries_ptr as "void*"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && (NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6))
+            std::int64_t nbPluginLibs = 0;
+            const char* const* pluginLibs = ((const IParser*) internal)->getUsedVCPluginLibraries(
+                nbPluginLibs
+            );
+            if (nbPluginLibs < 0) {
+                return false;
+            }
+            for (std::int64_t i = 0; i < nbPluginLibs; i++) {
+                const char* library = pluginLibs[i];
+                rust!(Parser_pushUsedVcPluginLibrary [
+                    libraries_ptr : *mut Vec<String> as "void*",
+                    library : *const std::os::raw::c_char as "const char*"
+                ] {
+                    (*libraries_ptr).push(
+                        std::ffi::CStr::from_ptr(library).to_string_lossy().into_owned(),
+                    );
+                });
+            }
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if success {
+            Ok(libraries)
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Parse ONNX file.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html#a973ac2ed682f18c4c6258ed93fc8efa3)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to file to parse.
+    /// * `verbosity` - Least severe level of messages that the parser reports.
+    fn parse_from_file(
+        &mut self,
+        path: &impl AsRef<std::path::Path>,
+        verbosity: Severity,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let path = path.as_ref();
+        let path_ffi = path
+            .to_str()
+            .and_then(|path| std::ffi::CString::new(path).ok())
+            .ok_or_else(|| {
+                crate::error::Error::InvalidArgument(format!("invalid path: {}", path.display()))
+            })?;
+        let path_ptr = path_ffi.as_ptr();
+        let verbosity = verbosity.as_i32();
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            path_ptr as "const char*",
+            verbosity as "std::int32_t"
+        ] -> bool as "bool" {
+            #ifdef ODDITY_FFI_ONNX
+            return ((IParser*) internal)->parseFromFile(path_ptr, verbosity);
+            #else
+            return false;
+            #endif
+        });
+        if ret {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Parse serialized ONNX model.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Serialized ONNX model.
+    fn parse_from_bytes(&mut self, buffer: &[u8]) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let buffer_ptr = buffer.as_ptr() as *const std::ffi::c_void;
+        let buffer_size = buffer.len();
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            buffer_ptr as "const void*",
+            buffer_size as "std::size_t"
+        ] -> bool as "bool" {
+            #ifdef ODDITY_FFI_ONNX
+            return ((IParser*) internal)->parse(buffer_ptr, buffer_size);
+            #else
+            return false;
+            #endif
+        });
+        if ret {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Create new parser.
+    ///
+    /// # Arguments
+    ///
+    /// * Reference to network definition to attach to parser.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that the [`Parser`] outlives the given [`NetworkDefinition`].
+    unsafe fn new(network_definition: &mut NetworkDefinition) -> Self {
+        let network_definition_internal = network_definition.as_ptr();
+        let internal = cpp!(unsafe [
+            network_definition_internal as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #ifdef ODDITY_FFI_ONNX
+            return createParser(
+                *((INetworkDefinition*) network_definition_internal),
+                GLOBAL_LOGGER
+            );
+            #else
+            return nullptr;
+            #endif
+        });
+        Parser(internal)
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let Parser(internal) = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let Parser(internal) = *self;
+        internal
+    }
+}
+
+/// For refitting an engine with the weights of an ONNX model.
+///
+/// The parser refitter sets the weights of the ONNX model on a [`Refitter`]. The engine must have
+/// been built from a model with the same structure. Requires TensorRT 10 or up.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser_refitter.html)
+pub struct ParserRefitter<'refitter, 'engine> {
+    internal: *mut std::ffi::c_void,
+    refitter: &'refitter mut Refitter<'engine>,
+}
+
+/// Implements [`Send`] for [`ParserRefitter`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`ParserRefitter`].
+unsafe impl Send for ParserRefitter<'_, '_> {}
+
+/// Implements [`Sync`] for [`ParserRefitter`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`ParserRefitter`].
+unsafe impl Sync for ParserRefitter<'_, '_> {}
+
+impl<'refitter, 'engine> ParserRefitter<'refitter, 'engine> {
+    /// Create a new [`ParserRefitter`] that sets weights on `refitter`.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvonnxparser.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `refitter` - Refitter of the engine to refit.
+    pub fn new(refitter: &'refitter mut Refitter<'engine>) -> Result<Self> {
+        let refitter_internal = refitter.as_mut_ptr();
+        let internal = cpp!(unsafe [
+            refitter_internal as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if defined(ODDITY_FFI_ONNX) && NV_TENSORRT_MAJOR >= 10
+            return createParserRefitter(*((IRefitter*) refitter_internal), GLOBAL_LOGGER);
+            #else
+            return nullptr;
+            #endif
+        });
+        if internal.is_null() {
+            return Err(crate::error::Error::InvalidArgument(
+                "parser refitter not supported by this version of TensorRT".to_string(),
+            ));
+        }
+        Ok(Self { internal, refitter })
+    }
+
+    /// Set the weights of a serialized ONNX model on the refitter.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser_refitter.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Serialized ONNX model.
+    pub fn refit_from_bytes(&mut self, buffer: &[u8]) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let buffer_ptr = buffer.as_ptr() as *const std::ffi::c_void;
+        let buffer_size = buffer.len();
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            buffer_ptr as "const void*",
+            buffer_size as "std::size_t"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && NV_TENSORRT_MAJOR >= 10
+            return ((IParserRefitter*) internal)->refitFromBytes(buffer_ptr, buffer_size);
+            #else
+            return false;
+            #endif
+        });
+        if ret {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Set the weights of an ONNX file on the refitter.
+    ///
+    /// External weights that the model refers to by relative path are resolved relative to the
+    /// directory of the model file.
+    ///
+    /// Note that this function is CPU-intensive. Callers should not use it in async context or
+    /// spawn a blocking task for it.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser_refitter.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to ONNX file.
+    pub fn refit_from_file(&mut self, path: &impl AsRef<std::path::Path>) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let path = path.as_ref();
+        let path_ffi = path
+            .to_str()
+            .and_then(|path| std::ffi::CString::new(path).ok())
+            .ok_or_else(|| {
+                crate::error::Error::InvalidArgument(format!("invalid path: {}", path.display()))
+            })?;
+        let path_ptr = path_ffi.as_ptr();
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            path_ptr as "const char*"
+        ] -> bool as "bool" {
+            #if defined(ODDITY_FFI_ONNX) && NV_TENSORRT_MAJOR >= 10
+            return ((IParserRefitter*) internal)->refitFromFile(path_ptr);
+            #else
+            return false;
+            #endif
+        });
+        if ret {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Update the engine with the weights that were set.
+    ///
+    /// The weights are owned by the parser refitter, so the engine must be refitted before it is
+    /// dropped.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_refitter.html)
+    #[inline(always)]
+    pub fn refit_cuda_engine(&mut self) -> Result<()> {
+        self.refitter.refit_cuda_engine()
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        self.internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        self.internal
+    }
+}
+
+impl Drop for ParserRefitter<'_, '_> {
+    fn drop(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #if defined(ODDITY_FFI_ONNX) && NV_TENSORRT_MAJOR >= 10
+            delete (IParserRefitter*) internal;
+            #endif
+        });
+    }
+}
+
+/// Support status of a subgraph of an ONNX model.
+///
+/// Produced by [`Parser::supports_model`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubgraphSupport {
+    /// Indices of the ONNX nodes in the subgraph.
+    pub node_indices: Vec<usize>,
+    /// Whether or not TensorRT supports the subgraph.
+    pub supported: bool,
+}
+
+/// Flags for configuring the ONNX parser.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvonnxparser.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ParserFlag {
+    /// Parse the ONNX `InstanceNormalization` operator with the native TensorRT layer instead of
+    /// the plugin. Version compatible engines require this flag, since they cannot contain the
+    /// plugin.
+    ///
+    /// Requires TensorRT 8.6 or later.
+    NativeInstanceNorm = 0,
+}
+
+/// Create error for parser flag that is not supported by the version of TensorRT in use.
+///
+/// # Arguments
+///
+/// * `flag` - Unsupported flag.
+fn unsupported_flag_error(flag: ParserFlag) -> crate::error::Error {
+    crate::error::Error::InvalidArgument(format!(
+        "parser flag {flag:?} not supported by this version of TensorRT"
+    ))
+}
+
+impl Drop for Parser {
+    fn drop(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #ifdef ODDITY_FFI_ONNX
+            destroy((IParser*) internal);
+            #endif
+        });
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+
+    use crate::tests::onnx::*;
+    use crate::{Builder, NetworkDefinitionCreationFlags};
+
+    #[tokio::test]
+    async fn test_parser_parses_onnx_file() {
+        let simple_onnx_file = simple_onnx_file!();
+        let mut builder = Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        assert!(
+            Parser::parse_network_definition_from_file(network, &simple_onnx_file.path()).is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parser_parses_onnx_file_with_verbosity() {
+        let simple_onnx_file = simple_onnx_file!();
+        let mut builder = Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        assert!(Parser::parse_network_definition_from_file_with_verbosity(
+            network,
+            &simple_onnx_file.path(),
+            Severity::Warning,
+        )
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_parser_parses_onnx_file_with_flags() {
+        let simple_onnx_file = simple_onnx_file!();
+        let mut builder = Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let network = Parser::parse_network_definition_from_file_with_flags(
+            network,
+            &simple_onnx_file.path(),
+            Severity::Warning,
+            &[ParserFlag::NativeInstanceNorm],
+        )
+        .unwrap();
+        let parser = network.onnx_parser().unwrap();
+        assert!(parser.get_flag(ParserFlag::NativeInstanceNorm));
+        assert!(parser.get_used_vc_plugin_libraries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_parser_supports_model() {
+        let mut builder = Builder::new().await.unwrap();
+        let network =
+            builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        let (supported, subgraphs) = Parser::supports_model(network, SIMPLE_ONNX);
+        assert!(supported);
+        assert!(!subgraphs.is_empty());
+        assert!(subgraphs.iter().all(|subgraph| subgraph.supported));
+    }
+}
@@ -0,0 +1,160 @@
+use cpp::cpp;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Information about a plugin creator in the plugin registry.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginCreatorInfo {
+    /// Name of plugin.
+    pub name: String,
+    /// Version of plugin.
+    pub version: String,
+    /// Namespace of plugin.
+    pub namespace: String,
+}
+
+/// Initialize and register all plugins that ship with TensorRT (such as `EfficientNMS_TRT`).
+///
+/// This must be called before deserializing engines or parsing networks that use these plugins.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/_nv_infer_plugin_8h.html)
+pub fn init_libnvinfer_plugins() -> Result<()> {
+    let success = cpp!(unsafe [] -> bool as "bool" {
+        #ifndef ODDITY_FFI_DISPATCH
+        return initLibNvInferPlugins(&GLOBAL_LOGGER, "");
+        #else
+        return false;
+        #endif
+    });
+    if success {
+        Ok(())
+    } else {
+        Err(crate::error::last_error())
+    }
+}
+
+/// Load plugins from a shared library and register them in the plugin registry.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+///
+/// # Arguments
+///
+/// * `path` - Path to plugin library.
+pub fn load_library(path: impl AsRef<std::path::Path>) -> Result<()> {
+    let path = path.as_ref();
+    let path_ffi = path
+        .to_str()
+        .and_then(|path| std::ffi::CString::new(path).ok())
+        .ok_or_else(|| {
+            crate::error::Error::InvalidArgument(format!("invalid path: {}", path.display()))
+        })?;
+    let path_ptr = path_ffi.as_ptr();
+    let status = cpp!(unsafe [
+        path_ptr as "const char*"
+    ] -> i32 as "std::int32_t" {
+        #if !defined(ODDITY_FFI_DISPATCH) && (NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6))
+        return getPluginRegistry()->loadLibrary(path_ptr) != nullptr ? 1 : 0;
+        #else
+        return -1;
+        #endif
+    });
+    match status {
+        1 => Ok(()),
+        0 => Err(crate::error::last_error()),
+        _ => Err(crate::error::Error::InvalidArgument(
+            "loading plugin libraries not supported by this version of TensorRT".to_string(),
+        )),
+    }
+}
+
+/// Get all plugin creators that are registered in the plugin registry.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+///
+/// # Return value
+///
+/// Name, version and namespace of each registered plugin creator.
+pub fn plugin_creators() -> Vec<PluginCreatorInfo> {
+    let mut creators: Vec<PluginCreatorInfo> = Vec::new();
+    let creators_ptr = &mut creators as *mut Vec<PluginCreatorInfo>;
+    cpp!(unsafe [
+        creators_ptr as "void*"
+    ] {
+        #ifndef ODDITY_FFI_DISPATCH
+        std::int32_t numCreators = 0;
+        #if NV_TENSORRT_MAJOR >= 10
+        IPluginCreatorInterface* const* creators = getPluginRegistry()->getAllCreators(&numCreators);
+        #else
+        IPluginCreator* const* creators = getPluginRegistry()->getPluginCreatorList(&numCreators);
+        #endif
+        if (creators == nullptr) {
+            return;
+        }
+        for (std::int32_t i = 0; i < numCreators; i++) {
+            const char* name = nullptr;
+            const char* version = nullptr;
+            const char* pluginNamespace = nullptr;
+            #if NV_TENSORRT_MAJOR >= 10
+            const char* kind = creators[i]->getInterfaceInfo().kind;
+            if (std::string(kind) == "PLUGIN CREATOR_V1") {
+                IPluginCreator* creator = static_cast<IPluginCreator*>(creators[i]);
+                name = creator->getPluginName();
+                version = creator->getPluginVersion();
+                pluginNamespace = creator->getPluginNamespace();
+            } else if (std::string(kind) == "PLUGIN CREATOR_V3ONE") {
+                IPluginCreatorV3One* creator = static_cast<IPluginCreatorV3One*>(creators[i]);
+                name = creator->getPluginName();
+                version = creator->getPluginVersion();
+                pluginNamespace = creator->getPluginNamespace();
+            } else {
+                continue;
+            }
+            #else
+            name = creators[i]->getPluginName();
+            version = creators[i]->getPluginVersion();
+            pluginNamespace = creators[i]->getPluginNamespace();
+            #endif
+            rust!(PluginRegistry_pushPluginCreatorInfo [
+                creators_ptr : *mut Vec<PluginCreatorInfo> as "void*",
+                name : *const std::os::raw::c_char as "const char*",
+                version : *const std::os::raw::c_char as "const char*",
+                pluginNamespace : *const std::os::raw::c_char as "const char*"
+            ] {
+                let to_string = |value: *const std::os::raw::c_char| {
+                    if !value.is_null() {
+                        std::ffi::CStr::from_ptr(value).to_string_lossy().to_string()
+                    } else {
+                        String::new()
+                    }
+                };
+                (*creators_ptr).push(PluginCreatorInfo {
+                    name: to_string(name),
+                    version: to_string(version),
+                    namespace: to_string(pluginNamespace),
+                });
+            });
+        }
+        #endif
+    });
+    creators
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_init_libnvinfer_plugins() {
+        init_libnvinfer_plugins().unwrap();
+        assert!(plugin_creators()
+            .iter()
+            .any(|creator| creator.name == "EfficientNMS_TRT"));
+    }
+
+    #[tokio::test]
+    async fn test_load_library_invalid_path() {
+        assert!(load_library("/does/not/exist/libplugin.so").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_ERROR_RECORDER
+    #define ODDITY_FFI_ERROR_RECORDER
+
+    #include <atomic>
+    #include <mutex>
+    #include <utility>
+    #include <vector>
+
+    // The error recorder is registered on every builder, runtime and refitter (engines inherit it
+    // from their runtime). Unlike the logger, which only keeps the last error message, it keeps all
+    // errors that were reported together with their error code, so that errors produced
+    // concurrently are not lost.
+    class ErrorRecorder : public IErrorRecorder
+    {
+    public:
+        std::int32_t getNbErrors() const noexcept override {
+            std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+            return static_cast<std::int32_t>(m_errors.size());
+        }
+
+        ErrorCode getErrorCode(std::int32_t errorIdx) const noexcept override {
+            std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+            if (errorIdx < 0 || errorIdx >= static_cast<std::int32_t>(m_errors.size())) {
+                return ErrorCode::kINVALID_ARGUMENT;
+            }
+            return m_errors[errorIdx].first;
+        }
+
+        ErrorDesc getErrorDesc(std::int32_t errorIdx) const noexcept override {
+            std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+            if (errorIdx < 0 || errorIdx >= static_cast<std::int32_t>(m_errors.size())) {
+                return "error index out of range";
+            }
+            return m_errors[errorIdx].second.c_str();
+        }
+
+        bool hasOverflowed() const noexcept override {
+            return m_overflowed;
+        }
+
+        void clear() noexcept override {
+            std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+            m_errors.clear();
+            m_overflowed = false;
+        }
+
+        bool reportError(ErrorCode val, ErrorDesc desc) noexcept override {
+            std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+            if (m_errors.size() < kMAX_ERRORS) {
+                m_errors.emplace_back(val, std::string(desc));
+            } else {
+                m_overflowed = true;
+            }
+            // Errors are never considered fatal by us, TensorRT decides how to continue.
+            return false;
+        }
+
+        RefCount incRefCount() noexcept override {
+            return ++m_refCount;
+        }
+
+        RefCount decRefCount() noexcept override {
+            return --m_refCount;
+        }
+
+        // Take all recorded errors and pass them to the Rust handler in the order in which they
+        // were reported. This clears the recorder.
+        void takeErrors(void* errors) noexcept {
+            std::vector<std::pair<ErrorCode, std::string>> taken;
+            {
+                std::lock_guard<std::mutex> _errorsGuard(m_errorsMutex);
+                std::swap(taken, m_errors);
+                m_overflowed = false;
+            }
+            for (const auto& error : taken) {
+                std::int32_t code = static_cast<std::int32_t>(error.first);
+                const char* desc = error.second.c_str();
+                rust!(ErrorRecorder_pushError [
+                    errors : *mut std::ffi::c_void as "void*",
+                    code : i32 as "std::int32_t",
+                    desc : *const std::os::raw::c_char as "const char*"
+                ] {
+                    push_error_raw(errors, code, desc);
+                });
+            }
+        }
+    private:
+        static constexpr std::size_t kMAX_ERRORS = 256;
+        mutable std::mutex m_errorsMutex {};
+        std::vector<std::pair<ErrorCode, std::string>> m_errors {};
+        std::atomic<bool> m_overflowed { false };
+        std::atomic<RefCount> m_refCount { 0 };
+    }
+    GLOBAL_ERROR_RECORDER;
+
+    #endif // ODDITY_FFI_ERROR_RECORDER
+}}
+
+/// TensorRT error code.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// Execution completed successfully.
+    Success,
+    /// An error that does not fall into any other category.
+    UnspecifiedError,
+    /// A non-recoverable TensorRT error occurred.
+    InternalError,
+    /// An argument passed to the function is invalid.
+    InvalidArgument,
+    /// An error occurred when comparing the state of an argument relative to other arguments.
+    InvalidConfig,
+    /// An error occurred when performing an allocation of memory.
+    FailedAllocation,
+    /// Initialization of a TensorRT component failed.
+    FailedInitialization,
+    /// An error occurred during execution that caused TensorRT to end prematurely.
+    FailedExecution,
+    /// An error occurred during execution that caused the data to become corrupted.
+    FailedComputation,
+    /// TensorRT was put into a bad state by incorrect sequence of function calls.
+    InvalidState,
+    /// An error occurred due to the network not being supported on the device.
+    UnsupportedState,
+}
+
+impl From<i32> for ErrorCode {
+    /// Convert from raw error code integer to [`ErrorCode`].
+    fn from(value: i32) -> Self {
+        match value {
+            0 => ErrorCode::Success,
+            2 => ErrorCode::InternalError,
+            3 => ErrorCode::InvalidArgument,
+            4 => ErrorCode::InvalidConfig,
+            5 => ErrorCode::FailedAllocation,
+            6 => ErrorCode::FailedInitialization,
+            7 => ErrorCode::FailedExecution,
+            8 => ErrorCode::FailedComputation,
+            9 => ErrorCode::InvalidState,
+            10 => ErrorCode::UnsupportedState,
+            _ => ErrorCode::UnspecifiedError,
+        }
+    }
+}
+
+/// Raw handler for pushing a recorded error into a [`Vec`].
+///
+/// # Arguments
+///
+/// * `errors` - Pointer to `Vec<(ErrorCode, String)>` to push into.
+/// * `code` - Integer error code.
+/// * `desc` - Raw C string error description.
+///
+/// # Safety
+///
+/// The caller must ensure that `errors` is a valid pointer to a `Vec<(ErrorCode, String)>` and
+/// that `desc` is a valid pointer to a C string.
+unsafe fn push_error_raw(
+    errors: *mut std::ffi::c_void,
+    code: i32,
+    desc: *const std::os::raw::c_char,
+) {
+    let errors = &mut *(errors as *mut Vec<(ErrorCode, String)>);
+    let desc = std::ffi::CStr::from_ptr(desc).to_string_lossy().to_string();
+    errors.push((code.into(), desc));
+}
@@ -9,12 +9,30 @@ cpp! {{
     #include <cuda_runtime.h>
 }}
 
+cpp! {{
+    #ifdef ODDITY_FFI_NVTX
+    #include <nvtx3/nvToolsExt.h>
+    #endif
+}}
+
+cpp! {{
+    #ifdef __linux__
+    #include <pthread.h>
+    #include <sched.h>
+    #endif
+}}
+
 cpp! {{
     #include <NvInfer.h>
+    #include <NvInferPlugin.h>
+    #ifdef ODDITY_FFI_ONNX
     #include <NvOnnxParser.h>
+    #endif
 }}
 
 cpp! {{
     using namespace nvinfer1;
+    #ifdef ODDITY_FFI_ONNX
     using namespace nvonnxparser;
+    #endif
 }}
@@ -75,6 +75,21 @@ impl From<i32> for Severity {
     }
 }
 
+impl Severity {
+    /// Convert [`Severity`] to its raw log level integer.
+    ///
+    /// [`Severity::Unknown`] is treated as the least severe level.
+    pub(crate) fn as_i32(self) -> i32 {
+        match self {
+            Severity::InternalError => 0,
+            Severity::Error => 1,
+            Severity::Warning => 2,
+            Severity::Info => 3,
+            Severity::Verbose | Severity::Unknown => 4,
+        }
+    }
+}
+
 /// Raw handler for log messages.
 ///
 /// This function redirects logging to `tracing`, with the following rules:
@@ -95,6 +110,7 @@ unsafe fn handle_log_message_raw(severity: i32, msg: *const std::os::raw::c_char
     let msg_c_str: &std::ffi::CStr = std::ffi::CStr::from_ptr(msg);
     let msg = msg_c_str.to_str().unwrap_or("");
     if !msg.is_empty() {
+        capture_log_message(severity.into(), msg);
         match severity.into() {
             Severity::InternalError | Severity::Error => {
                 tracing::error!(target: "tensorrt", "{msg}");
@@ -109,3 +125,58 @@ unsafe fn handle_log_message_raw(severity: i32, msg: *const std::os::raw::c_char
         }
     }
 }
+
+/// Maximum number of messages kept by [`capture_log()`].
+pub(crate) const MAX_CAPTURED_LOG_MESSAGES: usize = 32;
+
+std::thread_local! {
+    /// Warnings and errors logged on this thread while a capture is active, oldest first.
+    static CAPTURED_LOG: std::cell::RefCell<Option<std::collections::VecDeque<String>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Run `f` and capture the warnings and errors that TensorRT logs on the current thread meanwhile.
+///
+/// Only the last [`MAX_CAPTURED_LOG_MESSAGES`] messages are kept. Captures do not nest: a capture
+/// inside another one takes over until it ends, after which messages are no longer captured.
+///
+/// # Arguments
+///
+/// * `f` - Function to run.
+///
+/// # Return value
+///
+/// Output of `f` and captured messages, oldest first.
+pub(crate) fn capture_log<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    CAPTURED_LOG.with(|captured_log| {
+        *captured_log.borrow_mut() = Some(std::collections::VecDeque::new());
+    });
+    let output = f();
+    let messages = CAPTURED_LOG
+        .with(|captured_log| captured_log.borrow_mut().take())
+        .unwrap_or_default();
+    (output, messages.into())
+}
+
+/// Add log message to the capture of the current thread, if any.
+///
+/// # Arguments
+///
+/// * `severity` - Severity of log message.
+/// * `msg` - Log message.
+fn capture_log_message(severity: Severity, msg: &str) {
+    let prefix = match severity {
+        Severity::InternalError => "internal error",
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        _ => return,
+    };
+    CAPTURED_LOG.with(|captured_log| {
+        if let Some(messages) = captured_log.borrow_mut().as_mut() {
+            if messages.len() == MAX_CAPTURED_LOG_MESSAGES {
+                messages.pop_front();
+            }
+            messages.push_back(format!("{prefix}: {msg}"));
+        }
+    });
+}
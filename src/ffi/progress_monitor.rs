@@ -0,0 +1,262 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_PROGRESS_MONITOR
+    #define ODDITY_FFI_PROGRESS_MONITOR
+
+    #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+    // Progress monitor that forwards all calls to the Rust progress monitor state. The state is
+    // owned by the Rust side, this class only holds a pointer to it.
+    class RustProgressMonitor : public IProgressMonitor
+    {
+    public:
+        RustProgressMonitor(void* state) : m_state(state) {}
+
+        void phaseStart(
+            const char* phaseName,
+            const char* parentPhase,
+            std::int32_t nbSteps
+        ) noexcept override {
+            void* state = m_state;
+            rust!(RustProgressMonitor_phaseStart [
+                state : *const std::ffi::c_void as "void*",
+                phaseName : *const std::os::raw::c_char as "const char*",
+                parentPhase : *const std::os::raw::c_char as "const char*",
+                nbSteps : i32 as "std::int32_t"
+            ] {
+                phase_start_raw(state, phaseName, parentPhase, nbSteps);
+            });
+        }
+
+        bool stepComplete(const char* phaseName, std::int32_t step) noexcept override {
+            void* state = m_state;
+            return rust!(RustProgressMonitor_stepComplete [
+                state : *const std::ffi::c_void as "void*",
+                phaseName : *const std::os::raw::c_char as "const char*",
+                step : i32 as "std::int32_t"
+            ] -> bool as "bool" {
+                step_complete_raw(state, phaseName, step)
+            });
+        }
+
+        void phaseFinish(const char* phaseName) noexcept override {
+            void* state = m_state;
+            rust!(RustProgressMonitor_phaseFinish [
+                state : *const std::ffi::c_void as "void*",
+                phaseName : *const std::os::raw::c_char as "const char*"
+            ] {
+                phase_finish_raw(state, phaseName);
+            });
+        }
+
+    private:
+        void* m_state;
+    };
+    #endif
+
+    #endif // ODDITY_FFI_PROGRESS_MONITOR
+}}
+
+/// Monitor for the progress of an engine build.
+///
+/// The build is divided into phases, which may be nested and consist of a number of steps.
+/// Install a progress monitor with [`crate::BuilderConfig::set_progress_monitor()`]. Progress
+/// monitoring requires TensorRT 10 or up. On older versions the monitor is never called.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_progress_monitor.html)
+pub trait ProgressMonitor: Send {
+    /// Called when a phase of the build starts.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase_name` - Name of phase.
+    /// * `parent_phase` - Name of parent phase, if this phase is nested.
+    /// * `num_steps` - Number of steps in the phase.
+    fn phase_start(&mut self, phase_name: &str, parent_phase: Option<&str>, num_steps: usize);
+
+    /// Called when a step of a phase completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase_name` - Name of phase.
+    /// * `step` - Index of step that completed.
+    ///
+    /// # Return value
+    ///
+    /// `false` to cancel the build, `true` to continue.
+    fn step_complete(&mut self, phase_name: &str, step: usize) -> bool;
+
+    /// Called when a phase of the build finishes.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase_name` - Name of phase.
+    fn phase_finish(&mut self, phase_name: &str);
+}
+
+/// State shared between a [`ProgressMonitorBridge`] and the C++ progress monitor.
+#[derive(Default)]
+struct ProgressMonitorState {
+    cancelled: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    monitor: std::sync::Mutex<Option<Box<dyn ProgressMonitor>>>,
+}
+
+/// Bridge between Rust progress monitoring and a TensorRT `IProgressMonitor`.
+///
+/// Owns both the Rust state and the C++ object that forwards to it. The C++ object is only
+/// available on TensorRT 10 and up, on older versions the internal pointer is null.
+pub(crate) struct ProgressMonitorBridge {
+    internal: *mut std::ffi::c_void,
+    state: Box<ProgressMonitorState>,
+}
+
+/// Implements [`Send`] for [`ProgressMonitorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust state, which is [`Send`] itself.
+unsafe impl Send for ProgressMonitorBridge {}
+
+/// Implements [`Sync`] for [`ProgressMonitorBridge`].
+///
+/// # Safety
+///
+/// The bridge only forwards to the Rust state, which is [`Sync`] itself.
+unsafe impl Sync for ProgressMonitorBridge {}
+
+impl ProgressMonitorBridge {
+    /// Create a new bridge without progress monitor or cancellation flag.
+    pub(crate) fn new() -> Self {
+        let state = Box::<ProgressMonitorState>::default();
+        let state_ptr = state.as_ref() as *const ProgressMonitorState as *const std::ffi::c_void;
+        let internal = cpp!(unsafe [
+            state_ptr as "const void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+            return (void*) new RustProgressMonitor((void*) state_ptr);
+            #else
+            return nullptr;
+            #endif
+        });
+        ProgressMonitorBridge { internal, state }
+    }
+
+    /// Set flag that cancels the build when it is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancelled` - Flag that signals the build must be cancelled.
+    pub(crate) fn set_cancellation_flag(
+        &mut self,
+        cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    ) {
+        self.state.cancelled = Some(cancelled);
+    }
+
+    /// Set progress monitor to forward to.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor` - Progress monitor.
+    pub(crate) fn set_monitor(&mut self, monitor: Box<dyn ProgressMonitor>) {
+        *self.state.monitor.get_mut().unwrap() = Some(monitor);
+    }
+
+    /// Get internal pointer to `IProgressMonitor`.
+    #[inline(always)]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let ProgressMonitorBridge { internal, .. } = *self;
+        internal
+    }
+}
+
+impl Drop for ProgressMonitorBridge {
+    fn drop(&mut self) {
+        let ProgressMonitorBridge { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            #if NV_TENSORRT_MAJOR >= 10 && !defined(ODDITY_FFI_DISPATCH)
+            delete ((RustProgressMonitor*) internal);
+            #endif
+        });
+    }
+}
+
+/// Raw handler for `IProgressMonitor::phaseStart`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live [`ProgressMonitorState`], that
+/// `phase_name` is a valid pointer to a C string and that `parent_phase` is either null or a valid
+/// pointer to a C string.
+unsafe fn phase_start_raw(
+    state: *const std::ffi::c_void,
+    phase_name: *const std::os::raw::c_char,
+    parent_phase: *const std::os::raw::c_char,
+    num_steps: i32,
+) {
+    let state = &*(state as *const ProgressMonitorState);
+    if let Some(monitor) = state.monitor.lock().unwrap().as_mut() {
+        let phase_name = std::ffi::CStr::from_ptr(phase_name).to_string_lossy();
+        let parent_phase = if !parent_phase.is_null() {
+            Some(std::ffi::CStr::from_ptr(parent_phase).to_string_lossy())
+        } else {
+            None
+        };
+        monitor.phase_start(
+            &phase_name,
+            parent_phase.as_deref(),
+            num_steps.max(0) as usize,
+        );
+    }
+}
+
+/// Raw handler for `IProgressMonitor::stepComplete`.
+///
+/// # Return value
+///
+/// `false` if the build must be cancelled.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live [`ProgressMonitorState`] and that
+/// `phase_name` is a valid pointer to a C string.
+unsafe fn step_complete_raw(
+    state: *const std::ffi::c_void,
+    phase_name: *const std::os::raw::c_char,
+    step: i32,
+) -> bool {
+    let state = &*(state as *const ProgressMonitorState);
+    let cancelled = state
+        .cancelled
+        .as_ref()
+        .is_some_and(|cancelled| cancelled.load(std::sync::atomic::Ordering::SeqCst));
+    if cancelled {
+        return false;
+    }
+    match state.monitor.lock().unwrap().as_mut() {
+        Some(monitor) => {
+            let phase_name = std::ffi::CStr::from_ptr(phase_name).to_string_lossy();
+            monitor.step_complete(&phase_name, step.max(0) as usize)
+        }
+        None => true,
+    }
+}
+
+/// Raw handler for `IProgressMonitor::phaseFinish`.
+///
+/// # Safety
+///
+/// The caller must ensure that `state` points to a live [`ProgressMonitorState`] and that
+/// `phase_name` is a valid pointer to a C string.
+unsafe fn phase_finish_raw(
+    state: *const std::ffi::c_void,
+    phase_name: *const std::os::raw::c_char,
+) {
+    let state = &*(state as *const ProgressMonitorState);
+    if let Some(monitor) = state.monitor.lock().unwrap().as_mut() {
+        let phase_name = std::ffi::CStr::from_ptr(phase_name).to_string_lossy();
+        monitor.phase_finish(&phase_name);
+    }
+}
@@ -0,0 +1,174 @@
+use cpp::cpp;
+
+cpp! {{
+    #ifndef ODDITY_FFI_STREAM_READER
+    #define ODDITY_FFI_STREAM_READER
+
+    #if NV_TENSORRT_MAJOR >= 10
+    #include <vector>
+
+    // Stream reader that forwards reads and seeks to a Rust reader. The Rust reader is owned by the
+    // Rust side, this class only holds a pointer to it. On TensorRT 10.7 and up this implements
+    // `IStreamReaderV2`, which may ask to read directly into device memory.
+    #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 7)
+    class RustStreamReader : public IStreamReaderV2
+    #else
+    class RustStreamReader : public IStreamReader
+    #endif
+    {
+    public:
+        RustStreamReader(void* reader) : m_reader(reader) {}
+
+        #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 7)
+        std::int64_t read(
+            void* destination,
+            std::int64_t nbBytes,
+            cudaStream_t stream
+        ) noexcept override {
+            cudaPointerAttributes attributes;
+            bool isDevice =
+                cudaPointerGetAttributes(&attributes, destination) == cudaSuccess
+                && attributes.type == cudaMemoryTypeDevice;
+            // Clear error that may have been produced for host pointers.
+            cudaGetLastError();
+            if (!isDevice) {
+                return readHost(destination, nbBytes);
+            }
+            std::vector<std::uint8_t> staging(nbBytes);
+            std::int64_t nbRead = readHost(staging.data(), nbBytes);
+            if (nbRead > 0) {
+                if (cudaMemcpyAsync(
+                        destination,
+                        staging.data(),
+                        nbRead,
+                        cudaMemcpyHostToDevice,
+                        stream
+                    ) != cudaSuccess) {
+                    return -1;
+                }
+                // The staging buffer must stay alive until the copy is done.
+                if (cudaStreamSynchronize(stream) != cudaSuccess) {
+                    return -1;
+                }
+            }
+            return nbRead;
+        }
+
+        bool seek(std::int64_t offset, SeekPosition where) noexcept override {
+            void* reader = m_reader;
+            std::int32_t whence = static_cast<std::int32_t>(where);
+            return rust!(RustStreamReader_seek [
+                reader : *mut std::ffi::c_void as "void*",
+                offset : i64 as "std::int64_t",
+                whence : i32 as "std::int32_t"
+            ] -> bool as "bool" {
+                seek_raw(reader, offset, whence)
+            });
+        }
+        #else
+        std::int64_t read(void* destination, std::int64_t nbBytes) noexcept override {
+            return readHost(destination, nbBytes);
+        }
+        #endif
+
+    private:
+        std::int64_t readHost(void* destination, std::int64_t nbBytes) noexcept {
+            void* reader = m_reader;
+            return rust!(RustStreamReader_read [
+                reader : *mut std::ffi::c_void as "void*",
+                destination : *mut u8 as "void*",
+                nbBytes : i64 as "std::int64_t"
+            ] -> i64 as "std::int64_t" {
+                read_raw(reader, destination, nbBytes)
+            });
+        }
+
+        void* m_reader;
+    };
+    #endif
+
+    #endif // ODDITY_FFI_STREAM_READER
+}}
+
+/// Reader that can also seek, used as source to stream plans from.
+pub(crate) trait ReadSeek: std::io::Read + std::io::Seek {}
+
+impl<T: std::io::Read + std::io::Seek> ReadSeek for T {}
+
+/// Run a function with a TensorRT stream reader that reads from `reader`.
+///
+/// The stream reader is only valid for the duration of the function. Streaming requires TensorRT
+/// 10 or up. On older versions the function receives a null pointer.
+///
+/// # Arguments
+///
+/// * `reader` - Reader to read from.
+/// * `f` - Function that receives pointer to `IStreamReader` (or `IStreamReaderV2` on TensorRT
+///   10.7 and up).
+pub(crate) fn with_stream_reader<T>(
+    mut reader: &mut dyn ReadSeek,
+    f: impl FnOnce(*mut std::ffi::c_void) -> T,
+) -> T {
+    let reader_ptr = &mut reader as *mut &mut dyn ReadSeek as *mut std::ffi::c_void;
+    let internal = cpp!(unsafe [
+        reader_ptr as "void*"
+    ] -> *mut std::ffi::c_void as "void*" {
+        #if NV_TENSORRT_MAJOR >= 10
+        return (void*) new RustStreamReader(reader_ptr);
+        #else
+        return nullptr;
+        #endif
+    });
+    let ret = f(internal);
+    cpp!(unsafe [
+        internal as "void*"
+    ] {
+        #if NV_TENSORRT_MAJOR >= 10
+        delete ((RustStreamReader*) internal);
+        #endif
+    });
+    ret
+}
+
+/// Raw handler for `IStreamReader::read`.
+///
+/// Reads until `size` bytes were read or the end of the stream is reached.
+///
+/// # Return value
+///
+/// Number of bytes read, or `-1` on error.
+///
+/// # Safety
+///
+/// The caller must ensure that `reader` points to a live `&mut dyn ReadSeek` and that
+/// `destination` points to at least `size` writable bytes.
+unsafe fn read_raw(reader: *mut std::ffi::c_void, destination: *mut u8, size: i64) -> i64 {
+    let reader = &mut *(reader as *mut &mut dyn ReadSeek);
+    let buffer = std::slice::from_raw_parts_mut(destination, size.max(0) as usize);
+    let mut num_read = 0;
+    while num_read < buffer.len() {
+        match reader.read(&mut buffer[num_read..]) {
+            Ok(0) => break,
+            Ok(n) => num_read += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(_) => return -1,
+        }
+    }
+    num_read as i64
+}
+
+/// Raw handler for `IStreamReaderV2::seek`.
+///
+/// # Safety
+///
+/// The caller must ensure that `reader` points to a live `&mut dyn ReadSeek`.
+unsafe fn seek_raw(reader: *mut std::ffi::c_void, offset: i64, whence: i32) -> bool {
+    let reader = &mut *(reader as *mut &mut dyn ReadSeek);
+    let position = match whence {
+        0 => std::io::SeekFrom::Start(offset.max(0) as u64),
+        1 => std::io::SeekFrom::Current(offset),
+        2 => std::io::SeekFrom::End(offset),
+        _ => return false,
+    };
+    reader.seek(position).is_ok()
+}
@@ -4,9 +4,11 @@ use async_cuda::device::DeviceId;
 use async_cuda::ffi::device::Device;
 
 use crate::ffi::builder_config::BuilderConfig;
+use crate::ffi::gpu_allocator::{GpuAllocator, GpuAllocatorBridge};
 use crate::ffi::memory::HostBuffer;
 use crate::ffi::network::{NetworkDefinition, NetworkDefinitionCreationFlags};
 use crate::ffi::optimization_profile::OptimizationProfile;
+use crate::ffi::pre::logger::capture_log;
 use crate::ffi::result;
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
@@ -17,6 +19,7 @@ type Result<T> = std::result::Result<T, crate::error::Error>;
 pub struct Builder {
     addr: *mut std::ffi::c_void,
     device: DeviceId,
+    gpu_allocator: Option<GpuAllocatorBridge>,
 }
 
 /// Implements [`Send`] for [`Builder`].
@@ -34,14 +37,35 @@ unsafe impl Send for Builder {}
 unsafe impl Sync for Builder {}
 
 impl Builder {
+    /// Create a new [`Builder`].
+    ///
+    /// Synchronous version of [`crate::Builder::new()`].
     pub fn new() -> Result<Self> {
         let device = Device::get_or_panic();
         let addr = cpp!(unsafe [] -> *mut std::ffi::c_void as "void*" {
-            return createInferBuilder(GLOBAL_LOGGER);
+            #ifndef ODDITY_FFI_DISPATCH
+            IBuilder* builder = createInferBuilder(GLOBAL_LOGGER);
+            if (builder != nullptr) {
+                builder->setErrorRecorder(&GLOBAL_ERROR_RECORDER);
+            }
+            return builder;
+            #else
+            return nullptr;
+            #endif
         });
-        result!(addr, Builder { addr, device })
+        result!(
+            addr,
+            Builder {
+                addr,
+                device,
+                gpu_allocator: None,
+            }
+        )
     }
 
+    /// Create a builder configuration object.
+    ///
+    /// Synchronous version of [`crate::Builder::config()`].
     pub fn config(&mut self) -> BuilderConfig {
         let internal = self.as_mut_ptr();
         let internal = cpp!(unsafe [
@@ -52,6 +76,9 @@ impl Builder {
         BuilderConfig::wrap(internal)
     }
 
+    /// Create a new optimization profile.
+    ///
+    /// Synchronous version of [`crate::Builder::optimization_profile()`].
     pub fn optimization_profile(&mut self) -> Result<OptimizationProfile> {
         let internal = self.as_mut_ptr();
         let optimization_profile_internal = cpp!(unsafe [
@@ -65,59 +92,124 @@ impl Builder {
         )
     }
 
+    /// Add an empty optimization profile to the builder.
+    ///
+    /// Synchronous version of [`crate::Builder::add_default_optimization_profile()`].
     pub fn add_default_optimization_profile(&mut self) -> Result<()> {
         self.optimization_profile()?;
         Ok(())
     }
 
+    /// Add an empty optimization profile to the builder and return it.
+    ///
+    /// Synchronous version of [`crate::Builder::with_default_optimization_profile()`].
     pub fn with_default_optimization_profile(mut self) -> Result<Self> {
         self.optimization_profile()?;
         Ok(self)
     }
 
+    /// Build a network and serialize it into a plan.
+    ///
+    /// Synchronous version of [`crate::Builder::build_serialized_network()`].
     pub fn build_serialized_network(
         &mut self,
         network_definition: &mut NetworkDefinition,
-        config: BuilderConfig,
+        mut config: BuilderConfig,
     ) -> Result<HostBuffer> {
+        config.prepare_int8_calibrator(network_definition);
         let internal = self.as_mut_ptr();
         let internal_network_definition = network_definition.as_ptr();
         let internal_builder_config = config.as_ptr();
-        let plan_internal = cpp!(unsafe [
-            internal as "void*",
-            internal_network_definition as "void*",
-            internal_builder_config as "void*"
-        ] -> *mut std::ffi::c_void as "void*" {
-            return ((IBuilder*) internal)->buildSerializedNetwork(
-                *((INetworkDefinition*) internal_network_definition),
-                *((IBuilderConfig*) internal_builder_config)
-            );
+        let (plan_internal, log) = capture_log(|| {
+            cpp!(unsafe [
+                internal as "void*",
+                internal_network_definition as "void*",
+                internal_builder_config as "void*"
+            ] -> *mut std::ffi::c_void as "void*" {
+                return ((IBuilder*) internal)->buildSerializedNetwork(
+                    *((INetworkDefinition*) internal_network_definition),
+                    *((IBuilderConfig*) internal_builder_config)
+                );
+            })
         });
-        result!(plan_internal, HostBuffer::wrap(plan_internal))
+        result!(plan_internal, HostBuffer::wrap(plan_internal)).map_err(|error| {
+            crate::error::Error::BuildFailed {
+                error: Box::new(error),
+                log,
+            }
+        })
     }
 
+    /// Check whether a network can be built with a builder configuration.
+    ///
+    /// Synchronous version of [`crate::Builder::is_network_supported()`].
+    pub fn is_network_supported(
+        &self,
+        network_definition: &NetworkDefinition,
+        config: &BuilderConfig,
+    ) -> bool {
+        let internal = self.as_ptr();
+        let internal_network_definition = network_definition.as_ptr();
+        let internal_builder_config = config.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            internal_network_definition as "const void*",
+            internal_builder_config as "const void*"
+        ] -> bool as "bool" {
+            #pragma GCC diagnostic push
+            #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+            bool supported = ((const IBuilder*) internal)->isNetworkSupported(
+                *((const INetworkDefinition*) internal_network_definition),
+                *((const IBuilderConfig*) internal_builder_config)
+            );
+            #pragma GCC diagnostic pop
+            return supported;
+        })
+    }
+
+    /// Create a network definition object.
+    ///
+    /// Synchronous version of [`crate::Builder::network_definition()`].
     pub fn network_definition(
         &mut self,
         flags: NetworkDefinitionCreationFlags,
     ) -> NetworkDefinition {
         let internal = self.as_mut_ptr();
-        let set_explicit_batch_size = match flags {
-            NetworkDefinitionCreationFlags::None => false,
-            NetworkDefinitionCreationFlags::ExplicitBatchSize => true,
-        };
+        let flags = flags.bits();
         let internal = cpp!(unsafe [
             internal as "void*",
-            set_explicit_batch_size as "bool"
+            flags as "std::uint32_t"
         ] -> *mut std::ffi::c_void as "void*" {
-            std::uint32_t flags = 0;
-            if (set_explicit_batch_size) {
-                flags |= (1U << static_cast<uint32_t>(NetworkDefinitionCreationFlag::kEXPLICIT_BATCH));
-            }
-            return ((IBuilder*) internal)->createNetworkV2(flags);
+            std::uint32_t createFlags = flags;
+            #if NV_TENSORRT_MAJOR < 10
+            // Strongly typed networks are not supported, and the bit has a different meaning in
+            // older versions.
+            createFlags &= (1U << static_cast<uint32_t>(NetworkDefinitionCreationFlag::kEXPLICIT_BATCH));
+            #endif
+            return ((IBuilder*) internal)->createNetworkV2(createFlags);
         });
         NetworkDefinition::wrap(internal)
     }
 
+    /// Set the allocator for all GPU memory allocated by the builder.
+    ///
+    /// Synchronous version of [`crate::Builder::set_gpu_allocator()`].
+    pub fn set_gpu_allocator(&mut self, allocator: Box<dyn GpuAllocator>) {
+        let internal = self.as_mut_ptr();
+        let mut bridge = GpuAllocatorBridge::new(allocator);
+        let bridge_ptr = bridge.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            bridge_ptr as "void*"
+        ] {
+            ((IBuilder*) internal)->setGpuAllocator((IGpuAllocator*) bridge_ptr);
+        });
+        self.gpu_allocator = Some(bridge);
+    }
+
+    /// Determine whether the platform has fast native INT8.
+    ///
+    /// Synchronous version of [`crate::Builder::platform_has_fast_int8()`].
     pub fn platform_has_fast_int8(&self) -> bool {
         let internal = self.as_ptr();
         cpp!(unsafe [
@@ -127,6 +219,9 @@ impl Builder {
         })
     }
 
+    /// Determine whether the platform has fast native FP16.
+    ///
+    /// Synchronous version of [`crate::Builder::platform_has_fast_fp16()`].
     pub fn platform_has_fast_fp16(&self) -> bool {
         let internal = self.as_ptr();
         cpp!(unsafe [
@@ -136,11 +231,74 @@ impl Builder {
         })
     }
 
+    /// Determine whether the platform has TF32 support.
+    ///
+    /// Synchronous version of [`crate::Builder::platform_has_tf32()`].
+    pub fn platform_has_tf32(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            #pragma GCC diagnostic push
+            #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+            bool hasTf32 = ((const IBuilder*) internal)->platformHasTf32();
+            #pragma GCC diagnostic pop
+            return hasTf32;
+        })
+    }
+
+    /// Set the maximum number of threads that the builder may use.
+    ///
+    /// Synchronous version of [`crate::Builder::set_max_threads()`].
+    pub fn set_max_threads(&mut self, max_threads: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let max_threads = max_threads.min(i32::MAX as usize) as i32;
+        let ok = cpp!(unsafe [
+            internal as "void*",
+            max_threads as "std::int32_t"
+        ] -> bool as "bool" {
+            return ((IBuilder*) internal)->setMaxThreads(max_threads);
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Get the maximum number of threads that the builder may use.
+    ///
+    /// Synchronous version of [`crate::Builder::max_threads()`].
+    pub fn max_threads(&self) -> usize {
+        let internal = self.as_ptr();
+        let max_threads = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IBuilder*) internal)->getMaxThreads();
+        });
+        max_threads.max(0) as usize
+    }
+
+    /// Get the number of DLA cores available to the builder.
+    ///
+    /// Synchronous version of [`crate::Builder::num_dla_cores()`].
+    pub fn num_dla_cores(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_dla_cores = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IBuilder*) internal)->getNbDLACores();
+        });
+        num_dla_cores.max(0) as usize
+    }
+
+    /// Get internal readonly pointer.
     #[inline(always)]
     pub fn as_ptr(&self) -> *const std::ffi::c_void {
         self.addr
     }
 
+    /// Get internal mutable pointer.
     #[inline(always)]
     pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
         self.addr
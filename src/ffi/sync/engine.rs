@@ -4,12 +4,22 @@ use async_cuda::device::DeviceId;
 use async_cuda::ffi::device::Device;
 
 use crate::error::last_error;
+use crate::ffi::data_type::DataType;
+use crate::ffi::debug_listener::{DebugListener, DebugListenerBridge};
+use crate::ffi::dims::Dims;
+use crate::ffi::engine_capability::EngineCapability;
 use crate::ffi::memory::HostBuffer;
+use crate::ffi::output_allocator::{OutputAllocator, OutputAllocatorBridge};
 use crate::ffi::result;
+use crate::ffi::sync::event::Event;
 use crate::ffi::sync::runtime::Runtime;
+use crate::io_bindings::IoBindings;
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
+/// Alignment of IO tensor addresses that is sufficient for all data types.
+const TENSOR_ADDRESS_ALIGNMENT: usize = 256;
+
 /// Synchronous implementation of [`crate::Engine`].
 ///
 /// Refer to [`crate::Engine`] for documentation.
@@ -38,6 +48,9 @@ impl Engine {
         Engine { internal, runtime }
     }
 
+    /// Serialize the engine to a host buffer.
+    ///
+    /// Synchronous version of [`crate::Engine::serialize()`].
     pub fn serialize(&self) -> Result<HostBuffer> {
         let internal = self.as_ptr();
         let internal_buffer = cpp!(unsafe [
@@ -48,6 +61,94 @@ impl Engine {
         result!(internal_buffer, HostBuffer::wrap(internal_buffer))
     }
 
+    /// Serialize the engine to a host buffer with a serialization configuration.
+    ///
+    /// Synchronous version of [`crate::Engine::serialize_with_config()`].
+    pub fn serialize_with_config(&self, config: &SerializationConfig) -> Result<HostBuffer> {
+        let internal = self.as_ptr();
+        let flags = config.flags();
+        let mut supported = true;
+        let supported_ptr = std::ptr::addr_of_mut!(supported);
+        let internal_buffer = cpp!(unsafe [
+            internal as "const void*",
+            flags as "std::uint32_t",
+            supported_ptr as "bool*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if NV_TENSORRT_MAJOR >= 10
+            ISerializationConfig* config =
+                ((const ICudaEngine*) internal)->createSerializationConfig();
+            if (config == nullptr) {
+                return nullptr;
+            }
+            void* buffer = nullptr;
+            if (config->setFlags(flags)) {
+                buffer = (void*) ((const ICudaEngine*) internal)->serializeWithConfig(*config);
+            }
+            delete config;
+            return buffer;
+            #else
+            *supported_ptr = false;
+            return nullptr;
+            #endif
+        });
+        if !supported {
+            return Err(crate::error::Error::InvalidArgument(
+                "serialization config not supported by this version of TensorRT".to_string(),
+            ));
+        }
+        result!(internal_buffer, HostBuffer::wrap(internal_buffer))
+    }
+
+    /// Serialize the engine to a writer.
+    ///
+    /// Synchronous version of [`crate::Engine::serialize_to_writer()`].
+    pub fn serialize_to_writer(&self, mut writer: impl std::io::Write) -> Result<()> {
+        let plan = self.serialize()?;
+        writer.write_all(plan.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Serialize the engine to a file.
+    ///
+    /// Synchronous version of [`crate::Engine::serialize_to_file()`].
+    pub fn serialize_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+        self.serialize_to_writer(std::io::BufWriter::new(file))
+    }
+
+    /// Get the name of the engine.
+    ///
+    /// Synchronous version of [`crate::Engine::name()`].
+    pub fn name(&self) -> String {
+        let internal = self.as_ptr();
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const ICudaEngine*) internal)->getName();
+        });
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+    }
+
+    /// Get the engine capability that the engine was built with.
+    ///
+    /// Synchronous version of [`crate::Engine::engine_capability()`].
+    pub fn engine_capability(&self) -> EngineCapability {
+        let internal = self.as_ptr();
+        let engine_capability = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ICudaEngine*) internal)->getEngineCapability();
+        });
+        EngineCapability::from_i32(engine_capability)
+    }
+
+    /// Get the number of IO tensors.
+    ///
+    /// Synchronous version of [`crate::Engine::num_io_tensors()`].
     pub fn num_io_tensors(&self) -> usize {
         let internal = self.as_ptr();
         let num_io_tensors = cpp!(unsafe [
@@ -58,6 +159,9 @@ impl Engine {
         num_io_tensors as usize
     }
 
+    /// Retrieve the name of an IO tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::io_tensor_name()`].
     pub fn io_tensor_name(&self, io_tensor_index: usize) -> String {
         let internal = self.as_ptr();
         let io_tensor_index = io_tensor_index as std::os::raw::c_int;
@@ -78,14 +182,17 @@ impl Engine {
         }
     }
 
-    pub fn tensor_shape(&self, tensor_name: &str) -> Vec<usize> {
+    /// Get the shape of a tensor, where dynamic dimensions are `-1`.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_shape()`].
+    pub fn tensor_shape(&self, tensor_name: &str) -> Dims {
         let internal = self.as_ptr();
         let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
         let tensor_name_ptr = tensor_name_cstr.as_ptr();
         let tensor_dimensions = cpp!(unsafe [
             internal as "const void*",
             tensor_name_ptr as "const char*"
-        ] -> Dims as "Dims64" {
+        ] -> RawDims as "Dims64" {
             #if NV_TENSORRT_MAJOR >= 10
             return ((const ICudaEngine*) internal)->getTensorShape(tensor_name_ptr);
             #else
@@ -99,228 +206,2102 @@ impl Engine {
             #endif
         });
 
-        let mut dimensions = Vec::with_capacity(tensor_dimensions.nbDims as usize);
-        for i in 0..tensor_dimensions.nbDims {
-            dimensions.push(tensor_dimensions.d[i as usize] as usize);
-        }
-
-        dimensions
+        let num_dims = tensor_dimensions.nbDims.max(0) as usize;
+        Dims::from(&tensor_dimensions.d[..num_dims])
     }
 
-    pub fn tensor_io_mode(&self, tensor_name: &str) -> TensorIoMode {
-        let internal = self.as_ptr();
-        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
-        let tensor_name_ptr = tensor_name_cstr.as_ptr();
-        let tensor_io_mode = cpp!(unsafe [
-            internal as "const void*",
-            tensor_name_ptr as "const char*"
-        ] -> i32 as "std::int32_t" {
-            return (std::int32_t) ((const ICudaEngine*) internal)->getTensorIOMode(tensor_name_ptr);
-        });
-        TensorIoMode::from_i32(tensor_io_mode)
+    /// Get the min shape of an input tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_min_shape()`].
+    pub fn profile_min_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.profile_shape_for_selector(tensor_name, profile_index, 0)
     }
 
-    #[inline(always)]
-    pub fn as_ptr(&self) -> *const std::ffi::c_void {
-        let Engine { internal, .. } = *self;
-        internal
+    /// Get the opt shape of an input tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_opt_shape()`].
+    pub fn profile_opt_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.profile_shape_for_selector(tensor_name, profile_index, 1)
     }
 
-    #[inline(always)]
-    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
-        let Engine { internal, .. } = *self;
-        internal
+    /// Get the max shape of an input tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_max_shape()`].
+    pub fn profile_max_shape(&self, tensor_name: &str, profile_index: usize) -> Option<Dims> {
+        self.profile_shape_for_selector(tensor_name, profile_index, 2)
     }
 
-    #[inline(always)]
-    pub fn device(&self) -> DeviceId {
-        self.runtime.device()
+    /// Get the min, opt and max shape of an input tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_shape()`].
+    pub fn profile_shape(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<(Dims, Dims, Dims)> {
+        Some((
+            self.profile_min_shape(tensor_name, profile_index)?,
+            self.profile_opt_shape(tensor_name, profile_index)?,
+            self.profile_max_shape(tensor_name, profile_index)?,
+        ))
     }
-}
 
-impl Drop for Engine {
-    fn drop(&mut self) {
-        Device::set_or_panic(self.runtime.device());
-        let Engine { internal, .. } = *self;
-        cpp!(unsafe [
-            internal as "void*"
-        ] {
-            destroy((ICudaEngine*) internal);
-        });
+    /// Get the min values of an input shape tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_min_shape_values()`].
+    pub fn profile_min_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.profile_shape_values_for_selector(tensor_name, profile_index, 0)
     }
-}
-
-/// Synchronous implementation of [`crate::ExecutionContext`].
-///
-/// Refer to [`crate::ExecutionContext`] for documentation.
-pub struct ExecutionContext<'engine> {
-    internal: *mut std::ffi::c_void,
-    device: DeviceId,
-    _parent: Option<std::sync::Arc<Engine>>,
-    _phantom: std::marker::PhantomData<&'engine ()>,
-}
-
-/// Implements [`Send`] for `ExecutionContext`.
-///
-/// # Safety
-///
-/// The TensorRT API is thread-safe with regards to all operations on [`ExecutionContext`].
-unsafe impl<'engine> Send for ExecutionContext<'engine> {}
-
-/// Implements [`Sync`] for `ExecutionContext`.
-///
-/// # Safety
-///
-/// The TensorRT API is thread-safe with regards to all operations on [`ExecutionContext`].
-unsafe impl<'engine> Sync for ExecutionContext<'engine> {}
 
-impl ExecutionContext<'static> {
-    pub fn from_engine(mut engine: Engine) -> Result<Self> {
-        let internal = unsafe { Self::new_internal(&mut engine) };
-        result!(
-            internal,
-            Self {
-                internal,
-                device: engine.device(),
-                _parent: Some(std::sync::Arc::new(engine)),
-                _phantom: Default::default(),
-            }
-        )
+    /// Get the opt values of an input shape tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_opt_shape_values()`].
+    pub fn profile_opt_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.profile_shape_values_for_selector(tensor_name, profile_index, 1)
     }
 
-    pub fn from_engine_many(mut engine: Engine, num: usize) -> Result<Vec<Self>> {
-        let mut internals = Vec::with_capacity(num);
-        for _ in 0..num {
-            internals.push(unsafe { Self::new_internal(&mut engine) });
-        }
-        let device = engine.device();
-        let parent = std::sync::Arc::new(engine);
-        internals
-            .into_iter()
-            .map(|internal| {
-                result!(
-                    internal,
-                    Self {
-                        internal,
-                        device,
-                        _parent: Some(parent.clone()),
-                        _phantom: Default::default(),
-                    }
-                )
-            })
-            .collect()
+    /// Get the max values of an input shape tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_max_shape_values()`].
+    pub fn profile_max_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<Vec<i32>> {
+        self.profile_shape_values_for_selector(tensor_name, profile_index, 2)
     }
-}
 
-impl<'engine> ExecutionContext<'engine> {
-    pub fn new(engine: &'engine mut Engine) -> Result<Self> {
-        let internal = unsafe { Self::new_internal(engine) };
-        result!(
-            internal,
-            Self {
-                internal,
-                device: engine.device(),
-                _parent: None,
-                _phantom: Default::default(),
-            }
-        )
+    /// Get the min, opt and max values of an input shape tensor in an optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::profile_shape_values()`].
+    pub fn profile_shape_values(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+    ) -> Option<(Vec<i32>, Vec<i32>, Vec<i32>)> {
+        Some((
+            self.profile_min_shape_values(tensor_name, profile_index)?,
+            self.profile_opt_shape_values(tensor_name, profile_index)?,
+            self.profile_max_shape_values(tensor_name, profile_index)?,
+        ))
     }
 
-    pub fn enqueue<T: Copy>(
-        &mut self,
-        io_tensors: &mut std::collections::HashMap<
-            &str,
-            &mut async_cuda::ffi::memory::DeviceBuffer<T>,
-        >,
-        stream: &async_cuda::ffi::stream::Stream,
-    ) -> Result<()> {
-        let internal = self.as_mut_ptr();
-        for (tensor_name, buffer) in io_tensors {
-            unsafe {
-                self.set_tensor_address(tensor_name, buffer)?;
-            }
+    fn profile_shape_values_for_selector(
+        &self,
+        tensor_name: &str,
+        profile_index: usize,
+        select: i32,
+    ) -> Option<Vec<i32>> {
+        if profile_index >= self.num_optimization_profiles()
+            || self.tensor_io_mode(tensor_name) != TensorIoMode::Input
+            || !self.is_shape_inference_io(tensor_name)
+        {
+            return None;
         }
-        let stream_ptr = stream.as_internal().as_ptr();
-        let success = cpp!(unsafe [
-            internal as "void*",
-            stream_ptr as "const void*"
-        ] -> bool as "bool" {
-            return ((IExecutionContext*) internal)->enqueueV3((cudaStream_t) stream_ptr);
+        let num_values = self.tensor_shape(tensor_name).num_elements()?;
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let profile_index = profile_index as i32;
+        let values_ptr = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*",
+            profile_index as "std::int32_t",
+            select as "std::int32_t"
+        ] -> *const i32 as "const std::int32_t*" {
+            return ((const ICudaEngine*) internal)->getProfileTensorValues(
+                tensor_name_ptr,
+                profile_index,
+                static_cast<OptProfileSelector>(select)
+            );
         });
-        if success {
-            Ok(())
-        } else {
-            Err(last_error())
+        if values_ptr.is_null() {
+            return None;
         }
+        // SAFETY: TensorRT owns the values behind `values_ptr`, which remain valid for as long as
+        // the engine lives, and `self` is borrowed for the duration of the copy. The pointer refers
+        // to one value per element of the shape tensor, and `num_values` is the number of elements
+        // of that same tensor of this engine, so the slice is in bounds.
+        Some(unsafe { std::slice::from_raw_parts(values_ptr, num_values) }.to_vec())
     }
 
-    #[inline(always)]
-    pub fn as_ptr(&self) -> *const std::ffi::c_void {
-        let ExecutionContext { internal, .. } = *self;
-        internal
-    }
-
-    #[inline(always)]
-    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
-        let ExecutionContext { internal, .. } = *self;
-        internal
-    }
-
-    #[inline(always)]
-    pub fn device(&self) -> DeviceId {
-        self.device
-    }
-
-    unsafe fn new_internal(engine: &mut Engine) -> *mut std::ffi::c_void {
-        Device::set_or_panic(engine.device());
-        let internal_engine = engine.as_mut_ptr();
-        let internal = cpp!(unsafe [
-            internal_engine as "void*"
-        ] -> *mut std::ffi::c_void as "void*" {
-            return (void*) ((ICudaEngine*) internal_engine)->createExecutionContext();
-        });
-        internal
-    }
-
-    unsafe fn set_tensor_address<T: Copy>(
-        &mut self,
+    fn profile_shape_for_selector(
+        &self,
         tensor_name: &str,
-        buffer: &mut async_cuda::ffi::memory::DeviceBuffer<T>,
-    ) -> Result<()> {
-        let internal = self.as_mut_ptr();
+        profile_index: usize,
+        select: i32,
+    ) -> Option<Dims> {
+        let internal = self.as_ptr();
         let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
         let tensor_name_ptr = tensor_name_cstr.as_ptr();
-        let buffer_ptr = buffer.as_mut_internal().as_mut_ptr();
-        let success = cpp!(unsafe [
+        let profile_index = profile_index as i32;
+        let tensor_dimensions = cpp!(unsafe [
             internal as "const void*",
             tensor_name_ptr as "const char*",
-            buffer_ptr as "void*"
-        ] -> bool as "bool" {
-            return ((IExecutionContext*) internal)->setTensorAddress(
+            profile_index as "std::int32_t",
+            select as "std::int32_t"
+        ] -> RawDims as "Dims64" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((const ICudaEngine*) internal)->getProfileShape(
                 tensor_name_ptr,
-                buffer_ptr
+                profile_index,
+                static_cast<OptProfileSelector>(select)
+            );
+            #else
+            Dims32 dims32 = ((const ICudaEngine*) internal)->getProfileShape(
+                tensor_name_ptr,
+                profile_index,
+                static_cast<OptProfileSelector>(select)
             );
+            Dims64 dims64;
+            dims64.nbDims = dims32.nbDims;
+            for (int i = 0; i < dims32.nbDims; i++) {
+                dims64.d[i] = dims32.d[i];
+            }
+            return dims64;
+            #endif
         });
-        if success {
-            Ok(())
+
+        if tensor_dimensions.nbDims >= 0 {
+            let num_dims = tensor_dimensions.nbDims as usize;
+            Some(Dims::from(&tensor_dimensions.d[..num_dims]))
         } else {
-            Err(last_error())
+            None
         }
     }
-}
 
-impl<'engine> Drop for ExecutionContext<'engine> {
-    fn drop(&mut self) {
-        Device::set_or_panic(self.device);
-        let ExecutionContext { internal, .. } = *self;
-        cpp!(unsafe [
-            internal as "void*"
-        ] {
+    /// Get the IO mode of a tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_io_mode()`].
+    pub fn tensor_io_mode(&self, tensor_name: &str) -> TensorIoMode {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let tensor_io_mode = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ICudaEngine*) internal)->getTensorIOMode(tensor_name_ptr);
+        });
+        TensorIoMode::from_i32(tensor_io_mode)
+    }
+
+    /// Get the data type of a tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_data_type()`].
+    pub fn tensor_data_type(&self, tensor_name: &str) -> Option<DataType> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let data_type = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ICudaEngine*) internal)->getTensorDataType(tensor_name_ptr);
+        });
+        DataType::from_i32(data_type)
+    }
+
+    /// Get the location of a tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_location()`].
+    pub fn tensor_location(&self, tensor_name: &str) -> TensorLocation {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let location = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ICudaEngine*) internal)->getTensorLocation(tensor_name_ptr);
+        });
+        TensorLocation::from_i32(location)
+    }
+
+    /// Get descriptions of all IO tensors.
+    ///
+    /// Synchronous version of [`crate::Engine::io_tensors()`].
+    pub fn io_tensors(&self) -> Vec<IoTensorDescriptor> {
+        (0..self.num_io_tensors())
+            .map(|index| {
+                let name = self.io_tensor_name(index);
+                IoTensorDescriptor {
+                    io_mode: self.tensor_io_mode(&name),
+                    data_type: self.tensor_data_type(&name),
+                    shape: self.tensor_shape(&name),
+                    format: self.tensor_format(&name),
+                    location: self.tensor_location(&name),
+                    name,
+                }
+            })
+            .collect()
+    }
+
+    /// Get the memory layout of a tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_format()`].
+    pub fn tensor_format(&self, tensor_name: &str) -> Option<TensorFormat> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let tensor_format = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const ICudaEngine*) internal)->getTensorFormat(tensor_name_ptr);
+        });
+        TensorFormat::from_i32(tensor_format)
+    }
+
+    /// Get the number of bytes per component of a tensor element.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_bytes_per_component()`].
+    pub fn tensor_bytes_per_component(&self, tensor_name: &str) -> Option<usize> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let bytes_per_component = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return ((const ICudaEngine*) internal)->getTensorBytesPerComponent(tensor_name_ptr);
+        });
+        usize::try_from(bytes_per_component).ok()
+    }
+
+    /// Get the number of components in a tensor element.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_components_per_element()`].
+    pub fn tensor_components_per_element(&self, tensor_name: &str) -> Option<usize> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let components_per_element = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return ((const ICudaEngine*) internal)->getTensorComponentsPerElement(tensor_name_ptr);
+        });
+        usize::try_from(components_per_element).ok()
+    }
+
+    /// Get the dimension index along which a tensor is vectorized.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_vectorized_dim()`].
+    pub fn tensor_vectorized_dim(&self, tensor_name: &str) -> Option<usize> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let vectorized_dim = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return ((const ICudaEngine*) internal)->getTensorVectorizedDim(tensor_name_ptr);
+        });
+        usize::try_from(vectorized_dim).ok()
+    }
+
+    /// Get the amount of device memory required by an execution context.
+    ///
+    /// Synchronous version of [`crate::Engine::device_memory_size_v2()`].
+    pub fn device_memory_size_v2(&self) -> i64 {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return ((const ICudaEngine*) internal)->getDeviceMemorySizeV2();
+            #else
+            return (std::int64_t) ((const ICudaEngine*) internal)->getDeviceMemorySize();
+            #endif
+        })
+    }
+
+    /// Get the amount of device memory required by an execution context that uses a specific
+    /// optimization profile.
+    ///
+    /// Synchronous version of [`crate::Engine::device_memory_size_for_profile()`].
+    pub fn device_memory_size_for_profile(&self, profile_index: usize) -> Result<i64> {
+        if profile_index >= self.num_optimization_profiles() {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "optimization profile {profile_index} does not exist"
+            )));
+        }
+        let internal = self.as_ptr();
+        let profile_index = profile_index as i32;
+        Ok(cpp!(unsafe [
+            internal as "const void*",
+            profile_index as "std::int32_t"
+        ] -> i64 as "std::int64_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return ((const ICudaEngine*) internal)->getDeviceMemorySizeForProfileV2(profile_index);
+            #elif NV_TENSORRT_MAJOR >= 10
+            return (std::int64_t) ((const ICudaEngine*) internal)
+                ->getDeviceMemorySizeForProfile(profile_index);
+            #else
+            return (std::int64_t) ((const ICudaEngine*) internal)->getDeviceMemorySize();
+            #endif
+        }))
+    }
+
+    /// Set the budget of device memory that may be used for weights when weight streaming is
+    /// enabled.
+    ///
+    /// Synchronous version of [`crate::Engine::set_weight_streaming_budget_v2()`].
+    pub fn set_weight_streaming_budget_v2(&mut self, budget: i64) -> Result<()> {
+        Device::set(self.device())?;
+        let internal = self.as_mut_ptr();
+        let status = cpp!(unsafe [
+            internal as "void*",
+            budget as "std::int64_t"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return ((ICudaEngine*) internal)->setWeightStreamingBudgetV2(budget) ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        match status {
+            1 => Ok(()),
+            0 => Err(last_error()),
+            _ => Err(crate::error::Error::InvalidArgument(
+                "weight streaming not supported by this version of TensorRT".to_string(),
+            )),
+        }
+    }
+
+    /// Get the current weight streaming budget in bytes.
+    ///
+    /// Synchronous version of [`crate::Engine::get_weight_streaming_budget()`].
+    pub fn get_weight_streaming_budget(&self) -> i64 {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return ((const ICudaEngine*) internal)->getWeightStreamingBudgetV2();
+            #else
+            return 0;
+            #endif
+        })
+    }
+
+    /// Get the total size in bytes of all streamable weights.
+    ///
+    /// Synchronous version of [`crate::Engine::get_streamable_weights_size()`].
+    pub fn get_streamable_weights_size(&self) -> i64 {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((const ICudaEngine*) internal)->getStreamableWeightsSize();
+            #else
+            return 0;
+            #endif
+        })
+    }
+
+    /// Whether or not the engine was built from a network with an implicit batch dimension.
+    ///
+    /// Synchronous version of [`crate::Engine::has_implicit_batch_dimension()`].
+    pub fn has_implicit_batch_dimension(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR >= 10
+            // Implicit batch networks are no longer supported.
+            return false;
+            #else
+            return ((const ICudaEngine*) internal)->hasImplicitBatchDimension();
+            #endif
+        })
+    }
+
+    /// Get the range of GPU architectures that the engine is compatible with.
+    ///
+    /// Synchronous version of [`crate::Engine::hardware_compatibility_level()`].
+    pub fn hardware_compatibility_level(&self) -> HardwareCompatibilityLevel {
+        let internal = self.as_ptr();
+        let hardware_compatibility_level = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return (std::int32_t) ((const ICudaEngine*) internal)->getHardwareCompatibilityLevel();
+            #else
+            return 0;
+            #endif
+        });
+        HardwareCompatibilityLevel::from_i32(hardware_compatibility_level)
+    }
+
+    /// Get the number of optimization profiles the engine was built with.
+    ///
+    /// Synchronous version of [`crate::Engine::num_optimization_profiles()`].
+    pub fn num_optimization_profiles(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_optimization_profiles = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const ICudaEngine*) internal)->getNbOptimizationProfiles();
+        });
+        num_optimization_profiles.max(0) as usize
+    }
+
+    /// Get the number of layers in the engine.
+    ///
+    /// Synchronous version of [`crate::Engine::num_layers()`].
+    pub fn num_layers(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_layers = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const ICudaEngine*) internal)->getNbLayers();
+        });
+        num_layers.max(0) as usize
+    }
+
+    /// Get the number of auxiliary streams the engine uses.
+    ///
+    /// Synchronous version of [`crate::Engine::num_aux_streams()`].
+    pub fn num_aux_streams(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_aux_streams = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const ICudaEngine*) internal)->getNbAuxStreams();
+            #else
+            return 0;
+            #endif
+        });
+        num_aux_streams.max(0) as usize
+    }
+
+    /// Whether or not the engine can be refitted.
+    ///
+    /// Synchronous version of [`crate::Engine::is_refittable()`].
+    pub fn is_refittable(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            return ((const ICudaEngine*) internal)->isRefittable();
+        })
+    }
+
+    /// Whether or not a tensor is an input that is required for shape inference, or an output that
+    /// is computed by shape inference.
+    ///
+    /// Synchronous version of [`crate::Engine::is_shape_inference_io()`].
+    pub fn is_shape_inference_io(&self, tensor_name: &str) -> bool {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> bool as "bool" {
+            return ((const ICudaEngine*) internal)->isShapeInferenceIO(tensor_name_ptr);
+        })
+    }
+
+    /// Get the names and sizes in bytes of the IO tensors with the opt shapes of an optimization
+    /// profile.
+    ///
+    /// Synchronous version of [`crate::Engine::allocate_io_buffers_for_profile()`].
+    pub fn io_buffer_sizes(
+        &mut self,
+        profile_index: usize,
+        stream: &async_cuda::ffi::stream::Stream,
+    ) -> Result<Vec<(String, usize)>> {
+        let num_optimization_profiles = self.num_optimization_profiles().max(1);
+        if profile_index >= num_optimization_profiles {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "optimization profile {profile_index} out of range, engine has \
+                 {num_optimization_profiles} profiles"
+            )));
+        }
+        let io_tensors = self
+            .io_tensors()
+            .into_iter()
+            .filter(|io_tensor| {
+                !(io_tensor.io_mode == TensorIoMode::Input
+                    && self.is_shape_inference_io(&io_tensor.name))
+            })
+            .collect::<Vec<_>>();
+        let mut input_shapes = Vec::new();
+        for io_tensor in &io_tensors {
+            if io_tensor.io_mode == TensorIoMode::Input && io_tensor.shape.is_dynamic() {
+                let shape = self
+                    .profile_opt_shape(&io_tensor.name, profile_index)
+                    .ok_or_else(|| {
+                        crate::error::Error::InvalidArgument(format!(
+                            "input tensor {} has no shape in optimization profile {profile_index}",
+                            io_tensor.name,
+                        ))
+                    })?;
+                input_shapes.push((io_tensor.name.clone(), shape));
+            }
+        }
+        let mut context = ExecutionContext::new_without_device_memory(self)?;
+        if profile_index > 0 {
+            let internal = context.as_mut_ptr();
+            let profile_index = profile_index as i32;
+            let stream_ptr = stream.as_internal().as_ptr();
+            let success = cpp!(unsafe [
+                internal as "void*",
+                profile_index as "std::int32_t",
+                stream_ptr as "const void*"
+            ] -> bool as "bool" {
+                return ((IExecutionContext*) internal)->setOptimizationProfileAsync(
+                    profile_index,
+                    (cudaStream_t) stream_ptr
+                );
+            });
+            if !success {
+                return Err(last_error());
+            }
+        }
+        for (tensor_name, shape) in input_shapes {
+            context.set_input_shape(&tensor_name, shape)?;
+        }
+        Ok(io_tensors
+            .into_iter()
+            .filter_map(|io_tensor| {
+                let num_elements = context.tensor_shape(&io_tensor.name).num_elements()?;
+                let num_bytes = io_tensor.data_type?.num_bytes(num_elements);
+                Some((io_tensor.name, num_bytes))
+            })
+            .collect())
+    }
+
+    /// Get a description of the memory layout of a tensor.
+    ///
+    /// Synchronous version of [`crate::Engine::tensor_format_desc()`].
+    pub fn tensor_format_desc(&self, tensor_name: &str) -> Option<String> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let tensor_format_desc_ptr = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const ICudaEngine*) internal)->getTensorFormatDesc(tensor_name_ptr);
+        });
+        if tensor_format_desc_ptr.is_null() {
+            return None;
+        }
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe {
+            Some(
+                std::ffi::CStr::from_ptr(tensor_format_desc_ptr)
+                    .to_string_lossy()
+                    .to_string(),
+            )
+        }
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let Engine { internal, .. } = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let Engine { internal, .. } = *self;
+        internal
+    }
+
+    /// Get the device the engine was deserialized on.
+    ///
+    /// Synchronous version of [`crate::Engine::device()`].
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.runtime.device()
+    }
+}
+
+impl Drop for Engine {
+    fn drop(&mut self) {
+        Device::set_or_panic(self.runtime.device());
+        let Engine { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            destroy((ICudaEngine*) internal);
+        });
+    }
+}
+
+/// Synchronous implementation of [`crate::ExecutionContext`].
+///
+/// Refer to [`crate::ExecutionContext`] for documentation.
+pub struct ExecutionContext<'engine> {
+    internal: *mut std::ffi::c_void,
+    graph_exec: *mut std::ffi::c_void,
+    output_allocators: std::collections::HashMap<String, OutputAllocatorBridge>,
+    debug_listener: Option<DebugListenerBridge>,
+    shape_inputs: std::collections::HashMap<String, ShapeInputValues>,
+    validate_buffer_sizes: bool,
+    stream_events: std::collections::HashMap<usize, Event>,
+    device: DeviceId,
+    _parent: Option<std::sync::Arc<Engine>>,
+    _phantom: std::marker::PhantomData<&'engine ()>,
+}
+
+/// Implements [`Send`] for `ExecutionContext`.
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`ExecutionContext`].
+unsafe impl<'engine> Send for ExecutionContext<'engine> {}
+
+/// Implements [`Sync`] for `ExecutionContext`.
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`ExecutionContext`].
+unsafe impl<'engine> Sync for ExecutionContext<'engine> {}
+
+impl ExecutionContext<'static> {
+    /// Create an execution context from an [`Engine`].
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::from_engine()`].
+    pub fn from_engine(mut engine: Engine) -> Result<Self> {
+        let internal = unsafe { Self::new_internal(&mut engine) };
+        result!(
+            internal,
+            Self {
+                internal,
+                graph_exec: std::ptr::null_mut(),
+                output_allocators: std::collections::HashMap::new(),
+                debug_listener: None,
+                shape_inputs: std::collections::HashMap::new(),
+                validate_buffer_sizes: cfg!(debug_assertions),
+                stream_events: std::collections::HashMap::new(),
+                device: engine.device(),
+                _parent: Some(std::sync::Arc::new(engine)),
+                _phantom: Default::default(),
+            }
+        )
+    }
+
+    /// Create multiple execution contexts from an [`Engine`].
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::from_engine_many()`].
+    pub fn from_engine_many(mut engine: Engine, num: usize) -> Result<Vec<Self>> {
+        let mut internals = Vec::with_capacity(num);
+        for _ in 0..num {
+            internals.push(unsafe { Self::new_internal(&mut engine) });
+        }
+        let device = engine.device();
+        let parent = std::sync::Arc::new(engine);
+        internals
+            .into_iter()
+            .map(|internal| {
+                result!(
+                    internal,
+                    Self {
+                        internal,
+                        graph_exec: std::ptr::null_mut(),
+                        output_allocators: std::collections::HashMap::new(),
+                        debug_listener: None,
+                        shape_inputs: std::collections::HashMap::new(),
+                        validate_buffer_sizes: cfg!(debug_assertions),
+                        stream_events: std::collections::HashMap::new(),
+                        device,
+                        _parent: Some(parent.clone()),
+                        _phantom: Default::default(),
+                    }
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'engine> ExecutionContext<'engine> {
+    /// Create an execution context from an [`Engine`].
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::new()`].
+    pub fn new(engine: &'engine mut Engine) -> Result<Self> {
+        let internal = unsafe { Self::new_internal(engine) };
+        result!(
+            internal,
+            Self {
+                internal,
+                graph_exec: std::ptr::null_mut(),
+                output_allocators: std::collections::HashMap::new(),
+                debug_listener: None,
+                shape_inputs: std::collections::HashMap::new(),
+                validate_buffer_sizes: cfg!(debug_assertions),
+                stream_events: std::collections::HashMap::new(),
+                device: engine.device(),
+                _parent: None,
+                _phantom: Default::default(),
+            }
+        )
+    }
+
+    /// Create an execution context from an [`Engine`] without any device memory allocated.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::new_without_device_memory()`].
+    pub fn new_without_device_memory(engine: &'engine mut Engine) -> Result<Self> {
+        Self::new_with_allocation_strategy(engine, ExecutionContextAllocationStrategy::UserManaged)
+    }
+
+    /// Create an execution context from an [`Engine`] with the given strategy for allocating device
+    /// memory.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::new_with_allocation_strategy()`].
+    pub fn new_with_allocation_strategy(
+        engine: &'engine mut Engine,
+        strategy: ExecutionContextAllocationStrategy,
+    ) -> Result<Self> {
+        if strategy == ExecutionContextAllocationStrategy::OnProfileChange
+            && !allocation_strategies_supported()
+        {
+            return Err(crate::error::Error::InvalidArgument(
+                "allocation strategy OnProfileChange not supported by this version of TensorRT"
+                    .to_string(),
+            ));
+        }
+        Device::set(engine.device())?;
+        let internal_engine = engine.as_mut_ptr();
+        let strategy = strategy as i32;
+        let internal = cpp!(unsafe [
+            internal_engine as "void*",
+            strategy as "std::int32_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return (void*) ((ICudaEngine*) internal_engine)->createExecutionContext(
+                static_cast<ExecutionContextAllocationStrategy>(strategy)
+            );
+            #else
+            if (strategy == 2) {
+                return (void*) ((ICudaEngine*) internal_engine)->createExecutionContextWithoutDeviceMemory();
+            }
+            return (void*) ((ICudaEngine*) internal_engine)->createExecutionContext();
+            #endif
+        });
+        result!(
+            internal,
+            Self {
+                internal,
+                graph_exec: std::ptr::null_mut(),
+                output_allocators: std::collections::HashMap::new(),
+                debug_listener: None,
+                shape_inputs: std::collections::HashMap::new(),
+                validate_buffer_sizes: cfg!(debug_assertions),
+                stream_events: std::collections::HashMap::new(),
+                device: engine.device(),
+                _parent: None,
+                _phantom: Default::default(),
+            }
+        )
+    }
+
+    /// Set the device memory for use by the execution context.
+    ///
+    /// The buffer must be at least [`Engine::device_memory_size_v2()`] bytes large.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer outlives all inference that uses it, and is not
+    /// used by any other execution context while inference with this context is in progress. If
+    /// the context was using other device memory before, inference that uses the previous memory
+    /// must have completed before it is freed (see [`ExecutionContext::synchronize_streams()`]).
+    pub unsafe fn set_device_memory(
+        &mut self,
+        buffer: &async_cuda::ffi::memory::DeviceBuffer<u8>,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let required_size = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i64 as "std::int64_t" {
+            const ICudaEngine& engine = ((const IExecutionContext*) internal)->getEngine();
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            return engine.getDeviceMemorySizeV2();
+            #else
+            return (std::int64_t) engine.getDeviceMemorySize();
+            #endif
+        });
+        let buffer_size = buffer.num_elements as i64;
+        if buffer_size < required_size {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "device memory of {buffer_size} bytes is too small, engine requires \
+                 {required_size} bytes"
+            )));
+        }
+        let buffer_ptr = buffer.as_internal().as_ptr() as *mut std::ffi::c_void;
+        cpp!(unsafe [
+            internal as "void*",
+            buffer_ptr as "void*",
+            buffer_size as "std::int64_t"
+        ] {
+            #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+            ((IExecutionContext*) internal)->setDeviceMemoryV2(buffer_ptr, buffer_size);
+            #else
+            // The size was checked above since `setDeviceMemory` does not take one.
+            ((IExecutionContext*) internal)->setDeviceMemory(buffer_ptr);
+            #endif
+        });
+        Ok(())
+    }
+
+    /// Wait for all work that the execution context queued on its streams to complete.
+    ///
+    /// Unlike synchronizing the device, this does not wait for unrelated work on other streams.
+    pub fn synchronize_streams(&self) -> Result<()> {
+        for event in self.stream_events.values() {
+            event.synchronize()?;
+        }
+        Ok(())
+    }
+
+    /// Record the work that was just queued on the stream, so that
+    /// [`ExecutionContext::synchronize_streams`] can wait for it later.
+    fn record_stream_use(&mut self, stream: &async_cuda::ffi::stream::Stream) -> Result<()> {
+        let key = stream.as_internal().as_ptr() as usize;
+        let event = match self.stream_events.entry(key) {
+            std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                Device::set(self.device)?;
+                entry.insert(Event::new()?)
+            }
+        };
+        event.record(stream)
+    }
+
+    /// Set the name of the execution context.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_name()`].
+    pub fn set_name(&mut self, name: &str) {
+        let internal = self.as_mut_ptr();
+        let name_ffi = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name_ffi.as_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            name_ptr as "const char*"
+        ] {
+            ((IExecutionContext*) internal)->setName(name_ptr);
+        });
+    }
+
+    /// Get the name of the execution context.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::name()`].
+    pub fn name(&self) -> String {
+        let internal = self.as_ptr();
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const IExecutionContext*) internal)->getName();
+        });
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+    }
+
+    /// Get the name of the engine that the execution context belongs to.
+    pub fn engine_name(&self) -> String {
+        let internal = self.as_ptr();
+        let name = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            return ((const IExecutionContext*) internal)->getEngine().getName();
+        });
+        // SAFETY: This is safe because:
+        // * The pointer is valid because we just got it from TensorRT.
+        // * The pointer isn't kept after this block (we copy the string instead).
+        unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+    }
+
+    /// Set the maximum amount of L2 cache that may be persisted for activations.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_persistent_cache_limit()`].
+    pub fn set_persistent_cache_limit(&mut self, size: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            size as "std::size_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IExecutionContext*) internal)->setPersistentCacheLimit(size);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "persistent cache limit not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Get the maximum amount of L2 cache that may be persisted for activations.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::persistent_cache_limit()`].
+    pub fn persistent_cache_limit(&self) -> usize {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> usize as "std::size_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IExecutionContext*) internal)->getPersistentCacheLimit();
+            #else
+            return 0;
+            #endif
+        })
+    }
+
+    /// Set the auxiliary streams that TensorRT may use during inference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the streams outlive the execution context, or are replaced
+    /// by another call to this function before they are dropped.
+    pub unsafe fn set_aux_streams(
+        &mut self,
+        streams: &[&async_cuda::ffi::stream::Stream],
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let mut stream_ptrs = streams
+            .iter()
+            .map(|stream| stream.as_internal().as_ptr())
+            .collect::<Vec<_>>();
+        let stream_ptrs_ptr = stream_ptrs.as_mut_ptr();
+        let num_streams = stream_ptrs.len().min(i32::MAX as usize) as i32;
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            stream_ptrs_ptr as "void**",
+            num_streams as "std::int32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IExecutionContext*) internal)->setAuxStreams(
+                (cudaStream_t*) stream_ptrs_ptr,
+                num_streams
+            );
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "auxiliary streams not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Set the event that is recorded once the input buffers have been consumed.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the event outlives the execution context, or is replaced
+    /// by another call to this function before it is dropped.
+    pub unsafe fn set_input_consumed_event(&mut self, event: Option<&Event>) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let event_ptr = event.map_or(std::ptr::null(), |event| event.as_ptr());
+        let success = cpp!(unsafe [
+            internal as "void*",
+            event_ptr as "const void*"
+        ] -> bool as "bool" {
+            return ((IExecutionContext*) internal)->setInputConsumedEvent((cudaEvent_t) event_ptr);
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Enqueue inference on a stream, binding the given buffers to the IO tensors first.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::enqueue()`].
+    pub fn enqueue<T: Copy>(
+        &mut self,
+        io_tensors: &mut std::collections::HashMap<
+            &str,
+            &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+        >,
+        stream: &async_cuda::ffi::stream::Stream,
+    ) -> Result<()> {
+        self.validate_io_tensors(io_tensors.keys().copied())?;
+        for (tensor_name, buffer) in io_tensors {
+            unsafe {
+                self.set_tensor_address(tensor_name, buffer)?;
+            }
+        }
+        self.enqueue_v3(stream)
+    }
+
+    /// Enqueue inference on a stream, binding the given input and output buffers first.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::enqueue_with_inputs()`].
+    pub fn enqueue_with_inputs<T: Copy>(
+        &mut self,
+        inputs: &std::collections::HashMap<&str, &async_cuda::ffi::memory::DeviceBuffer<T>>,
+        outputs: &mut std::collections::HashMap<
+            &str,
+            &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+        >,
+        stream: &async_cuda::ffi::stream::Stream,
+    ) -> Result<()> {
+        self.validate_io_tensors(inputs.keys().chain(outputs.keys()).copied())?;
+        for (tensor_name, buffer) in inputs {
+            unsafe {
+                self.bind_input(tensor_name, buffer)?;
+            }
+        }
+        for (tensor_name, buffer) in outputs {
+            unsafe {
+                self.bind_output(tensor_name, buffer)?;
+            }
+        }
+        self.enqueue_v3(stream)
+    }
+
+    /// Enqueue inference on a stream with a precomputed set of bindings.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::enqueue_bound()`].
+    pub fn enqueue_bound(
+        &mut self,
+        bindings: &IoBindings,
+        stream: &async_cuda::ffi::stream::Stream,
+    ) -> Result<()> {
+        self.validate_io_tensors(bindings.tensor_names())?;
+        for binding in bindings.bindings() {
+            self.check_io_mode(&binding.tensor_name, binding.io_mode)?;
+            match self.engine_tensor_data_type(&binding.tensor_name) {
+                Some(expected) if expected == binding.data_type => {}
+                Some(expected) => {
+                    return Err(crate::error::Error::DataTypeMismatch {
+                        tensor_name: binding.tensor_name.clone(),
+                        expected,
+                        actual: binding.data_type,
+                    });
+                }
+                None => {
+                    return Err(crate::error::Error::InvalidArgument(format!(
+                        "tensor {} has a data type that is not supported",
+                        binding.tensor_name,
+                    )));
+                }
+            }
+            self.check_tensor_location(&binding.tensor_name, TensorLocation::Device)?;
+            self.check_buffer_size(&binding.tensor_name, binding.num_bytes)?;
+            // SAFETY: The buffers are borrowed by `bindings` for as long as it is alive.
+            unsafe {
+                match binding.io_mode {
+                    TensorIoMode::Input => {
+                        self.set_input_tensor_address_raw(&binding.tensor_name, binding.buffer_ptr)?
+                    }
+                    _ => self.set_tensor_address_raw(&binding.tensor_name, binding.buffer_ptr)?,
+                }
+            }
+        }
+        self.enqueue_v3(stream)
+    }
+
+    /// Enqueue inference on a stream with the buffers that are currently bound.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::enqueue_prebound()`].
+    pub fn enqueue_prebound(&mut self, stream: &async_cuda::ffi::stream::Stream) -> Result<()> {
+        self.check_ready()?;
+        self.enqueue_v3(stream)
+    }
+
+    fn enqueue_v3(&mut self, stream: &async_cuda::ffi::stream::Stream) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let stream_ptr = stream.as_internal().as_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            stream_ptr as "const void*"
+        ] -> bool as "bool" {
+            #ifdef ODDITY_FFI_NVTX
+            // Name the range after the context, so that profiler traces show which model the
+            // kernels belong to.
+            const char* name = ((const IExecutionContext*) internal)->getName();
+            if (name == nullptr || name[0] == '\0') {
+                name = ((const IExecutionContext*) internal)->getEngine().getName();
+            }
+            nvtxRangePushA(name);
+            bool success = ((IExecutionContext*) internal)->enqueueV3((cudaStream_t) stream_ptr);
+            nvtxRangePop();
+            return success;
+            #else
+            return ((IExecutionContext*) internal)->enqueueV3((cudaStream_t) stream_ptr);
+            #endif
+        });
+        if !success {
+            return Err(last_error());
+        }
+        self.record_stream_use(stream)
+    }
+
+    /// Bind a buffer to an IO tensor.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    pub unsafe fn bind_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.set_tensor_address(tensor_name, buffer)
+    }
+
+    /// Bind a buffer to an input tensor without requiring mutable access to it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    pub unsafe fn bind_input<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &async_cuda::ffi::memory::DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.check_io_mode(tensor_name, TensorIoMode::Input)?;
+        self.check_tensor_location(tensor_name, TensorLocation::Device)?;
+        self.check_buffer_size(tensor_name, buffer.num_elements * std::mem::size_of::<T>())?;
+        self.set_input_tensor_address_raw(tensor_name, buffer.as_internal().as_ptr())
+    }
+
+    /// Bind a buffer to an output tensor.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    pub unsafe fn bind_output<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.check_io_mode(tensor_name, TensorIoMode::Output)?;
+        self.set_tensor_address(tensor_name, buffer)
+    }
+
+    /// Bind part of a buffer, starting at `byte_offset`, to an IO tensor.
+    ///
+    /// The offset must be a multiple of 256 bytes, and the tensor, with its current shape, must
+    /// fit in the buffer after the offset.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed, and that the shape of the tensor does not grow beyond the bound
+    /// region after binding.
+    pub unsafe fn bind_tensor_with_offset<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+        byte_offset: usize,
+    ) -> Result<()> {
+        if byte_offset % TENSOR_ADDRESS_ALIGNMENT != 0 {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "offset {byte_offset} is not aligned to {TENSOR_ADDRESS_ALIGNMENT} bytes"
+            )));
+        }
+        self.check_tensor_location(tensor_name, TensorLocation::Device)?;
+        let Some(tensor_bytes) = self.tensor_num_bytes(tensor_name) else {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "size of tensor {tensor_name} is not known"
+            )));
+        };
+        let num_bytes = buffer.num_elements * std::mem::size_of::<T>();
+        let required = byte_offset.saturating_add(tensor_bytes);
+        if required > num_bytes {
+            return Err(crate::error::Error::BufferTooSmall {
+                tensor_name: tensor_name.to_string(),
+                required,
+                actual: num_bytes,
+            });
+        }
+        let buffer_ptr = (buffer.as_mut_internal().as_mut_ptr() as *mut u8).add(byte_offset);
+        self.set_tensor_address_raw(tensor_name, buffer_ptr as *mut std::ffi::c_void)
+    }
+
+    /// Whether or not the device supports binding page-locked host memory to IO tensors with
+    /// [`ExecutionContext::bind_host_tensor()`].
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::supports_host_tensors()`].
+    pub fn supports_host_tensors(&self) -> bool {
+        let device = self.device;
+        cpp!(unsafe [
+            device as "std::int32_t"
+        ] -> bool as "bool" {
+            int canMapHostMemory = 0;
+            if (cudaDeviceGetAttribute(
+                    &canMapHostMemory,
+                    cudaDevAttrCanMapHostMemory,
+                    device
+                ) != cudaSuccess) {
+                return false;
+            }
+            return canMapHostMemory != 0;
+        })
+    }
+
+    /// Bind a page-locked host buffer to an IO tensor, without copying it to the device.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    pub unsafe fn bind_host_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::HostBuffer<T>,
+    ) -> Result<()> {
+        if !self.supports_host_tensors() {
+            return Err(crate::error::Error::InvalidArgument(
+                "device does not support mapping host memory".to_string(),
+            ));
+        }
+        self.check_tensor_location(tensor_name, TensorLocation::Device)?;
+        self.check_buffer_size(tensor_name, buffer.num_elements * std::mem::size_of::<T>())?;
+        Device::set(self.device)?;
+        let host_ptr = buffer.as_mut_internal().as_mut_ptr();
+        let mut device_ptr = std::ptr::null_mut::<std::ffi::c_void>();
+        let device_ptr_ptr = &mut device_ptr as *mut *mut std::ffi::c_void;
+        let ret = cpp!(unsafe [
+            host_ptr as "void*",
+            device_ptr_ptr as "void**"
+        ] -> i32 as "std::int32_t" {
+            return cudaHostGetDevicePointer(device_ptr_ptr, host_ptr, 0);
+        });
+        if ret != 0 {
+            return Err(cuda_error(ret));
+        }
+        self.set_tensor_address_raw(tensor_name, device_ptr)
+    }
+
+    /// Bind a page-locked host buffer to an IO tensor that is located on the host.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure that the buffer remains alive until all inference that uses the
+    /// binding has completed.
+    pub unsafe fn bind_host_located_tensor<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::HostBuffer<T>,
+    ) -> Result<()> {
+        self.check_tensor_location(tensor_name, TensorLocation::Host)?;
+        self.check_buffer_size(tensor_name, buffer.num_elements * std::mem::size_of::<T>())?;
+        let host_ptr = buffer.as_mut_internal().as_mut_ptr();
+        self.set_tensor_address_raw(tensor_name, host_ptr)
+    }
+
+    /// Set the output allocator for an output tensor.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_output_allocator()`].
+    pub fn set_output_allocator(
+        &mut self,
+        tensor_name: &str,
+        allocator: Box<dyn OutputAllocator>,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let mut bridge = OutputAllocatorBridge::new(allocator);
+        let bridge_ptr = bridge.as_mut_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            tensor_name_ptr as "const char*",
+            bridge_ptr as "void*"
+        ] -> bool as "bool" {
+            return ((IExecutionContext*) internal)->setOutputAllocator(
+                tensor_name_ptr,
+                (IOutputAllocator*) bridge_ptr
+            );
+        });
+        if success {
+            // Replacing the bridge drops the previous allocator, which TensorRT no longer refers
+            // to at this point.
+            self.output_allocators
+                .insert(tensor_name.to_string(), bridge);
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Set the debug listener that receives the values of debug tensors.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_debug_listener()`].
+    pub fn set_debug_listener(&mut self, listener: Box<dyn DebugListener>) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let mut bridge = DebugListenerBridge::new(listener);
+        let bridge_ptr = bridge.as_mut_ptr();
+        let status = cpp!(unsafe [
+            internal as "void*",
+            bridge_ptr as "void*"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((IExecutionContext*) internal)->setDebugListener(
+                (IDebugListener*) bridge_ptr
+            ) ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        match status {
+            1 => {
+                // Replacing the bridge drops the previous listener, which TensorRT no longer refers
+                // to at this point.
+                self.debug_listener = Some(bridge);
+                Ok(())
+            }
+            0 => Err(last_error()),
+            _ => Err(debug_tensors_unsupported_error()),
+        }
+    }
+
+    /// Enable or disable reporting a debug tensor to the debug listener.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_tensor_debug_state()`].
+    pub fn set_tensor_debug_state(&mut self, tensor_name: &str, enabled: bool) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let status = cpp!(unsafe [
+            internal as "void*",
+            tensor_name_ptr as "const char*",
+            enabled as "bool"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((IExecutionContext*) internal)->setTensorDebugState(
+                tensor_name_ptr,
+                enabled
+            ) ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        match status {
+            1 => Ok(()),
+            0 => Err(last_error()),
+            _ => Err(debug_tensors_unsupported_error()),
+        }
+    }
+
+    /// Enable or disable reporting all debug tensors to the debug listener.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_all_tensors_debug_state()`].
+    pub fn set_all_tensors_debug_state(&mut self, enabled: bool) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let status = cpp!(unsafe [
+            internal as "void*",
+            enabled as "bool"
+        ] -> i32 as "std::int32_t" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((IExecutionContext*) internal)->setAllTensorsDebugState(enabled) ? 1 : 0;
+            #else
+            return -1;
+            #endif
+        });
+        match status {
+            1 => Ok(()),
+            0 => Err(last_error()),
+            _ => Err(debug_tensors_unsupported_error()),
+        }
+    }
+
+    /// Set whether buffers are checked to be large enough for the tensors they are bound to.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_validate_buffer_sizes()`].
+    pub fn set_validate_buffer_sizes(&mut self, enabled: bool) {
+        self.validate_buffer_sizes = enabled;
+    }
+
+    /// Get whether buffers are checked to be large enough for the tensors they are bound to.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::validate_buffer_sizes()`].
+    pub fn validate_buffer_sizes(&self) -> bool {
+        self.validate_buffer_sizes
+    }
+
+    /// Set the shape of an input tensor.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_input_shape()`].
+    pub fn set_input_shape(&mut self, tensor_name: &str, dims: impl Into<Dims>) -> Result<()> {
+        let dims = dims.into();
+        if dims.len() > 8 {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "input shape of tensor {tensor_name} has more than 8 dimensions"
+            )));
+        }
+        let internal = self.as_mut_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let mut tensor_dimensions = RawDims {
+            nbDims: dims.len() as i32,
+            d: [0; 8],
+        };
+        tensor_dimensions.d[..dims.len()].copy_from_slice(&dims);
+        let success = cpp!(unsafe [
+            internal as "void*",
+            tensor_name_ptr as "const char*",
+            tensor_dimensions as "Dims64"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((IExecutionContext*) internal)->setInputShape(
+                tensor_name_ptr,
+                tensor_dimensions
+            );
+            #else
+            Dims32 dims32;
+            dims32.nbDims = tensor_dimensions.nbDims;
+            for (int i = 0; i < tensor_dimensions.nbDims; i++) {
+                dims32.d[i] = (std::int32_t) tensor_dimensions.d[i];
+            }
+            return ((IExecutionContext*) internal)->setInputShape(tensor_name_ptr, dims32);
+            #endif
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Set the values of a shape tensor input.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::set_shape_input()`].
+    pub fn set_shape_input(&mut self, tensor_name: &str, values: &[i64]) -> Result<()> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let mut data_type = 0;
+        let data_type_ptr = &mut data_type as *mut i32;
+        let is_shape_input = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*",
+            data_type_ptr as "std::int32_t*"
+        ] -> bool as "bool" {
+            const ICudaEngine& engine = ((const IExecutionContext*) internal)->getEngine();
+            *data_type_ptr = (std::int32_t) engine.getTensorDataType(tensor_name_ptr);
+            return engine.isShapeInferenceIO(tensor_name_ptr)
+                && engine.getTensorIOMode(tensor_name_ptr) == TensorIOMode::kINPUT;
+        });
+        if !is_shape_input {
+            return Err(crate::error::Error::InvalidArgument(format!(
+                "tensor {tensor_name} is not a shape tensor input"
+            )));
+        }
+        let num_values = self.tensor_shape(tensor_name).num_elements().unwrap_or(0);
+        if num_values != values.len() {
+            return Err(crate::error::Error::ShapeMismatch {
+                tensor_name: tensor_name.to_string(),
+                expected: vec![num_values],
+                actual: vec![values.len()],
+            });
+        }
+        let mut values = match DataType::from_i32(data_type) {
+            Some(DataType::Int32) => ShapeInputValues::Int32(
+                values
+                    .iter()
+                    .map(|value| {
+                        i32::try_from(*value).map_err(|_| {
+                            crate::error::Error::InvalidArgument(format!(
+                                "value {value} of shape tensor {tensor_name} does not fit in \
+                                 int32"
+                            ))
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            Some(DataType::Int64) => ShapeInputValues::Int64(values.to_vec()),
+            data_type => {
+                return Err(crate::error::Error::InvalidArgument(format!(
+                    "shape tensor {tensor_name} has unsupported data type {data_type:?}"
+                )));
+            }
+        };
+        // SAFETY: The values are stored in the context below, so they remain alive until they are
+        // replaced by another call to this function, which points the tensor to the replacement
+        // first.
+        unsafe {
+            self.set_tensor_address_raw(tensor_name, values.as_mut_ptr())?;
+        }
+        self.shape_inputs.insert(tensor_name.to_string(), values);
+        Ok(())
+    }
+
+    /// Run shape inference for all tensors whose shapes can be computed.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::infer_shapes()`].
+    pub fn infer_shapes(&mut self) -> Result<Vec<String>> {
+        let internal = self.as_mut_ptr();
+        let max_num_names = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IExecutionContext*) internal)->getEngine().getNbIOTensors();
+        });
+        let mut names_ptrs = vec![std::ptr::null::<std::os::raw::c_char>(); max_num_names as usize];
+        let names_ptrs_ptr = names_ptrs.as_mut_ptr();
+        let num_names = cpp!(unsafe [
+            internal as "void*",
+            max_num_names as "std::int32_t",
+            names_ptrs_ptr as "const char**"
+        ] -> i32 as "std::int32_t" {
+            return ((IExecutionContext*) internal)->inferShapes(max_num_names, names_ptrs_ptr);
+        });
+        if num_names < 0 {
+            return Err(last_error());
+        }
+        Ok(names_ptrs
+            .into_iter()
+            .take(num_names as usize)
+            .filter(|name_ptr| !name_ptr.is_null())
+            .map(|name_ptr| {
+                // SAFETY: The names are owned by the engine, which outlives this call, and TensorRT
+                // guarantees that they are valid C strings.
+                unsafe { std::ffi::CStr::from_ptr(name_ptr) }
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect())
+    }
+
+    /// Get the shape of a tensor in this execution context.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::tensor_shape()`].
+    pub fn tensor_shape(&self, tensor_name: &str) -> Dims {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let tensor_dimensions = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> RawDims as "Dims64" {
+            #if NV_TENSORRT_MAJOR >= 10
+            return ((const IExecutionContext*) internal)->getTensorShape(tensor_name_ptr);
+            #else
+            Dims32 dims32 = ((const IExecutionContext*) internal)->getTensorShape(tensor_name_ptr);
+            Dims64 dims64;
+            dims64.nbDims = dims32.nbDims;
+            for (int i = 0; i < dims32.nbDims; i++) {
+                dims64.d[i] = dims32.d[i];
+            }
+            return dims64;
+            #endif
+        });
+
+        let num_dims = tensor_dimensions.nbDims.max(0) as usize;
+        Dims::from(&tensor_dimensions.d[..num_dims])
+    }
+
+    /// Get the names and shapes of all IO tensors in this execution context.
+    pub fn io_tensor_shapes(&self) -> Vec<(String, Dims)> {
+        self.io_tensor_names()
+            .into_iter()
+            .map(|tensor_name| {
+                let shape = self.tensor_shape(&tensor_name);
+                (tensor_name, shape)
+            })
+            .collect()
+    }
+
+    /// Get the names and sizes in bytes of all IO tensors in this execution context.
+    pub fn io_tensor_sizes(&self) -> Result<Vec<(String, usize)>> {
+        self.io_tensor_names()
+            .into_iter()
+            .map(|tensor_name| {
+                if self.tensor_location(&tensor_name) != TensorLocation::Device {
+                    return Err(crate::error::Error::InvalidArgument(format!(
+                        "tensor {tensor_name} is not located on the device"
+                    )));
+                }
+                let num_elements =
+                    self.tensor_shape(&tensor_name)
+                        .num_elements()
+                        .ok_or_else(|| {
+                            crate::error::Error::InvalidArgument(format!(
+                                "tensor {tensor_name} has a dynamic shape that is not specified"
+                            ))
+                        })?;
+                let data_type = self.engine_tensor_data_type(&tensor_name).ok_or_else(|| {
+                    crate::error::Error::InvalidArgument(format!(
+                        "tensor {tensor_name} has a data type that is not supported"
+                    ))
+                })?;
+                Ok((tensor_name, data_type.num_bytes(num_elements)))
+            })
+            .collect()
+    }
+
+    /// Capture inference into a CUDA graph for low-overhead replay.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::capture_cuda_graph()`].
+    pub fn capture_cuda_graph<T: Copy>(
+        &mut self,
+        io_tensors: &mut std::collections::HashMap<
+            &str,
+            &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+        >,
+        stream: &async_cuda::ffi::stream::Stream,
+    ) -> Result<()> {
+        Device::set(self.device)?;
+        self.validate_io_tensors(io_tensors.keys().copied())?;
+        for (tensor_name, buffer) in io_tensors {
+            unsafe {
+                self.set_tensor_address(tensor_name, buffer)?;
+            }
+        }
+        // Warm up outside of the capture, since TensorRT performs lazy initialization on the first
+        // inference that cannot be captured.
+        self.enqueue_v3(stream)?;
+        stream.synchronize()?;
+        let internal = self.as_mut_ptr();
+        let graph_exec_ptr = &mut self.graph_exec as *mut *mut std::ffi::c_void;
+        let stream_ptr = stream.as_internal().as_ptr();
+        // `async_cuda` does not expose stream capture or CUDA graphs, so capture happens here,
+        // around the enqueue call.
+        let status = cpp!(unsafe [
+            internal as "void*",
+            graph_exec_ptr as "void**",
+            stream_ptr as "const void*"
+        ] -> i32 as "std::int32_t" {
+            cudaStream_t stream = (cudaStream_t) stream_ptr;
+            cudaError_t err = cudaStreamBeginCapture(stream, cudaStreamCaptureModeThreadLocal);
+            if (err != cudaSuccess) {
+                return (std::int32_t) err;
+            }
+            bool enqueued = ((IExecutionContext*) internal)->enqueueV3(stream);
+            cudaGraph_t graph = nullptr;
+            err = cudaStreamEndCapture(stream, &graph);
+            if (!enqueued) {
+                if (graph != nullptr) {
+                    cudaGraphDestroy(graph);
+                }
+                // Signal that the error originates from TensorRT instead of CUDA.
+                return -1;
+            }
+            if (err != cudaSuccess) {
+                return (std::int32_t) err;
+            }
+            cudaGraphExec_t graphExec = nullptr;
+            err = cudaGraphInstantiateWithFlags(&graphExec, graph, 0);
+            cudaGraphDestroy(graph);
+            if (err != cudaSuccess) {
+                return (std::int32_t) err;
+            }
+            if (*graph_exec_ptr != nullptr) {
+                cudaGraphExecDestroy((cudaGraphExec_t) *graph_exec_ptr);
+            }
+            *graph_exec_ptr = (void*) graphExec;
+            return 0;
+        });
+        match status {
+            0 => Ok(()),
+            -1 => Err(last_error()),
+            code => Err(cuda_error(code)),
+        }
+    }
+
+    /// Launch the CUDA graph previously captured with [`ExecutionContext::capture_cuda_graph`].
+    ///
+    /// # Safety
+    ///
+    /// The buffers that were bound when the graph was captured must still be alive.
+    pub unsafe fn launch_graph(&mut self, stream: &async_cuda::ffi::stream::Stream) -> Result<()> {
+        if self.graph_exec.is_null() {
+            return Err(crate::error::Error::InvalidArgument(
+                "no CUDA graph has been captured".to_string(),
+            ));
+        }
+        Device::set(self.device)?;
+        let graph_exec = self.graph_exec;
+        let stream_ptr = stream.as_internal().as_ptr();
+        let status = cpp!(unsafe [
+            graph_exec as "void*",
+            stream_ptr as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) cudaGraphLaunch(
+                (cudaGraphExec_t) graph_exec,
+                (cudaStream_t) stream_ptr
+            );
+        });
+        if status != 0 {
+            return Err(cuda_error(status));
+        }
+        self.record_stream_use(stream)
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let ExecutionContext { internal, .. } = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let ExecutionContext { internal, .. } = *self;
+        internal
+    }
+
+    /// Get the device of the engine that the execution context belongs to.
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.device
+    }
+
+    unsafe fn new_internal(engine: &mut Engine) -> *mut std::ffi::c_void {
+        Device::set_or_panic(engine.device());
+        let internal_engine = engine.as_mut_ptr();
+        let internal = cpp!(unsafe [
+            internal_engine as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) ((ICudaEngine*) internal_engine)->createExecutionContext();
+        });
+        internal
+    }
+
+    unsafe fn set_tensor_address<T: Copy>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &mut async_cuda::ffi::memory::DeviceBuffer<T>,
+    ) -> Result<()> {
+        self.check_tensor_location(tensor_name, TensorLocation::Device)?;
+        self.check_buffer_size(tensor_name, buffer.num_elements * std::mem::size_of::<T>())?;
+        let buffer_ptr = buffer.as_mut_internal().as_mut_ptr();
+        self.set_tensor_address_raw(tensor_name, buffer_ptr)
+    }
+
+    unsafe fn set_tensor_address_raw(
+        &mut self,
+        tensor_name: &str,
+        buffer_ptr: *mut std::ffi::c_void,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let success = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*",
+            buffer_ptr as "void*"
+        ] -> bool as "bool" {
+            return ((IExecutionContext*) internal)->setTensorAddress(
+                tensor_name_ptr,
+                buffer_ptr
+            );
+        });
+        if success {
+            debug_assert_eq!(
+                self.tensor_address(tensor_name),
+                buffer_ptr as *const std::ffi::c_void,
+                "tensor {tensor_name} is not bound to the address that was set",
+            );
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    unsafe fn set_input_tensor_address_raw(
+        &mut self,
+        tensor_name: &str,
+        buffer_ptr: *const std::ffi::c_void,
+    ) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*",
+            tensor_name_ptr as "const char*",
+            buffer_ptr as "const void*"
+        ] -> bool as "bool" {
+            return ((IExecutionContext*) internal)->setInputTensorAddress(
+                tensor_name_ptr,
+                buffer_ptr
+            );
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Check that `tensor_name` is an IO tensor with IO mode `io_mode`.
+    fn check_io_mode(&self, tensor_name: &str, io_mode: TensorIoMode) -> Result<()> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let actual_io_mode = TensorIoMode::from_i32(cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const IExecutionContext*) internal)
+                ->getEngine()
+                .getTensorIOMode(tensor_name_ptr);
+        }));
+        match actual_io_mode {
+            TensorIoMode::None => Err(crate::error::Error::UnknownTensor(tensor_name.to_string())),
+            actual_io_mode if actual_io_mode != io_mode => {
+                Err(crate::error::Error::InvalidArgument(format!(
+                    "tensor {tensor_name} is not an {} tensor",
+                    if io_mode == TensorIoMode::Input {
+                        "input"
+                    } else {
+                        "output"
+                    },
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that a buffer of `num_bytes` is large enough to hold the tensor with its current
+    /// shape, if buffer size validation is enabled and the shape is known.
+    fn check_buffer_size(&self, tensor_name: &str, num_bytes: usize) -> Result<()> {
+        if !self.validate_buffer_sizes {
+            return Ok(());
+        }
+        let Some(required) = self.tensor_num_bytes(tensor_name) else {
+            return Ok(());
+        };
+        if num_bytes < required {
+            return Err(crate::error::Error::BufferTooSmall {
+                tensor_name: tensor_name.to_string(),
+                required,
+                actual: num_bytes,
+            });
+        }
+        Ok(())
+    }
+
+    fn tensor_num_bytes(&self, tensor_name: &str) -> Option<usize> {
+        let num_elements = self.tensor_shape(tensor_name).num_elements()?;
+        self.engine_tensor_data_type(tensor_name)
+            .map(|data_type| data_type.num_bytes(num_elements))
+    }
+
+    fn engine_tensor_data_type(&self, tensor_name: &str) -> Option<DataType> {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let data_type = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const IExecutionContext*) internal)
+                ->getEngine()
+                .getTensorDataType(tensor_name_ptr);
+        });
+        DataType::from_i32(data_type)
+    }
+
+    /// Get the location of an IO tensor.
+    ///
+    /// Synchronous version of [`crate::ExecutionContext::tensor_location()`].
+    pub fn tensor_location(&self, tensor_name: &str) -> TensorLocation {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        let tensor_location = cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> i32 as "std::int32_t" {
+            return (std::int32_t) ((const IExecutionContext*) internal)
+                ->getEngine()
+                .getTensorLocation(tensor_name_ptr);
+        });
+        TensorLocation::from_i32(tensor_location)
+    }
+
+    /// Check that an IO tensor is located where the buffer that is bound to it lives.
+    fn check_tensor_location(&self, tensor_name: &str, location: TensorLocation) -> Result<()> {
+        let tensor_location = self.tensor_location(tensor_name);
+        if tensor_location == location {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(format!(
+                "tensor {tensor_name} is located on the {tensor_location} but a {location} buffer \
+                 was provided"
+            )))
+        }
+    }
+
+    /// Check that the shapes of all inputs are specified and that every IO tensor is bound or has
+    /// an output allocator, so that TensorRT does not fail halfway through enqueueing.
+    fn check_ready(&mut self) -> Result<()> {
+        let unspecified_shapes = self.infer_shapes()?;
+        let missing_bindings = self
+            .io_tensor_names()
+            .into_iter()
+            .filter(|io_tensor_name| {
+                !self.output_allocators.contains_key(io_tensor_name)
+                    && self.tensor_address(io_tensor_name).is_null()
+            })
+            .collect::<Vec<_>>();
+        if unspecified_shapes.is_empty() && missing_bindings.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::error::Error::NotReady {
+                unspecified_shapes,
+                missing_bindings,
+            })
+        }
+    }
+
+    /// Check that `tensor_names` are all IO tensors of the engine, and that every IO tensor that
+    /// is not in `tensor_names` is already bound or has an output allocator.
+    fn validate_io_tensors<'a>(&self, tensor_names: impl Iterator<Item = &'a str>) -> Result<()> {
+        let io_tensor_names = self.io_tensor_names();
+        let mut provided = std::collections::HashSet::new();
+        for tensor_name in tensor_names {
+            if !io_tensor_names.iter().any(|name| name == tensor_name) {
+                return Err(crate::error::Error::UnknownTensor(tensor_name.to_string()));
+            }
+            provided.insert(tensor_name);
+        }
+        for io_tensor_name in io_tensor_names {
+            if !provided.contains(io_tensor_name.as_str())
+                && !self.output_allocators.contains_key(&io_tensor_name)
+                && self.tensor_address(&io_tensor_name).is_null()
+            {
+                return Err(crate::error::Error::MissingBinding(io_tensor_name));
+            }
+        }
+        Ok(())
+    }
+
+    fn io_tensor_names(&self) -> Vec<String> {
+        let internal = self.as_ptr();
+        let num_io_tensors = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IExecutionContext*) internal)->getEngine().getNbIOTensors();
+        });
+        (0..num_io_tensors)
+            .map(|io_tensor_index| {
+                let io_tensor_name_ptr = cpp!(unsafe [
+                    internal as "const void*",
+                    io_tensor_index as "std::int32_t"
+                ] -> *const std::os::raw::c_char as "const char*" {
+                    return ((const IExecutionContext*) internal)
+                        ->getEngine()
+                        .getIOTensorName(io_tensor_index);
+                });
+                // SAFETY: The name is owned by the engine, which outlives this call, and it is
+                // copied before returning.
+                unsafe { std::ffi::CStr::from_ptr(io_tensor_name_ptr) }
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn tensor_address(&self, tensor_name: &str) -> *const std::ffi::c_void {
+        let internal = self.as_ptr();
+        let tensor_name_cstr = std::ffi::CString::new(tensor_name).unwrap();
+        let tensor_name_ptr = tensor_name_cstr.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*",
+            tensor_name_ptr as "const char*"
+        ] -> *const std::ffi::c_void as "const void*" {
+            return ((const IExecutionContext*) internal)->getTensorAddress(tensor_name_ptr);
+        })
+    }
+}
+
+impl<'engine> Drop for ExecutionContext<'engine> {
+    fn drop(&mut self) {
+        Device::set_or_panic(self.device);
+        let ExecutionContext {
+            internal,
+            graph_exec,
+            ..
+        } = *self;
+        cpp!(unsafe [
+            internal as "void*",
+            graph_exec as "void*"
+        ] {
+            if (graph_exec != nullptr) {
+                cudaGraphExecDestroy((cudaGraphExec_t) graph_exec);
+            }
             destroy((IExecutionContext*) internal);
         });
     }
 }
 
+/// Create error from CUDA error code.
+///
+/// # Arguments
+///
+/// * `code` - CUDA error code.
+#[inline]
+pub(crate) fn cuda_error(code: i32) -> crate::error::Error {
+    crate::error::Error::Cuda(async_cuda::Error::Cuda(code))
+}
+
+/// Host memory holding the values of a shape tensor input.
+enum ShapeInputValues {
+    /// Values of a shape tensor with 32-bit integer data type.
+    Int32(Vec<i32>),
+    /// Values of a shape tensor with 64-bit integer data type.
+    Int64(Vec<i64>),
+}
+
+impl ShapeInputValues {
+    /// Get pointer to the values.
+    fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        match self {
+            ShapeInputValues::Int32(values) => values.as_mut_ptr() as *mut std::ffi::c_void,
+            ShapeInputValues::Int64(values) => values.as_mut_ptr() as *mut std::ffi::c_void,
+        }
+    }
+}
+
+/// Whether or not the version of TensorRT in use supports all execution context allocation
+/// strategies.
+fn allocation_strategies_supported() -> bool {
+    cpp!(unsafe [] -> bool as "bool" {
+        #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 1)
+        return true;
+        #else
+        return false;
+        #endif
+    })
+}
+
+/// Create error for TensorRT versions that do not support debug tensors.
+fn debug_tensors_unsupported_error() -> crate::error::Error {
+    crate::error::Error::InvalidArgument(
+        "debug tensors not supported by this version of TensorRT".to_string(),
+    )
+}
+
 /// Tensor IO mode.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TensorIoMode {
@@ -344,11 +2325,232 @@ impl TensorIoMode {
     }
 }
 
+/// Location of a tensor.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TensorLocation {
+    /// Data stored on device.
+    Device,
+    /// Data stored on host.
+    Host,
+}
+
+impl std::fmt::Display for TensorLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TensorLocation::Device => write!(f, "device"),
+            TensorLocation::Host => write!(f, "host"),
+        }
+    }
+}
+
+impl TensorLocation {
+    /// Create [`TensorLocation`] from `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of tensor location.
+    pub(crate) fn from_i32(value: i32) -> Self {
+        match value {
+            1 => TensorLocation::Host,
+            _ => TensorLocation::Device,
+        }
+    }
+}
+
+/// Range of GPU architectures that an engine is compatible with.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum HardwareCompatibilityLevel {
+    /// Compatible only with the GPU architecture that built the engine.
+    #[default]
+    None,
+    /// Compatible with all GPU architectures from Ampere onwards.
+    AmperePlus,
+}
+
+impl HardwareCompatibilityLevel {
+    /// Create [`HardwareCompatibilityLevel`] from `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of hardware compatibility level.
+    pub(crate) fn from_i32(value: i32) -> Self {
+        match value {
+            1 => HardwareCompatibilityLevel::AmperePlus,
+            _ => HardwareCompatibilityLevel::None,
+        }
+    }
+}
+
+/// Strategy for allocating the device memory of an execution context.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ExecutionContextAllocationStrategy {
+    /// Allocate the maximum amount of memory required by any optimization profile when the
+    /// context is created.
+    #[default]
+    Static = 0,
+    /// Allocate the amount of memory required by the selected optimization profile, and
+    /// reallocate when the profile changes.
+    OnProfileChange = 1,
+    /// Do not allocate memory. The memory must be provided by the user.
+    UserManaged = 2,
+}
+
+/// Description of an IO tensor of an engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IoTensorDescriptor {
+    /// Name of tensor.
+    pub name: String,
+    /// Whether the tensor is an input or output.
+    pub io_mode: TensorIoMode,
+    /// Data type of tensor, or `None` if the data type is not known to this crate.
+    pub data_type: Option<DataType>,
+    /// Shape of tensor, where dynamic dimensions are `-1`.
+    pub shape: Dims,
+    /// Memory layout of tensor, or `None` if the format is not known to this crate.
+    pub format: Option<TensorFormat>,
+    /// Location of tensor.
+    pub location: TensorLocation,
+}
+
+/// Flags that control what is included when serializing an engine.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SerializationFlag {
+    /// Exclude the weights that can be refitted.
+    ExcludeWeights = 0,
+    /// Exclude the lean runtime from the plan.
+    ExcludeLeanRuntime = 1,
+}
+
+/// Configuration for serializing an engine with [`crate::Engine::serialize_with_config()`].
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_serialization_config.html)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SerializationConfig(u32);
+
+impl SerializationConfig {
+    /// Create a new [`SerializationConfig`] without any flags set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a serialization flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to set.
+    pub fn set_flag(&mut self, flag: SerializationFlag) {
+        self.0 |= 1 << flag as u32;
+    }
+
+    /// Set a serialization flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to set.
+    pub fn with_flag(mut self, flag: SerializationFlag) -> Self {
+        self.set_flag(flag);
+        self
+    }
+
+    /// Clear a serialization flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to clear.
+    pub fn clear_flag(&mut self, flag: SerializationFlag) {
+        self.0 &= !(1 << flag as u32);
+    }
+
+    /// Whether or not a serialization flag is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `flag` - Flag to check.
+    pub fn get_flag(&self, flag: SerializationFlag) -> bool {
+        self.0 & (1 << flag as u32) != 0
+    }
+
+    /// Get the flags as bitmask.
+    #[inline(always)]
+    pub(crate) fn flags(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Memory layout of a tensor.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TensorFormat {
+    /// Row-major linear format.
+    Linear,
+    /// Two-wide channel vectorized row-major format.
+    Chw2,
+    /// Eight-channel format where C is padded to a multiple of 8.
+    Hwc8,
+    /// Four-wide channel vectorized row-major format.
+    Chw4,
+    /// Sixteen-wide channel vectorized row-major format.
+    Chw16,
+    /// Thirty-two-wide channel vectorized row-major format.
+    Chw32,
+    /// Eight-channel format where C is padded to a multiple of 8, with depth.
+    Dhwc8,
+    /// Thirty-two-wide channel vectorized row-major format, with depth.
+    Cdhw32,
+    /// Non-vectorized channel-last format.
+    Hwc,
+    /// DLA planar format.
+    DlaLinear,
+    /// DLA image format.
+    DlaHwc4,
+    /// Sixteen-channel format where C is padded to a multiple of 16.
+    Hwc16,
+    /// Non-vectorized channel-last format, with depth.
+    Dhwc,
+}
+
+impl TensorFormat {
+    /// Create [`TensorFormat`] from `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of tensor format.
+    fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(TensorFormat::Linear),
+            1 => Some(TensorFormat::Chw2),
+            2 => Some(TensorFormat::Hwc8),
+            3 => Some(TensorFormat::Chw4),
+            4 => Some(TensorFormat::Chw16),
+            5 => Some(TensorFormat::Chw32),
+            6 => Some(TensorFormat::Dhwc8),
+            7 => Some(TensorFormat::Cdhw32),
+            8 => Some(TensorFormat::Hwc),
+            9 => Some(TensorFormat::DlaLinear),
+            10 => Some(TensorFormat::DlaHwc4),
+            11 => Some(TensorFormat::Hwc16),
+            12 => Some(TensorFormat::Dhwc),
+            _ => None,
+        }
+    }
+}
+
 /// Internal representation of the `Dims64` struct in TensorRT.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[allow(non_snake_case)]
-struct Dims {
+struct RawDims {
     pub nbDims: i32,
     pub d: [i64; 8usize],
 }
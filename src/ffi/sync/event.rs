@@ -0,0 +1,176 @@
+use cpp::cpp;
+
+use async_cuda::device::DeviceId;
+use async_cuda::ffi::device::Device;
+
+use crate::ffi::sync::engine::cuda_error;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Synchronous implementation of [`crate::Event`].
+///
+/// Refer to [`crate::Event`] for documentation.
+pub struct Event {
+    internal: *mut std::ffi::c_void,
+    device: DeviceId,
+}
+
+/// Implements [`Send`] for [`Event`].
+///
+/// # Safety
+///
+/// The CUDA API is thread-safe with regards to all operations on [`Event`].
+unsafe impl Send for Event {}
+
+/// Implements [`Sync`] for [`Event`].
+///
+/// # Safety
+///
+/// The CUDA API is thread-safe with regards to all operations on [`Event`].
+unsafe impl Sync for Event {}
+
+impl Event {
+    /// Create a new [`Event`] on the current device.
+    ///
+    /// Synchronous version of [`crate::Event::new()`].
+    pub fn new() -> Result<Self> {
+        Self::new_with_timing_enabled(false)
+    }
+
+    /// Create a new [`Event`] on the current device that records timing information.
+    ///
+    /// Synchronous version of [`crate::Event::new_with_timing()`].
+    pub fn new_with_timing() -> Result<Self> {
+        Self::new_with_timing_enabled(true)
+    }
+
+    fn new_with_timing_enabled(timing: bool) -> Result<Self> {
+        let device = Device::get_or_panic();
+        let mut internal = std::ptr::null_mut();
+        let internal_ptr = std::ptr::addr_of_mut!(internal);
+        let ret = cpp!(unsafe [
+            internal_ptr as "void**",
+            timing as "bool"
+        ] -> i32 as "std::int32_t" {
+            return cudaEventCreateWithFlags(
+                (cudaEvent_t*) internal_ptr,
+                timing ? cudaEventDefault : cudaEventDisableTiming
+            );
+        });
+        if ret == 0 {
+            Ok(Self { internal, device })
+        } else {
+            Err(cuda_error(ret))
+        }
+    }
+
+    /// Check whether all work captured by the event has completed, without waiting.
+    ///
+    /// Synchronous version of [`crate::Event::query()`].
+    pub fn query(&self) -> Result<bool> {
+        Device::set(self.device)?;
+        let internal = self.internal;
+        let ret = cpp!(unsafe [
+            internal as "void*"
+        ] -> i32 as "std::int32_t" {
+            return cudaEventQuery((cudaEvent_t) internal);
+        });
+        match ret {
+            0 => Ok(true),
+            // `cudaErrorNotReady`
+            600 => Ok(false),
+            _ => Err(cuda_error(ret)),
+        }
+    }
+
+    /// Capture the work that is currently queued on a stream.
+    ///
+    /// Synchronous version of [`crate::Event::record()`].
+    pub fn record(&self, stream: &async_cuda::ffi::stream::Stream) -> Result<()> {
+        Device::set(self.device)?;
+        let internal = self.internal;
+        let stream_ptr = stream.as_internal().as_ptr();
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            stream_ptr as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return cudaEventRecord((cudaEvent_t) internal, (cudaStream_t) stream_ptr);
+        });
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(cuda_error(ret))
+        }
+    }
+
+    /// Get the time elapsed between this event and a later event, in milliseconds.
+    ///
+    /// Synchronous version of [`crate::Event::elapsed_time()`].
+    pub fn elapsed_time(&self, end: &Event) -> Result<f32> {
+        Device::set(self.device)?;
+        let internal = self.internal;
+        let end_internal = end.internal;
+        let mut milliseconds = 0.0_f32;
+        let milliseconds_ptr = std::ptr::addr_of_mut!(milliseconds);
+        let ret = cpp!(unsafe [
+            internal as "void*",
+            end_internal as "void*",
+            milliseconds_ptr as "float*"
+        ] -> i32 as "std::int32_t" {
+            return cudaEventElapsedTime(milliseconds_ptr, (cudaEvent_t) internal, (cudaEvent_t) end_internal);
+        });
+        if ret == 0 {
+            Ok(milliseconds)
+        } else {
+            Err(cuda_error(ret))
+        }
+    }
+
+    /// Wait until all work captured by the event has completed.
+    ///
+    /// Synchronous version of [`crate::Event::synchronize()`].
+    pub fn synchronize(&self) -> Result<()> {
+        Device::set(self.device)?;
+        let internal = self.internal;
+        let ret = cpp!(unsafe [
+            internal as "void*"
+        ] -> i32 as "std::int32_t" {
+            return cudaEventSynchronize((cudaEvent_t) internal);
+        });
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(cuda_error(ret))
+        }
+    }
+
+    /// Get the device that the event was created on.
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.device
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        self.internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        self.internal
+    }
+}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        Device::set_or_panic(self.device);
+        let Event { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            cudaEventDestroy((cudaEvent_t) internal);
+        });
+    }
+}
@@ -1,3 +1,7 @@
+#[cfg(not(feature = "dispatch"))]
 pub mod builder;
 pub mod engine;
+pub mod event;
+#[cfg(not(feature = "dispatch"))]
+pub mod refitter;
 pub mod runtime;
@@ -0,0 +1,194 @@
+use cpp::cpp;
+
+use async_cuda::device::DeviceId;
+use async_cuda::ffi::device::Device;
+
+use crate::element::TensorElement;
+use crate::error::last_error;
+use crate::ffi::data_type::DataType;
+use crate::ffi::layer::Weights;
+use crate::ffi::result;
+use crate::ffi::sync::engine::Engine;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Synchronous implementation of [`crate::Refitter`].
+///
+/// Refer to [`crate::Refitter`] for documentation.
+pub struct Refitter<'engine> {
+    internal: *mut std::ffi::c_void,
+    device: DeviceId,
+    weights: Vec<Weights>,
+    _phantom: std::marker::PhantomData<&'engine ()>,
+}
+
+/// Implements [`Send`] for [`Refitter`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`Refitter`].
+unsafe impl<'engine> Send for Refitter<'engine> {}
+
+/// Implements [`Sync`] for [`Refitter`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`Refitter`].
+unsafe impl<'engine> Sync for Refitter<'engine> {}
+
+impl<'engine> Refitter<'engine> {
+    /// Create a new [`Refitter`] for an [`Engine`].
+    ///
+    /// Synchronous version of [`crate::Refitter::new()`].
+    pub fn new(engine: &'engine mut Engine) -> Result<Self> {
+        let device = engine.device();
+        Device::set(device)?;
+        let internal_engine = engine.as_mut_ptr();
+        let internal = cpp!(unsafe [
+            internal_engine as "void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #ifndef ODDITY_FFI_DISPATCH
+            IRefitter* refitter = createInferRefitter(*((ICudaEngine*) internal_engine), GLOBAL_LOGGER);
+            if (refitter != nullptr) {
+                refitter->setErrorRecorder(&GLOBAL_ERROR_RECORDER);
+            }
+            return refitter;
+            #else
+            return nullptr;
+            #endif
+        });
+        result!(
+            internal,
+            Refitter {
+                internal,
+                device,
+                weights: Vec::new(),
+                _phantom: Default::default(),
+            }
+        )
+    }
+
+    /// Specify new weights of given name.
+    ///
+    /// Synchronous version of [`crate::Refitter::set_named_weights()`].
+    pub fn set_named_weights<T: TensorElement>(
+        &mut self,
+        name: &str,
+        data_type: DataType,
+        values: &[T],
+    ) -> Result<()> {
+        // Creating the weights validates the element size against the data type.
+        let weights = Weights::new(data_type, values)?;
+        let internal = self.as_mut_ptr();
+        let name_cstr = std::ffi::CString::new(name).unwrap();
+        let name_ptr = name_cstr.as_ptr();
+        let values_ptr = weights.values_ptr();
+        let count = weights.count();
+        let data_type = data_type as i32;
+        let success = cpp!(unsafe [
+            internal as "void*",
+            name_ptr as "const char*",
+            data_type as "std::int32_t",
+            values_ptr as "const void*",
+            count as "std::int64_t"
+        ] -> bool as "bool" {
+            Weights weights;
+            weights.type = static_cast<DataType>(data_type);
+            weights.values = values_ptr;
+            weights.count = count;
+            return ((IRefitter*) internal)->setNamedWeights(name_ptr, weights);
+        });
+        if success {
+            // TensorRT requires the weights memory to remain valid until the engine has been
+            // refitted, so we keep the weights around for as long as the refitter lives.
+            self.weights.push(weights);
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Get names of weights that still need to be set before the engine can be refitted.
+    ///
+    /// Synchronous version of [`crate::Refitter::get_missing_weights()`].
+    pub fn get_missing_weights(&self) -> Vec<String> {
+        let internal = self.as_ptr();
+        let num_missing = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((IRefitter*) internal)->getMissingWeights(0, nullptr);
+        });
+        if num_missing <= 0 {
+            return Vec::new();
+        }
+        let mut names = vec![std::ptr::null::<std::os::raw::c_char>(); num_missing as usize];
+        let names_ptr = names.as_mut_ptr();
+        let num_missing = cpp!(unsafe [
+            internal as "const void*",
+            num_missing as "std::int32_t",
+            names_ptr as "const char**"
+        ] -> i32 as "std::int32_t" {
+            return ((IRefitter*) internal)->getMissingWeights(num_missing, names_ptr);
+        });
+        names
+            .into_iter()
+            .take(num_missing.max(0) as usize)
+            .map(|name| {
+                // SAFETY: This is safe because:
+                // * The pointer is valid because we just got it from TensorRT.
+                // * The pointer isn't kept after this block (we copy the string instead).
+                unsafe { std::ffi::CStr::from_ptr(name).to_string_lossy().to_string() }
+            })
+            .collect()
+    }
+
+    /// Update the engine with the new weights.
+    ///
+    /// Synchronous version of [`crate::Refitter::refit_cuda_engine()`].
+    pub fn refit_cuda_engine(&mut self) -> Result<()> {
+        Device::set(self.device)?;
+        let internal = self.as_mut_ptr();
+        let success = cpp!(unsafe [
+            internal as "void*"
+        ] -> bool as "bool" {
+            return ((IRefitter*) internal)->refitCudaEngine();
+        });
+        if success {
+            Ok(())
+        } else {
+            Err(last_error())
+        }
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let Refitter { internal, .. } = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let Refitter { internal, .. } = *self;
+        internal
+    }
+
+    /// Get the device of the engine that is refitted.
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.device
+    }
+}
+
+impl<'engine> Drop for Refitter<'engine> {
+    fn drop(&mut self) {
+        Device::set_or_panic(self.device);
+        let Refitter { internal, .. } = *self;
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            destroy((IRefitter*) internal);
+        });
+    }
+}
@@ -3,18 +3,30 @@ use cpp::cpp;
 use async_cuda::device::DeviceId;
 use async_cuda::ffi::device::Device;
 
+use crate::ffi::gpu_allocator::{GpuAllocator, GpuAllocatorBridge};
 use crate::ffi::memory::HostBuffer;
+#[cfg(not(feature = "dispatch"))]
+use crate::ffi::plugin::PluginCreatorInfo;
 use crate::ffi::result;
+use crate::ffi::stream_reader::with_stream_reader;
 use crate::ffi::sync::engine::Engine;
 
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
+cpp! {{
+    #ifndef _WIN32
+    #include <sys/mman.h>
+    #endif
+}}
+
 /// Synchronous implementation of [`crate::Runtime`].
 ///
 /// Refer to [`crate::Runtime`] for documentation.
 pub struct Runtime {
     addr: *mut std::ffi::c_void,
     device: DeviceId,
+    gpu_allocator: Option<GpuAllocatorBridge>,
+    temporary_directory: Option<std::ffi::CString>,
 }
 
 /// Implements [`Send`] for [`Runtime`].
@@ -32,14 +44,29 @@ unsafe impl Send for Runtime {}
 unsafe impl Sync for Runtime {}
 
 impl Runtime {
+    /// Create a new [`Runtime`].
+    ///
+    /// Synchronous version of [`crate::Runtime::new()`].
     pub fn new() -> Self {
         let device = Device::get_or_panic();
         let addr = cpp!(unsafe [] -> *mut std::ffi::c_void as "void*" {
-            return createInferRuntime(GLOBAL_LOGGER);
+            IRuntime* runtime = createInferRuntime(GLOBAL_LOGGER);
+            if (runtime != nullptr) {
+                runtime->setErrorRecorder(&GLOBAL_ERROR_RECORDER);
+            }
+            return runtime;
         });
-        Runtime { addr, device }
+        Runtime {
+            addr,
+            device,
+            gpu_allocator: None,
+            temporary_directory: None,
+        }
     }
 
+    /// Deserialize an engine from a plan.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine_from_plan()`].
     pub fn deserialize_engine_from_plan(self, plan: &HostBuffer) -> Result<Engine> {
         unsafe {
             // SAFETY: Since we have a reference to the buffer for the duration of this call, we
@@ -48,6 +75,9 @@ impl Runtime {
         }
     }
 
+    /// Deserialize an engine from a buffer.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine()`].
     pub fn deserialize_engine(self, buffer: &[u8]) -> Result<Engine> {
         unsafe {
             // SAFETY: Since we have a reference to the slice for the duration of this call, we
@@ -56,6 +86,115 @@ impl Runtime {
         }
     }
 
+    /// Deserialize an engine from a buffer on a specific device.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine_on_device()`].
+    pub fn deserialize_engine_on_device(
+        mut self,
+        buffer: &[u8],
+        device: DeviceId,
+    ) -> Result<Engine> {
+        // Only adopt the device once it is known to be valid, since dropping the runtime sets its
+        // device and panics on failure.
+        Device::set(device)?;
+        self.device = device;
+        self.deserialize_engine(buffer)
+    }
+
+    /// Deserialize an engine from a plan file.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine_from_file()`].
+    #[cfg(unix)]
+    pub fn deserialize_engine_from_file(self, path: impl AsRef<std::path::Path>) -> Result<Engine> {
+        use std::os::unix::io::AsRawFd;
+        let file = std::fs::File::open(path)?;
+        let size = file.metadata()?.len() as usize;
+        if size == 0 {
+            return Err(crate::error::Error::InvalidArgument(
+                "plan file is empty".to_string(),
+            ));
+        }
+        let fd = file.as_raw_fd();
+        let mapped = cpp!(unsafe [
+            fd as "int",
+            size as "std::size_t"
+        ] -> *mut std::ffi::c_void as "void*" {
+            #ifndef _WIN32
+            void* mapped = mmap(nullptr, size, PROT_READ, MAP_PRIVATE, fd, 0);
+            return mapped != MAP_FAILED ? mapped : nullptr;
+            #else
+            return nullptr;
+            #endif
+        });
+        if mapped.is_null() {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let engine = unsafe {
+            // SAFETY: The mapping is valid until it is unmapped below, and TensorRT does not hold
+            // on to the buffer after deserialization.
+            self.deserialize_engine_raw(mapped, size)
+        };
+        cpp!(unsafe [
+            mapped as "void*",
+            size as "std::size_t"
+        ] {
+            #ifndef _WIN32
+            munmap(mapped, size);
+            #endif
+        });
+        engine
+    }
+
+    /// Deserialize an engine from a plan file.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine_from_file()`].
+    #[cfg(not(unix))]
+    pub fn deserialize_engine_from_file(self, path: impl AsRef<std::path::Path>) -> Result<Engine> {
+        let buffer = std::fs::read(path)?;
+        self.deserialize_engine(&buffer)
+    }
+
+    /// Deserialize an engine from a reader.
+    ///
+    /// Synchronous version of [`crate::Runtime::deserialize_engine_from_reader()`].
+    pub fn deserialize_engine_from_reader(
+        mut self,
+        mut reader: impl std::io::Read + std::io::Seek,
+    ) -> Result<Engine> {
+        Device::set(self.device)?;
+        let internal = self.as_mut_ptr();
+        let internal_engine = with_stream_reader(&mut reader, |stream_reader| {
+            cpp!(unsafe [
+                internal as "void*",
+                stream_reader as "void*"
+            ] -> *mut std::ffi::c_void as "void*" {
+                #if NV_TENSORRT_MAJOR > 10 || (NV_TENSORRT_MAJOR == 10 && NV_TENSORRT_MINOR >= 7)
+                return ((IRuntime*) internal)->deserializeCudaEngine(
+                    *((IStreamReaderV2*) stream_reader)
+                );
+                #elif NV_TENSORRT_MAJOR >= 10
+                #pragma GCC diagnostic push
+                #pragma GCC diagnostic ignored "-Wdeprecated-declarations"
+                ICudaEngine* engine = ((IRuntime*) internal)->deserializeCudaEngine(
+                    *((IStreamReader*) stream_reader)
+                );
+                #pragma GCC diagnostic pop
+                return engine;
+                #else
+                return nullptr;
+                #endif
+            })
+        });
+        if internal_engine.is_null() && stream_reader_unsupported() {
+            // Streaming is not available, fall back to reading the entire plan into memory.
+            use std::io::Read;
+            let mut buffer = Vec::new();
+            reader.read_to_end(&mut buffer)?;
+            return self.deserialize_engine(&buffer);
+        }
+        result!(internal_engine, Engine::wrap(internal_engine, self))
+    }
+
     /// Deserialize an engine from a buffer.
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html#ad0dc765e77cab99bfad901e47216a767)
@@ -85,22 +224,356 @@ impl Runtime {
         result!(internal_engine, Engine::wrap(internal_engine, self))
     }
 
+    /// Set the allocator for all GPU memory allocated by the runtime and its engines.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_gpu_allocator()`].
+    pub fn set_gpu_allocator(&mut self, allocator: Box<dyn GpuAllocator>) {
+        let internal = self.as_mut_ptr();
+        let mut bridge = GpuAllocatorBridge::new(allocator);
+        let bridge_ptr = bridge.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            bridge_ptr as "void*"
+        ] {
+            ((IRuntime*) internal)->setGpuAllocator((IGpuAllocator*) bridge_ptr);
+        });
+        self.gpu_allocator = Some(bridge);
+    }
+
+    /// Load plugins from a shared library so that engines that use them can be deserialized.
+    ///
+    /// Synchronous version of [`crate::Runtime::load_library()`].
+    #[cfg(not(feature = "dispatch"))]
+    pub fn load_library(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        crate::ffi::plugin::load_library(path)
+    }
+
+    /// Get all plugin creators that are registered in the plugin registry.
+    ///
+    /// Synchronous version of [`crate::Runtime::plugin_creators()`].
+    #[cfg(not(feature = "dispatch"))]
+    pub fn plugin_creators(&self) -> Vec<PluginCreatorInfo> {
+        crate::ffi::plugin::plugin_creators()
+    }
+
+    /// Set the DLA core that deserialized engines must execute on.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_dla_core()`].
+    pub fn set_dla_core(&mut self, dla_core: i32) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*",
+            dla_core as "std::int32_t"
+        ] {
+            ((IRuntime*) internal)->setDLACore(dla_core);
+        });
+    }
+
+    /// Get the DLA core that deserialized engines execute on.
+    ///
+    /// Synchronous version of [`crate::Runtime::dla_core()`].
+    pub fn dla_core(&self) -> i32 {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IRuntime*) internal)->getDLACore();
+        })
+    }
+
+    /// Set the maximum number of threads that the runtime may use.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_max_threads()`].
+    pub fn set_max_threads(&mut self, max_threads: usize) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let max_threads = max_threads.min(i32::MAX as usize) as i32;
+        let ok = cpp!(unsafe [
+            internal as "void*",
+            max_threads as "std::int32_t"
+        ] -> bool as "bool" {
+            return ((IRuntime*) internal)->setMaxThreads(max_threads);
+        });
+        if ok {
+            Ok(())
+        } else {
+            Err(crate::error::last_error())
+        }
+    }
+
+    /// Get the maximum number of threads that the runtime may use.
+    ///
+    /// Synchronous version of [`crate::Runtime::max_threads()`].
+    pub fn max_threads(&self) -> usize {
+        let internal = self.as_ptr();
+        let max_threads = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IRuntime*) internal)->getMaxThreads();
+        });
+        max_threads.max(0) as usize
+    }
+
+    /// Get the number of DLA cores available to the runtime.
+    ///
+    /// Synchronous version of [`crate::Runtime::num_dla_cores()`].
+    pub fn num_dla_cores(&self) -> usize {
+        let internal = self.as_ptr();
+        let num_dla_cores = cpp!(unsafe [
+            internal as "const void*"
+        ] -> i32 as "std::int32_t" {
+            return ((const IRuntime*) internal)->getNbDLACores();
+        });
+        num_dla_cores.max(0) as usize
+    }
+
+    /// Set whether or not the runtime may execute host code that is serialized in engines.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_engine_host_code_allowed()`].
+    pub fn set_engine_host_code_allowed(&mut self, allowed: bool) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            allowed as "bool"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IRuntime*) internal)->setEngineHostCodeAllowed(allowed);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "engine host code not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Whether or not the runtime may execute host code that is serialized in engines.
+    ///
+    /// Synchronous version of [`crate::Runtime::engine_host_code_allowed()`].
+    pub fn engine_host_code_allowed(&self) -> bool {
+        let internal = self.as_ptr();
+        cpp!(unsafe [
+            internal as "const void*"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IRuntime*) internal)->getEngineHostCodeAllowed();
+            #else
+            return false;
+            #endif
+        })
+    }
+
+    /// Set the directory that the runtime creates temporary files in.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_temporary_directory()`].
+    pub fn set_temporary_directory(&mut self, path: Option<&std::path::Path>) -> Result<()> {
+        let path = path
+            .map(|path| {
+                path.to_str()
+                    .and_then(|path| std::ffi::CString::new(path).ok())
+                    .ok_or_else(|| {
+                        crate::error::Error::InvalidArgument(format!(
+                            "invalid path: {}",
+                            path.display()
+                        ))
+                    })
+            })
+            .transpose()?;
+        let internal = self.as_mut_ptr();
+        let path_ptr = path
+            .as_ref()
+            .map(|path| path.as_ptr())
+            .unwrap_or(std::ptr::null());
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            path_ptr as "const char*"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IRuntime*) internal)->setTemporaryDirectory(path_ptr);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            // The runtime may refer to the string for as long as it is alive, so we keep it.
+            self.temporary_directory = path;
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "temporary directory not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Get the directory that the runtime creates temporary files in.
+    ///
+    /// Synchronous version of [`crate::Runtime::temporary_directory()`].
+    pub fn temporary_directory(&self) -> Option<std::path::PathBuf> {
+        let internal = self.as_ptr();
+        let path_ptr = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *const std::os::raw::c_char as "const char*" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IRuntime*) internal)->getTemporaryDirectory();
+            #else
+            return nullptr;
+            #endif
+        });
+        if path_ptr.is_null() {
+            None
+        } else {
+            // SAFETY: The string is owned by the runtime, which outlives this call, and it is
+            // copied before returning.
+            let path = unsafe { std::ffi::CStr::from_ptr(path_ptr) };
+            Some(std::path::PathBuf::from(
+                path.to_string_lossy().into_owned(),
+            ))
+        }
+    }
+
+    /// Set how the runtime may create temporary files.
+    ///
+    /// Synchronous version of [`crate::Runtime::set_tempfile_control_flags()`].
+    pub fn set_tempfile_control_flags(&mut self, flags: TempfileControlFlags) -> Result<()> {
+        let internal = self.as_mut_ptr();
+        let flags = flags.bits();
+        let supported = cpp!(unsafe [
+            internal as "void*",
+            flags as "std::uint32_t"
+        ] -> bool as "bool" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            ((IRuntime*) internal)->setTempfileControlFlags(flags);
+            return true;
+            #else
+            return false;
+            #endif
+        });
+        if supported {
+            Ok(())
+        } else {
+            Err(crate::error::Error::InvalidArgument(
+                "tempfile control flags not supported by this version of TensorRT".to_string(),
+            ))
+        }
+    }
+
+    /// Get how the runtime may create temporary files.
+    ///
+    /// Synchronous version of [`crate::Runtime::tempfile_control_flags()`].
+    pub fn tempfile_control_flags(&self) -> TempfileControlFlags {
+        let internal = self.as_ptr();
+        let flags = cpp!(unsafe [
+            internal as "const void*"
+        ] -> u32 as "std::uint32_t" {
+            #if NV_TENSORRT_MAJOR > 8 || (NV_TENSORRT_MAJOR == 8 && NV_TENSORRT_MINOR >= 6)
+            return ((const IRuntime*) internal)->getTempfileControlFlags();
+            #else
+            return 0;
+            #endif
+        });
+        TempfileControlFlags::from_bits(flags)
+    }
+
+    /// Get internal readonly pointer.
     #[inline(always)]
     pub fn as_ptr(&self) -> *const std::ffi::c_void {
         self.addr
     }
 
+    /// Get internal mutable pointer.
     #[inline(always)]
     pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
         self.addr
     }
 
+    /// Get the device that the runtime was created on.
+    ///
+    /// Synchronous version of [`crate::Runtime::device()`].
     #[inline(always)]
     pub fn device(&self) -> DeviceId {
         self.device
     }
 }
 
+/// Set of flags that control how TensorRT may create temporary files, for example for the host
+/// code of version compatible engines.
+///
+/// Flags can be combined with `|`. By default, both in-memory and temporary files are allowed.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1.html)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TempfileControlFlags(u32);
+
+impl TempfileControlFlags {
+    /// Allow creating and loading files in memory (or on a memory backed filesystem).
+    pub const ALLOW_IN_MEMORY_FILES: TempfileControlFlags = TempfileControlFlags(1 << 0);
+    /// Allow creating and loading files in the temporary directory of the runtime.
+    pub const ALLOW_TEMPORARY_FILES: TempfileControlFlags = TempfileControlFlags(1 << 1);
+
+    /// No flags, which disallows all temporary files.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        TempfileControlFlags(0)
+    }
+
+    /// Create flags from raw bits as used by TensorRT.
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - Bitmask with bit `1 << TempfileControlFlag` set for each enabled flag.
+    #[inline(always)]
+    pub const fn from_bits(bits: u32) -> Self {
+        TempfileControlFlags(bits)
+    }
+
+    /// Get raw bits as used by TensorRT.
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Whether or not all flags in `other` are contained in this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Flags to check.
+    #[inline(always)]
+    pub const fn contains(&self, other: TempfileControlFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for TempfileControlFlags {
+    fn default() -> Self {
+        TempfileControlFlags::ALLOW_IN_MEMORY_FILES | TempfileControlFlags::ALLOW_TEMPORARY_FILES
+    }
+}
+
+impl std::ops::BitOr for TempfileControlFlags {
+    type Output = TempfileControlFlags;
+
+    #[inline(always)]
+    fn bitor(self, rhs: TempfileControlFlags) -> TempfileControlFlags {
+        TempfileControlFlags(self.0 | rhs.0)
+    }
+}
+
+/// Whether or not the version of TensorRT in use does not support deserializing from a stream.
+fn stream_reader_unsupported() -> bool {
+    cpp!(unsafe [] -> bool as "bool" {
+        #if NV_TENSORRT_MAJOR >= 10
+        return false;
+        #else
+        return true;
+        #endif
+    })
+}
+
 impl Drop for Runtime {
     fn drop(&mut self) {
         Device::set_or_panic(self.device);
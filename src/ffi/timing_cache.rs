@@ -0,0 +1,103 @@
+use cpp::cpp;
+
+use crate::ffi::memory::HostBuffer;
+use crate::ffi::result;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Cache of kernel timings collected by the builder.
+///
+/// A timing cache can be serialized and later loaded again to speed up subsequent builds.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_timing_cache.html)
+pub struct TimingCache(*mut std::ffi::c_void);
+
+/// Implements [`Send`] for [`TimingCache`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`TimingCache`].
+unsafe impl Send for TimingCache {}
+
+/// Implements [`Sync`] for [`TimingCache`].
+///
+/// # Safety
+///
+/// The TensorRT API is thread-safe with regards to all operations on [`TimingCache`].
+unsafe impl Sync for TimingCache {}
+
+impl TimingCache {
+    /// Wrap internal pointer as [`TimingCache`].
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid `ITimingCache` object.
+    #[inline]
+    pub(crate) fn wrap(internal: *mut std::ffi::c_void) -> Self {
+        TimingCache(internal)
+    }
+
+    /// Serialize the timing cache.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_timing_cache.html)
+    ///
+    /// # Return value
+    ///
+    /// A [`HostBuffer`] that contains the serialized timing cache.
+    pub fn serialize(&self) -> Result<HostBuffer> {
+        let internal = self.as_ptr();
+        let internal_buffer = cpp!(unsafe [
+            internal as "const void*"
+        ] -> *mut std::ffi::c_void as "void*" {
+            return (void*) ((const ITimingCache*) internal)->serialize();
+        });
+        result!(internal_buffer, HostBuffer::wrap(internal_buffer))
+    }
+
+    /// Get internal readonly pointer.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const std::ffi::c_void {
+        let TimingCache(internal) = *self;
+        internal
+    }
+
+    /// Get internal mutable pointer.
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut std::ffi::c_void {
+        let TimingCache(internal) = *self;
+        internal
+    }
+}
+
+impl Drop for TimingCache {
+    fn drop(&mut self) {
+        let internal = self.as_mut_ptr();
+        cpp!(unsafe [
+            internal as "void*"
+        ] {
+            destroy((ITimingCache*) internal);
+        });
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use crate::Builder;
+
+    #[tokio::test]
+    async fn test_timing_cache_serialize_and_load() {
+        let mut builder = Builder::new().await.unwrap();
+        let config = builder.config().await;
+        let timing_cache = config.create_timing_cache(&[]).unwrap();
+        let serialized_timing_cache = timing_cache.serialize().unwrap();
+        assert!(serialized_timing_cache.size() > 0);
+        let mut config = builder.config().await;
+        let timing_cache = config
+            .create_timing_cache(serialized_timing_cache.as_bytes())
+            .unwrap();
+        assert!(config
+            .set_timing_cache(std::sync::Arc::new(timing_cache), false)
+            .is_ok());
+        assert!(config.get_timing_cache().is_some());
+    }
+}
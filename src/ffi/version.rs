@@ -0,0 +1,124 @@
+use cpp::cpp;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// TensorRT version.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// Major version.
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Patch version.
+    pub patch: u32,
+}
+
+impl Version {
+    /// Create [`Version`] from the integer representation that TensorRT uses.
+    ///
+    /// TensorRT 10 and up encode the version as `major * 10000 + minor * 100 + patch`, older
+    /// versions as `major * 1000 + minor * 100 + patch`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Integer representation of version.
+    pub(crate) fn from_i32(value: i32) -> Self {
+        let value = value.max(0) as u32;
+        if value >= 10000 {
+            Version {
+                major: value / 10000,
+                minor: (value / 100) % 100,
+                patch: value % 100,
+            }
+        } else {
+            Version {
+                major: value / 1000,
+                minor: (value / 100) % 10,
+                patch: value % 100,
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Get the version of the TensorRT headers that this crate was compiled against.
+pub fn tensorrt_version() -> Version {
+    let major = cpp!(unsafe [] -> u32 as "std::uint32_t" {
+        return NV_TENSORRT_MAJOR;
+    });
+    let minor = cpp!(unsafe [] -> u32 as "std::uint32_t" {
+        return NV_TENSORRT_MINOR;
+    });
+    let patch = cpp!(unsafe [] -> u32 as "std::uint32_t" {
+        return NV_TENSORRT_PATCH;
+    });
+    Version {
+        major,
+        minor,
+        patch,
+    }
+}
+
+/// Get the version of the TensorRT library that is loaded at runtime.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/_nv_infer_runtime_8h.html)
+pub fn runtime_tensorrt_version() -> Version {
+    let version = cpp!(unsafe [] -> i32 as "std::int32_t" {
+        return getInferLibVersion();
+    });
+    Version::from_i32(version)
+}
+
+/// Check that the TensorRT library that is loaded at runtime matches the headers this crate was
+/// compiled against.
+///
+/// The major and minor versions must be equal. Using a library that differs from the headers
+/// results in undefined behavior, since the layout of TensorRT classes changes between versions.
+///
+/// # Return value
+///
+/// [`crate::Error::VersionMismatch`] if the versions are not compatible.
+pub fn assert_version_compatible() -> Result<()> {
+    let compiled = tensorrt_version();
+    let runtime = runtime_tensorrt_version();
+    if compiled.major == runtime.major && compiled.minor == runtime.minor {
+        Ok(())
+    } else {
+        Err(crate::error::Error::VersionMismatch { compiled, runtime })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_from_i32() {
+        assert_eq!(
+            Version::from_i32(8601),
+            Version {
+                major: 8,
+                minor: 6,
+                patch: 1
+            }
+        );
+        assert_eq!(
+            Version::from_i32(100300),
+            Version {
+                major: 10,
+                minor: 3,
+                patch: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_assert_version_compatible() {
+        assert!(assert_version_compatible().is_ok());
+    }
+}
@@ -0,0 +1,165 @@
+use async_cuda::DeviceBuffer;
+
+use crate::element::TensorElement;
+use crate::ffi::data_type::DataType;
+use crate::ffi::sync::engine::TensorIoMode;
+
+/// Buffers bound to the IO tensors of an engine, for use with
+/// [`crate::ExecutionContext::enqueue_bound()`].
+///
+/// Unlike [`crate::ExecutionContext::enqueue()`], which requires all buffers to have the same
+/// element type, each tensor is bound with its own element type. This allows executing engines
+/// with mixed precision IO, for example half precision inputs and single precision outputs.
+///
+/// Inputs are bound through shared references and outputs through mutable references, so input
+/// buffers can be shared with other bindings at the same time.
+pub struct IoBindings<'a> {
+    bindings: Vec<Binding>,
+    _buffers: std::marker::PhantomData<&'a mut ()>,
+}
+
+/// Buffer bound to a single IO tensor.
+pub(crate) struct Binding {
+    pub(crate) tensor_name: String,
+    pub(crate) io_mode: TensorIoMode,
+    pub(crate) data_type: DataType,
+    pub(crate) buffer_ptr: *mut std::ffi::c_void,
+    pub(crate) num_bytes: usize,
+}
+
+/// Implements [`Send`] for [`IoBindings`].
+///
+/// # Safety
+///
+/// The bindings only hold the device addresses of buffers that are borrowed for `'a`, and device
+/// buffers can be used from any thread.
+unsafe impl Send for IoBindings<'_> {}
+
+/// Implements [`Sync`] for [`IoBindings`].
+///
+/// # Safety
+///
+/// The bindings only hold the device addresses of buffers that are borrowed for `'a`, and device
+/// buffers can be used from any thread.
+unsafe impl Sync for IoBindings<'_> {}
+
+impl<'a> IoBindings<'a> {
+    /// Create empty [`IoBindings`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            _buffers: std::marker::PhantomData,
+        }
+    }
+
+    /// Bind a buffer to an input tensor.
+    ///
+    /// This replaces any buffer previously bound to the same tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of input tensor.
+    /// * `buffer` - Buffer to bind.
+    pub fn set_input<T: TensorElement>(&mut self, tensor_name: &str, buffer: &'a DeviceBuffer<T>) {
+        self.insert(Binding {
+            tensor_name: tensor_name.to_string(),
+            io_mode: TensorIoMode::Input,
+            data_type: T::DATA_TYPE,
+            buffer_ptr: buffer.inner().as_internal().as_ptr() as *mut std::ffi::c_void,
+            num_bytes: buffer.num_elements() * std::mem::size_of::<T>(),
+        });
+    }
+
+    /// Bind a buffer to an input tensor.
+    ///
+    /// This replaces any buffer previously bound to the same tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of input tensor.
+    /// * `buffer` - Buffer to bind.
+    pub fn with_input<T: TensorElement>(
+        mut self,
+        tensor_name: &str,
+        buffer: &'a DeviceBuffer<T>,
+    ) -> Self {
+        self.set_input(tensor_name, buffer);
+        self
+    }
+
+    /// Bind a buffer to an output tensor.
+    ///
+    /// This replaces any buffer previously bound to the same tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `buffer` - Buffer to bind.
+    pub fn set_output<T: TensorElement>(
+        &mut self,
+        tensor_name: &str,
+        buffer: &'a mut DeviceBuffer<T>,
+    ) {
+        self.insert(Binding {
+            tensor_name: tensor_name.to_string(),
+            io_mode: TensorIoMode::Output,
+            data_type: T::DATA_TYPE,
+            buffer_ptr: buffer.inner_mut().as_mut_internal().as_mut_ptr(),
+            num_bytes: buffer.num_elements() * std::mem::size_of::<T>(),
+        });
+    }
+
+    /// Bind a buffer to an output tensor.
+    ///
+    /// This replaces any buffer previously bound to the same tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Name of output tensor.
+    /// * `buffer` - Buffer to bind.
+    pub fn with_output<T: TensorElement>(
+        mut self,
+        tensor_name: &str,
+        buffer: &'a mut DeviceBuffer<T>,
+    ) -> Self {
+        self.set_output(tensor_name, buffer);
+        self
+    }
+
+    /// Get the names of the tensors that have a buffer bound.
+    pub fn tensor_names(&self) -> impl Iterator<Item = &str> {
+        self.bindings
+            .iter()
+            .map(|binding| binding.tensor_name.as_str())
+    }
+
+    /// Get the number of bound tensors.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Whether no tensors are bound.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Get the bindings.
+    #[inline(always)]
+    pub(crate) fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    fn insert(&mut self, binding: Binding) {
+        self.bindings
+            .retain(|existing| existing.tensor_name != binding.tensor_name);
+        self.bindings.push(binding);
+    }
+}
+
+impl Default for IoBindings<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use async_cuda::DeviceBuffer;
+
+/// Device buffers for the IO tensors of an engine, created by
+/// [`crate::Engine::allocate_io_buffers()`].
+///
+/// Each buffer holds the raw bytes of a tensor and is sized for the shape the engine was allocated
+/// for.
+pub struct IoBuffers {
+    buffers: HashMap<String, DeviceBuffer<u8>>,
+}
+
+impl IoBuffers {
+    /// Create [`IoBuffers`] from buffers by tensor name.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffers` - Buffers by tensor name.
+    pub(crate) fn new(buffers: HashMap<String, DeviceBuffer<u8>>) -> Self {
+        Self { buffers }
+    }
+
+    /// Get the buffer of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn get(&self, tensor_name: &str) -> Option<&DeviceBuffer<u8>> {
+        self.buffers.get(tensor_name)
+    }
+
+    /// Get the buffer of a tensor mutably.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor_name` - Tensor name.
+    #[inline(always)]
+    pub fn get_mut(&mut self, tensor_name: &str) -> Option<&mut DeviceBuffer<u8>> {
+        self.buffers.get_mut(tensor_name)
+    }
+
+    /// Get the names of the tensors that have a buffer.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.buffers.keys().map(String::as_str)
+    }
+
+    /// Get the number of buffers.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Whether there are no buffers.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// Get the buffers in the form expected by [`crate::ExecutionContext::enqueue()`].
+    pub fn as_map_mut(&mut self) -> HashMap<&str, &mut DeviceBuffer<u8>> {
+        self.buffers
+            .iter_mut()
+            .map(|(name, buffer)| (name.as_str(), buffer))
+            .collect()
+    }
+
+    /// Turn into buffers by tensor name.
+    #[inline(always)]
+    pub fn into_inner(self) -> HashMap<String, DeviceBuffer<u8>> {
+        self.buffers
+    }
+}
@@ -1,20 +1,135 @@
 #![recursion_limit = "256"]
 
+#[cfg(all(feature = "dispatch", feature = "onnx"))]
+compile_error!("feature `dispatch` cannot be combined with feature `onnx`");
+
+#[cfg(feature = "async")]
+pub mod benchmark;
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
 pub mod builder;
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
+pub mod calibrator;
+#[cfg(feature = "async")]
+pub mod context_pool;
+#[cfg(feature = "async")]
+pub mod device;
+pub mod element;
+#[cfg(feature = "async")]
 pub mod engine;
+#[cfg(all(feature = "async", feature = "onnx"))]
+pub mod engine_cache;
 pub mod error;
+#[cfg(feature = "async")]
+pub mod event;
+#[cfg(feature = "async")]
+pub mod executor;
 pub mod ffi;
+pub mod io_bindings;
+#[cfg(feature = "async")]
+pub mod io_buffers;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "async")]
+pub mod multi_device;
+#[cfg(all(feature = "async", feature = "onnx"))]
+pub mod onnx;
+#[cfg(feature = "async")]
+pub mod pipeline;
+pub mod plan;
+#[cfg(feature = "postprocess")]
+pub mod postprocess;
+#[cfg(feature = "image")]
+pub mod preprocess;
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
+pub mod refitter;
+#[cfg(feature = "async")]
 pub mod runtime;
+#[cfg(feature = "async")]
+pub mod session;
+#[cfg(feature = "sync-api")]
+pub mod sync;
+
+#[cfg(feature = "async")]
+mod pool;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "async")]
+pub use benchmark::{benchmark_engine, BenchmarkReport};
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
 pub use builder::Builder;
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
+pub use calibrator::{EntropyCalibrator, MinMaxCalibrator};
+#[cfg(feature = "async")]
+pub use context_pool::{ExecutionContextGuard, ExecutionContextPool};
+#[cfg(feature = "async")]
+pub use device::{compute_capability, on_device, DeviceGuard};
+#[cfg(feature = "half")]
+pub use element::{bf16, f16};
+pub use element::{TensorData, TensorElement};
+#[cfg(feature = "async")]
 pub use engine::{Engine, ExecutionContext};
+#[cfg(all(feature = "async", feature = "onnx"))]
+pub use engine_cache::EngineCache;
 pub use error::Error;
-pub use ffi::builder_config::BuilderConfig;
+#[cfg(feature = "async")]
+pub use event::Event;
+#[cfg(feature = "async")]
+pub use executor::{configure_executor, executor_config, ExecutorConfig, ExecutorStrategy};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::algorithm_selector::{Algorithm, AlgorithmContext, AlgorithmSelector};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::builder_config::{
+    BuilderConfig, BuilderFlag, DeviceType, MemoryPoolType, Preset, PreviewFeature,
+    QuantizationFlag, RuntimePlatform, TacticSources,
+};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::calibrator::{CalibrationAlgorithm, Calibrator};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::custom_plugin::{
+    register_plugin_creator, Plugin, PluginCreator, PluginInput, PluginOutput,
+};
+pub use ffi::data_type::DataType;
+pub use ffi::debug_listener::{DebugListener, DebugTensor};
+pub use ffi::dims::Dims;
+pub use ffi::engine_capability::EngineCapability;
+pub use ffi::gpu_allocator::GpuAllocator;
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::layer::{ActivationType, ElementWiseOperation, Layer, MatrixOperation, Weights};
 pub use ffi::memory::HostBuffer;
+#[cfg(not(feature = "dispatch"))]
 pub use ffi::network::{NetworkDefinition, NetworkDefinitionCreationFlags, Tensor};
-pub use ffi::optimization_profile::OptimizationProfile;
-pub use ffi::parser::Parser;
-pub use runtime::Runtime;
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::optimization_profile::{OptimizationProfile, OptimizationProfileBuilder};
+pub use ffi::output_allocator::OutputAllocator;
+#[cfg(feature = "onnx")]
+pub use ffi::parsers::onnx::{Parser, ParserFlag, SubgraphSupport};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::plugin::{init_libnvinfer_plugins, PluginCreatorInfo};
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::progress_monitor::ProgressMonitor;
+#[cfg(not(feature = "dispatch"))]
+pub use ffi::timing_cache::TimingCache;
+pub use ffi::version::{
+    assert_version_compatible, runtime_tensorrt_version, tensorrt_version, Version,
+};
+pub use ffi::{ErrorCode, Severity};
+pub use io_bindings::IoBindings;
+#[cfg(feature = "async")]
+pub use io_buffers::IoBuffers;
+#[cfg(feature = "async")]
+pub use multi_device::{MultiDeviceEngine, SchedulingPolicy};
+#[cfg(all(feature = "async", feature = "onnx"))]
+pub use onnx::{build_engine_from_onnx, BuildOptions, OnnxModel};
+#[cfg(feature = "async")]
+pub use pipeline::PipelinedRunner;
+pub use plan::{plan_info, PlanInfo};
+#[cfg(all(feature = "async", feature = "onnx"))]
+pub use refitter::ParserRefitter;
+#[cfg(all(feature = "async", not(feature = "dispatch")))]
+pub use refitter::Refitter;
+#[cfg(feature = "async")]
+pub use runtime::{Runtime, TempfileControlFlags};
+#[cfg(feature = "async")]
+pub use session::{BatchRunner, InferenceSession, StatefulSession};
@@ -0,0 +1,141 @@
+//! Inference metrics emitted through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! The metrics are recorded by [`crate::ExecutionContext`], [`crate::InferenceSession`] and
+//! [`crate::PipelinedRunner`], so they cover every call site without wrapping it. They are only
+//! collected once a recorder is installed, for example with `metrics-exporter-prometheus`.
+//!
+//! All metrics except [`BYTES_TRANSFERRED`] carry an `engine` label with the name of the engine
+//! (see [`crate::Engine::name()`]).
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Counter of inferences that were enqueued successfully.
+pub const INFERENCES: &str = "tensorrt_inferences_total";
+
+/// Counter of inferences that failed to enqueue.
+pub const ENQUEUE_FAILURES: &str = "tensorrt_enqueue_failures_total";
+
+/// Histogram of the time inferences took on the device, in seconds.
+///
+/// Only inferences executed with [`crate::ExecutionContext::enqueue_timed()`] are measured.
+pub const GPU_LATENCY: &str = "tensorrt_inference_gpu_latency_seconds";
+
+/// Counter of bytes copied between host and device for inference.
+///
+/// The `direction` label is either `host_to_device` or `device_to_host`.
+pub const BYTES_TRANSFERRED: &str = "tensorrt_bytes_transferred_total";
+
+/// Register descriptions and units for all metrics with the installed recorder.
+///
+/// Call this once after installing the recorder. This is optional, but exporters use the
+/// descriptions for help text.
+pub fn describe_metrics() {
+    metrics::describe_counter!(
+        INFERENCES,
+        metrics::Unit::Count,
+        "Number of inferences that were enqueued successfully."
+    );
+    metrics::describe_counter!(
+        ENQUEUE_FAILURES,
+        metrics::Unit::Count,
+        "Number of inferences that failed to enqueue."
+    );
+    metrics::describe_histogram!(
+        GPU_LATENCY,
+        metrics::Unit::Seconds,
+        "Time inferences took on the device."
+    );
+    metrics::describe_counter!(
+        BYTES_TRANSFERRED,
+        metrics::Unit::Bytes,
+        "Number of bytes copied between host and device for inference."
+    );
+}
+
+/// Direction of a copy between host and device.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Direction {
+    HostToDevice,
+    DeviceToHost,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::HostToDevice => "host_to_device",
+            Direction::DeviceToHost => "device_to_host",
+        }
+    }
+}
+
+/// Record the outcome of an enqueue.
+///
+/// # Arguments
+///
+/// * `engine_name` - Name of the engine the inference ran on.
+/// * `result` - Result of the enqueue.
+pub(crate) fn record_enqueue<T>(engine_name: &str, result: &Result<T>) {
+    let name = if result.is_ok() {
+        INFERENCES
+    } else {
+        ENQUEUE_FAILURES
+    };
+    metrics::counter!(name, "engine" => engine_name.to_string()).increment(1);
+}
+
+/// Record the time an inference took on the device.
+///
+/// # Arguments
+///
+/// * `engine_name` - Name of the engine the inference ran on.
+/// * `latency` - Time the inference took on the device.
+pub(crate) fn record_gpu_latency(engine_name: &str, latency: std::time::Duration) {
+    metrics::histogram!(GPU_LATENCY, "engine" => engine_name.to_string())
+        .record(latency.as_secs_f64());
+}
+
+/// Record bytes copied between host and device.
+///
+/// # Arguments
+///
+/// * `direction` - Direction of the copy.
+/// * `num_bytes` - Number of bytes copied.
+pub(crate) fn record_bytes_transferred(direction: Direction, num_bytes: usize) {
+    metrics::counter!(BYTES_TRANSFERRED, "direction" => direction.as_str())
+        .increment(num_bytes as u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        metrics::with_local_recorder(&recorder, || {
+            record_enqueue("detector", &Ok(()));
+            record_enqueue("detector", &Ok(()));
+            record_enqueue::<()>(
+                "detector",
+                &Err(crate::error::Error::InvalidArgument("test".to_string())),
+            );
+            record_gpu_latency("detector", std::time::Duration::from_millis(5));
+            record_bytes_transferred(Direction::HostToDevice, 16);
+        });
+        let snapshot = snapshotter.snapshot().into_hashmap();
+        let value = |name: &str| {
+            snapshot
+                .iter()
+                .find(|(key, _)| key.key().name() == name)
+                .map(|(_, (_, _, value))| value.clone())
+                .unwrap()
+        };
+        assert_eq!(value(INFERENCES), DebugValue::Counter(2));
+        assert_eq!(value(ENQUEUE_FAILURES), DebugValue::Counter(1));
+        assert_eq!(value(BYTES_TRANSFERRED), DebugValue::Counter(16));
+        assert!(matches!(value(GPU_LATENCY), DebugValue::Histogram(values) if values.len() == 1));
+    }
+}
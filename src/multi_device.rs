@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_cuda::device::DeviceId;
+use async_cuda::Stream;
+
+use crate::device::DeviceGuard;
+use crate::engine::ExecutionContext;
+use crate::error::Error;
+use crate::ffi::memory::HostBuffer;
+use crate::pool::Pool;
+use crate::runtime::Runtime;
+use crate::session::InferenceSession;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Policy that decides which device runs the next inference in a [`MultiDeviceEngine`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SchedulingPolicy {
+    /// Cycle through the devices in order.
+    RoundRobin,
+    /// Pick the device with the most idle execution contexts.
+    #[default]
+    LeastBusy,
+}
+
+/// Runs the same engine on multiple devices.
+///
+/// For each device, the plan is deserialized by a separate runtime, and a number of execution
+/// contexts are created from the resulting engine. Each execution context is paired with its own
+/// stream and IO buffers (see [`InferenceSession`]). Calls to [`MultiDeviceEngine::infer()`] are
+/// distributed over the devices according to the [`SchedulingPolicy`], and may run concurrently.
+pub struct MultiDeviceEngine {
+    devices: Vec<DeviceSessions>,
+    policy: SchedulingPolicy,
+    next: AtomicUsize,
+}
+
+/// Inference sessions that run on a single device.
+struct DeviceSessions {
+    device: DeviceId,
+    sessions: Pool<InferenceSession>,
+}
+
+impl MultiDeviceEngine {
+    /// Create a new [`MultiDeviceEngine`].
+    ///
+    /// This changes the current device while setting up each device, and restores it afterwards. It
+    /// must not run concurrently with other code that depends on the current device.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - Serialized engine.
+    /// * `devices` - Devices to run the engine on.
+    /// * `num_contexts_per_device` - Number of execution contexts to create on each device. This
+    ///   is the maximum number of concurrent inferences per device.
+    pub async fn new(
+        plan: &HostBuffer,
+        devices: &[DeviceId],
+        num_contexts_per_device: usize,
+    ) -> Result<Self> {
+        if devices.is_empty() || num_contexts_per_device == 0 {
+            return Err(Error::InvalidArgument(
+                "multi-device engine requires at least one device and one context per device"
+                    .to_string(),
+            ));
+        }
+        let mut device_sessions = Vec::with_capacity(devices.len());
+        for &device in devices {
+            let device_guard = DeviceGuard::new(device).await?;
+            let runtime = Runtime::new().await;
+            let engine = runtime.deserialize_engine_from_plan(plan).await?;
+            let io_tensors = engine.io_tensors();
+            let contexts =
+                ExecutionContext::from_engine_many(engine, num_contexts_per_device).await?;
+            let mut sessions = Vec::with_capacity(num_contexts_per_device);
+            for context in contexts {
+                let stream = Stream::new().await?;
+                sessions.push(InferenceSession::from_context(context, stream, &io_tensors).await?);
+            }
+            device_guard.restore().await?;
+            device_sessions.push(DeviceSessions {
+                device,
+                sessions: Pool::new(sessions),
+            });
+        }
+        Ok(Self {
+            devices: device_sessions,
+            policy: SchedulingPolicy::default(),
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Set the scheduling policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Policy that decides which device runs the next inference.
+    pub fn with_scheduling_policy(mut self, policy: SchedulingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Get the devices that the engine runs on.
+    pub fn devices(&self) -> Vec<DeviceId> {
+        self.devices.iter().map(|device| device.device).collect()
+    }
+
+    /// Run inference on one of the devices.
+    ///
+    /// If all execution contexts of the selected device are busy, this waits until one becomes
+    /// available.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn infer(&self, inputs: HashMap<&str, &[f32]>) -> Result<HashMap<String, Vec<f32>>> {
+        let device = &self.devices[self.select_device()];
+        let mut session = device.sessions.acquire().await;
+        session.infer(inputs).await
+    }
+
+    /// Select the index of the device that runs the next inference.
+    fn select_device(&self) -> usize {
+        let num_devices = self.devices.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % num_devices;
+        match self.policy {
+            SchedulingPolicy::RoundRobin => start,
+            SchedulingPolicy::LeastBusy => {
+                // Walk the devices in rotation order and keep the first one with the most idle
+                // contexts, so that ties are still spread over the devices.
+                let mut selected = start;
+                let mut max_num_available = 0;
+                for offset in 0..num_devices {
+                    let index = (start + offset) % num_devices;
+                    let num_available = self.devices[index].sessions.num_available();
+                    if offset == 0 || num_available > max_num_available {
+                        selected = index;
+                        max_num_available = num_available;
+                    }
+                }
+                selected
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_multi_device_engine_infer() {
+        let plan = simple_network_plan!();
+        let engine = MultiDeviceEngine::new(&plan, &[0], 2)
+            .await
+            .unwrap()
+            .with_scheduling_policy(SchedulingPolicy::RoundRobin);
+        assert_eq!(engine.devices(), &[0]);
+        let outputs = engine
+            .infer(HashMap::from([("X", [2.0, 4.0].as_slice())]))
+            .await
+            .unwrap();
+        assert_eq!(&outputs["Y"], &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_multi_device_engine_no_devices() {
+        let plan = simple_network_plan!();
+        assert!(MultiDeviceEngine::new(&plan, &[], 2).await.is_err());
+    }
+}
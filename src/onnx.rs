@@ -0,0 +1,288 @@
+use crate::builder::Builder;
+use crate::executor::Future;
+use crate::ffi::builder_config::BuilderFlag;
+use crate::ffi::dims::Dims;
+use crate::ffi::memory::HostBuffer;
+use crate::ffi::network::NetworkDefinitionCreationFlags;
+use crate::ffi::optimization_profile::OptimizationProfileBuilder;
+use crate::ffi::parsers::onnx::Parser;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// ONNX model to build an engine from, either a file or a serialized model.
+#[derive(Debug, Copy, Clone)]
+pub enum OnnxModel<'a> {
+    /// Path to ONNX file. External weights are resolved relative to the directory of the file.
+    Path(&'a std::path::Path),
+    /// Serialized ONNX model. The model cannot refer to external weights.
+    Bytes(&'a [u8]),
+}
+
+impl<'a> From<&'a std::path::Path> for OnnxModel<'a> {
+    #[inline]
+    fn from(path: &'a std::path::Path) -> Self {
+        OnnxModel::Path(path)
+    }
+}
+
+impl<'a> From<&'a std::path::PathBuf> for OnnxModel<'a> {
+    #[inline]
+    fn from(path: &'a std::path::PathBuf) -> Self {
+        OnnxModel::Path(path.as_path())
+    }
+}
+
+impl<'a> From<&'a str> for OnnxModel<'a> {
+    #[inline]
+    fn from(path: &'a str) -> Self {
+        OnnxModel::Path(std::path::Path::new(path))
+    }
+}
+
+impl<'a> From<&'a [u8]> for OnnxModel<'a> {
+    #[inline]
+    fn from(buffer: &'a [u8]) -> Self {
+        OnnxModel::Bytes(buffer)
+    }
+}
+
+impl<'a> From<&'a Vec<u8>> for OnnxModel<'a> {
+    #[inline]
+    fn from(buffer: &'a Vec<u8>) -> Self {
+        OnnxModel::Bytes(buffer.as_slice())
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for OnnxModel<'a> {
+    #[inline]
+    fn from(buffer: &'a [u8; N]) -> Self {
+        OnnxModel::Bytes(buffer.as_slice())
+    }
+}
+
+/// Options for [`build_engine_from_onnx()`].
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    fp16: bool,
+    int8: bool,
+    max_workspace_size: Option<usize>,
+    optimization_profiles: Vec<Vec<InputShapes>>,
+}
+
+/// Shape range of a single input in an optimization profile.
+#[derive(Debug, Clone)]
+struct InputShapes {
+    input_name: String,
+    min: Dims,
+    opt: Dims,
+    max: Dims,
+}
+
+impl BuildOptions {
+    /// Create [`BuildOptions`] with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable FP16 layer selection.
+    pub fn with_fp16(mut self) -> Self {
+        self.fp16 = true;
+        self
+    }
+
+    /// Enable INT8 layer selection.
+    ///
+    /// Since there is no calibration, this requires a model with explicit quantization, that is,
+    /// with `QuantizeLinear` and `DequantizeLinear` nodes.
+    pub fn with_int8(mut self) -> Self {
+        self.int8 = true;
+        self
+    }
+
+    /// Set the maximum workspace size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The maximum GPU temporary memory which the engine can use at execution time in
+    ///   bytes.
+    pub fn with_max_workspace_size(mut self, size: usize) -> Self {
+        self.max_workspace_size = Some(size);
+        self
+    }
+
+    /// Set the shape range of a dynamic input in the current optimization profile.
+    ///
+    /// If no optimization profile was started with [`BuildOptions::with_optimization_profile()`]
+    /// yet, this starts the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_name` - Name of input tensor.
+    /// * `min` - Minimum dimensions.
+    /// * `opt` - Dimensions to optimize for.
+    /// * `max` - Maximum dimensions.
+    pub fn with_input_shapes(
+        mut self,
+        input_name: &str,
+        min: impl Into<Dims>,
+        opt: impl Into<Dims>,
+        max: impl Into<Dims>,
+    ) -> Self {
+        if self.optimization_profiles.is_empty() {
+            self.optimization_profiles.push(Vec::new());
+        }
+        self.optimization_profiles
+            .last_mut()
+            .unwrap()
+            .push(InputShapes {
+                input_name: input_name.to_string(),
+                min: min.into(),
+                opt: opt.into(),
+                max: max.into(),
+            });
+        self
+    }
+
+    /// Start a new optimization profile.
+    ///
+    /// Input shapes set with [`BuildOptions::with_input_shapes()`] afterwards go into the new
+    /// profile.
+    pub fn with_optimization_profile(mut self) -> Self {
+        self.optimization_profiles.push(Vec::new());
+        self
+    }
+
+    /// Write the options to a hasher for use in a persisted cache key.
+    ///
+    /// Unlike a derived [`std::hash::Hash`] implementation, the bytes that are written are fixed:
+    /// integers are written as little-endian and every variable length field is prefixed with its
+    /// length. Changing this changes the cache keys of all existing plans.
+    pub(crate) fn write_cache_key(&self, hasher: &mut impl std::hash::Hasher) {
+        hasher.write_u8(self.fp16 as u8);
+        hasher.write_u8(self.int8 as u8);
+        match self.max_workspace_size {
+            Some(size) => {
+                hasher.write_u8(1);
+                hasher.write(&(size as u64).to_le_bytes());
+            }
+            None => hasher.write_u8(0),
+        }
+        hasher.write(&(self.optimization_profiles.len() as u64).to_le_bytes());
+        for input_shapes in &self.optimization_profiles {
+            hasher.write(&(input_shapes.len() as u64).to_le_bytes());
+            for InputShapes {
+                input_name,
+                min,
+                opt,
+                max,
+            } in input_shapes
+            {
+                hasher.write(&(input_name.len() as u64).to_le_bytes());
+                hasher.write(input_name.as_bytes());
+                for dims in [min, opt, max] {
+                    hasher.write(&(dims.as_slice().len() as u64).to_le_bytes());
+                    for dim in dims.as_slice() {
+                        hasher.write(&dim.to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Build a serialized engine from an ONNX model.
+///
+/// This creates a builder and an explicit batch network definition, parses the model into it,
+/// configures the builder according to `options` and builds the network. The resulting plan can be
+/// deserialized with [`crate::Runtime::deserialize_engine_from_plan()`] or stored for later use.
+///
+/// # Arguments
+///
+/// * `model` - Path to ONNX file or serialized ONNX model.
+/// * `options` - Build options.
+///
+/// # Return value
+///
+/// Serialized engine.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(options = ?options))
+)]
+pub async fn build_engine_from_onnx(
+    model: impl Into<OnnxModel<'_>>,
+    options: BuildOptions,
+) -> Result<HostBuffer> {
+    let mut builder = Builder::new().await?;
+    let network_definition =
+        builder.network_definition(NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+    let model = model.into();
+    // SAFETY: The future is awaited right away and not leaked.
+    let mut network_definition = unsafe {
+        Future::new(move || match model {
+            OnnxModel::Path(path) => {
+                Parser::parse_network_definition_from_file(network_definition, &path)
+            }
+            OnnxModel::Bytes(buffer) => {
+                Parser::parse_network_definition_from_bytes(network_definition, buffer)
+            }
+        })
+    }
+    .await?;
+
+    let mut config = builder.config().await;
+    if options.fp16 {
+        config.set_flag(BuilderFlag::Fp16)?;
+    }
+    if options.int8 {
+        config.set_flag(BuilderFlag::Int8)?;
+    }
+    if let Some(max_workspace_size) = options.max_workspace_size {
+        config = config.with_max_workspace_size(max_workspace_size);
+    }
+    for input_shapes in &options.optimization_profiles {
+        let mut optimization_profile_builder = OptimizationProfileBuilder::new();
+        for InputShapes {
+            input_name,
+            min,
+            opt,
+            max,
+        } in input_shapes
+        {
+            optimization_profile_builder.add_input(input_name, min, opt, max);
+        }
+        config.add_optimization_profile(optimization_profile_builder.build(&mut builder)?)?;
+    }
+
+    builder
+        .build_serialized_network(&mut network_definition, config)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::onnx::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_build_engine_from_onnx_file() {
+        let simple_onnx_file = simple_onnx_file!();
+        let plan = build_engine_from_onnx(simple_onnx_file.path(), BuildOptions::new())
+            .await
+            .unwrap();
+        assert!(plan.size() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_build_engine_from_onnx_bytes() {
+        let plan = build_engine_from_onnx(SIMPLE_ONNX, BuildOptions::new().with_fp16())
+            .await
+            .unwrap();
+        let engine = crate::Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&plan)
+            .await
+            .unwrap();
+        assert_eq!(engine.num_io_tensors(), 2);
+    }
+}
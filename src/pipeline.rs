@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+
+use async_cuda::{DeviceBuffer, HostBuffer, Stream};
+
+use crate::element::{as_bytes, from_bytes, TensorElement};
+use crate::engine::{Engine, ExecutionContext, TensorIoMode};
+use crate::error::Error;
+use crate::ffi::data_type::DataType;
+use crate::pool::Pool;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Default number of slots of a [`PipelinedRunner`], which results in double buffering.
+const DEFAULT_DEPTH: usize = 2;
+
+/// Runs inference with multiple streams and sets of IO buffers, so that host-device copies of one
+/// inference overlap with the execution of another.
+///
+/// The runner owns a number of slots (two by default). Each slot has its own execution context,
+/// stream, page-locked host buffers and device buffers. A call to [`PipelinedRunner::submit()`]
+/// checks out a slot and queues the copy of the inputs to the device, inference and the copy of
+/// the outputs back to the host on the stream of that slot. When multiple calls are in flight, the
+/// device overlaps the copies of one with the execution of the others.
+///
+/// To benefit from the overlap, calls must be made concurrently, for example by polling multiple
+/// [`PipelinedRunner::submit()`] futures at the same time. If all slots are busy, calls wait until
+/// one becomes available.
+///
+/// All IO tensors of the engine must have static shapes.
+pub struct PipelinedRunner {
+    slots: Pool<Slot>,
+    data_types: HashMap<String, DataType>,
+    output_names: Vec<String>,
+    depth: usize,
+}
+
+/// Execution context, stream and buffers of a single pipeline slot.
+struct Slot {
+    context: ExecutionContext<'static>,
+    stream: Stream,
+    host_buffers: HashMap<String, HostBuffer<u8>>,
+    device_buffers: HashMap<String, DeviceBuffer<u8>>,
+}
+
+impl PipelinedRunner {
+    /// Create a new [`PipelinedRunner`] with two slots.
+    ///
+    /// This consumes the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to perform inference with.
+    pub async fn new(engine: Engine) -> Result<Self> {
+        Self::new_with_depth(engine, DEFAULT_DEPTH).await
+    }
+
+    /// Create a new [`PipelinedRunner`] with a custom number of slots.
+    ///
+    /// This consumes the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to perform inference with.
+    /// * `depth` - Number of slots, which is the maximum number of inferences in flight.
+    pub async fn new_with_depth(engine: Engine, depth: usize) -> Result<Self> {
+        if depth == 0 {
+            return Err(Error::InvalidArgument(
+                "pipelined runner requires at least one slot".to_string(),
+            ));
+        }
+        let io_tensors = engine.io_tensors();
+        let mut sizes = Vec::with_capacity(io_tensors.len());
+        let mut data_types = HashMap::with_capacity(io_tensors.len());
+        let mut output_names = Vec::new();
+        for io_tensor in &io_tensors {
+            let num_elements = io_tensor.shape.num_elements().ok_or_else(|| {
+                Error::InvalidArgument(format!("tensor {} has a dynamic shape", io_tensor.name,))
+            })?;
+            let data_type = io_tensor.data_type.ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "tensor {} has a data type that is not supported",
+                    io_tensor.name,
+                ))
+            })?;
+            if io_tensor.io_mode == TensorIoMode::Output {
+                output_names.push(io_tensor.name.clone());
+            }
+            sizes.push((io_tensor.name.clone(), data_type.num_bytes(num_elements)));
+            data_types.insert(io_tensor.name.clone(), data_type);
+        }
+
+        let contexts = ExecutionContext::from_engine_many(engine, depth).await?;
+        let mut slots = Vec::with_capacity(depth);
+        for context in contexts {
+            let stream = Stream::new().await?;
+            let mut host_buffers = HashMap::with_capacity(sizes.len());
+            let mut device_buffers = HashMap::with_capacity(sizes.len());
+            for (name, num_bytes) in &sizes {
+                host_buffers.insert(name.clone(), HostBuffer::new(*num_bytes).await);
+                device_buffers.insert(name.clone(), DeviceBuffer::new(*num_bytes, &stream).await);
+            }
+            slots.push(Slot {
+                context,
+                stream,
+                host_buffers,
+                device_buffers,
+            });
+        }
+        Ok(Self {
+            slots: Pool::new(slots),
+            data_types,
+            output_names,
+            depth,
+        })
+    }
+
+    /// Get the number of slots, which is the maximum number of inferences in flight.
+    #[inline(always)]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Submit inputs and wait for the outputs.
+    ///
+    /// All IO tensors must have data type [`DataType::Float`]. Use
+    /// [`PipelinedRunner::submit_typed()`] for other data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn submit(&self, inputs: HashMap<&str, &[f32]>) -> Result<HashMap<String, Vec<f32>>> {
+        self.submit_typed(inputs).await
+    }
+
+    /// Submit inputs of data type `T` and wait for the outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn submit_typed<T: TensorElement>(
+        &self,
+        inputs: HashMap<&str, &[T]>,
+    ) -> Result<HashMap<String, Vec<T>>> {
+        for name in inputs
+            .keys()
+            .copied()
+            .chain(self.output_names.iter().map(String::as_str))
+        {
+            match self.data_types.get(name) {
+                Some(&expected) if expected != T::DATA_TYPE => {
+                    return Err(Error::DataTypeMismatch {
+                        tensor_name: name.to_string(),
+                        expected,
+                        actual: T::DATA_TYPE,
+                    });
+                }
+                Some(_) => {}
+                None => return Err(Error::UnknownTensor(name.to_string())),
+            }
+        }
+
+        let mut slot = self.slots.acquire().await;
+        let Slot {
+            context,
+            stream,
+            host_buffers,
+            device_buffers,
+        } = &mut *slot;
+
+        for (name, data) in inputs {
+            let data = as_bytes(data);
+            let host_buffer = host_buffers.get_mut(name).unwrap();
+            if host_buffer.num_elements() != data.len() {
+                return Err(Error::InvalidArgument(format!(
+                    "input tensor {name} has {} elements but {} were provided",
+                    host_buffer.num_elements() / T::DATA_TYPE.size(),
+                    data.len() / T::DATA_TYPE.size(),
+                )));
+            }
+            host_buffer.copy_from_slice(data);
+            device_buffers
+                .get_mut(name)
+                .unwrap()
+                .copy_from(host_buffer, stream)
+                .await?;
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_bytes_transferred(
+                crate::metrics::Direction::HostToDevice,
+                data.len(),
+            );
+        }
+
+        let mut io_buffers = device_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (name.as_str(), buffer))
+            .collect();
+        context.enqueue(&mut io_buffers, stream).await?;
+
+        for name in &self.output_names {
+            device_buffers[name]
+                .copy_to(host_buffers.get_mut(name).unwrap(), stream)
+                .await?;
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_bytes_transferred(
+                crate::metrics::Direction::DeviceToHost,
+                device_buffers[name].num_elements(),
+            );
+        }
+        stream.synchronize().await?;
+
+        Ok(self
+            .output_names
+            .iter()
+            .map(|name| (name.clone(), from_bytes(&host_buffers[name].to_vec())))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pipelined_runner_submit() {
+        let engine = identity_engine!();
+        let runner = PipelinedRunner::new(engine).await.unwrap();
+        assert_eq!(runner.depth(), 2);
+        let input1 = [1.0, 2.0, 3.0, 4.0];
+        let input2 = [5.0, 6.0, 7.0, 8.0];
+        let input3 = [9.0, 10.0, 11.0, 12.0];
+        let (output1, output2, output3) = tokio::join!(
+            runner.submit(HashMap::from([("X", input1.as_slice())])),
+            runner.submit(HashMap::from([("X", input2.as_slice())])),
+            runner.submit(HashMap::from([("X", input3.as_slice())])),
+        );
+        assert_eq!(&output1.unwrap()["Y"], &input1);
+        assert_eq!(&output2.unwrap()["Y"], &input2);
+        assert_eq!(&output3.unwrap()["Y"], &input3);
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_runner_unknown_tensor() {
+        let engine = identity_engine!();
+        let runner = PipelinedRunner::new(engine).await.unwrap();
+        assert!(matches!(
+            runner
+                .submit(HashMap::from([("Z", [0.0; 4].as_slice())]))
+                .await,
+            Err(Error::UnknownTensor(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_runner_zero_depth() {
+        let engine = identity_engine!();
+        assert!(PipelinedRunner::new_with_depth(engine, 0).await.is_err());
+    }
+}
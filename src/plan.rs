@@ -0,0 +1,103 @@
+use crate::error::Error;
+use crate::ffi::version::{runtime_tensorrt_version, Version};
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Tag that every serialized plan starts with.
+const PLAN_MAGIC: &[u8; 4] = b"ftrt";
+
+/// Offset of the TensorRT version in the plan header.
+const PLAN_VERSION_OFFSET: usize = 24;
+
+/// Size of the plan header that is parsed by [`plan_info()`].
+const PLAN_HEADER_SIZE: usize = PLAN_VERSION_OFFSET + 4;
+
+/// Information about a serialized plan, read from its header.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlanInfo {
+    /// Version of TensorRT that built the plan.
+    pub tensorrt_version: Version,
+    /// Build number of the TensorRT version that built the plan.
+    pub build: u32,
+}
+
+impl PlanInfo {
+    /// Check whether the plan can be deserialized by the TensorRT library that is loaded at
+    /// runtime.
+    ///
+    /// Plans can only be deserialized by the same major and minor version of TensorRT that built
+    /// them, unless they were built to be version compatible.
+    ///
+    /// # Return value
+    ///
+    /// [`Error::VersionMismatch`] if the versions are not compatible.
+    pub fn check_compatible(&self) -> Result<()> {
+        let runtime = runtime_tensorrt_version();
+        if self.tensorrt_version.major == runtime.major
+            && self.tensorrt_version.minor == runtime.minor
+        {
+            Ok(())
+        } else {
+            Err(Error::VersionMismatch {
+                compiled: self.tensorrt_version,
+                runtime,
+            })
+        }
+    }
+}
+
+/// Read information about a serialized plan from its header, without deserializing it.
+///
+/// This does not require a GPU, and can be used to reject plans that were built by an incompatible
+/// version of TensorRT with a clear error, instead of the error that TensorRT produces when
+/// deserialization fails.
+///
+/// Note that the layout of the plan header is not documented by TensorRT. The header is expected
+/// to start with the `ftrt` tag, and to hold the major, minor, patch and build number of the
+/// TensorRT version at byte offset 24.
+///
+/// # Arguments
+///
+/// * `buffer` - Serialized plan.
+pub fn plan_info(buffer: &[u8]) -> Result<PlanInfo> {
+    if buffer.len() < PLAN_HEADER_SIZE || &buffer[..PLAN_MAGIC.len()] != PLAN_MAGIC {
+        return Err(Error::InvalidArgument(
+            "buffer is not a serialized TensorRT plan".to_string(),
+        ));
+    }
+    let version = &buffer[PLAN_VERSION_OFFSET..PLAN_HEADER_SIZE];
+    Ok(PlanInfo {
+        tensorrt_version: Version {
+            major: version[0] as u32,
+            minor: version[1] as u32,
+            patch: version[2] as u32,
+        },
+        build: version[3] as u32,
+    })
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use crate::ffi::version::tensorrt_version;
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_plan_info() {
+        let network_plan = simple_network_plan!();
+        let plan_info = plan_info(network_plan.as_bytes()).unwrap();
+        let version = tensorrt_version();
+        assert_eq!(plan_info.tensorrt_version.major, version.major);
+        assert_eq!(plan_info.tensorrt_version.minor, version.minor);
+        assert!(plan_info.check_compatible().is_ok());
+    }
+
+    #[test]
+    fn test_plan_info_invalid() {
+        assert!(matches!(
+            plan_info(&[0, 1, 2, 3]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
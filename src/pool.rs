@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
+
+/// Pool of items that can be checked out asynchronously.
+///
+/// Items are checked out with [`Pool::acquire()`], which waits until an item is available. The item
+/// is returned to the pool when the guard is dropped.
+pub(crate) struct Pool<T> {
+    state: Mutex<PoolState<T>>,
+}
+
+struct PoolState<T> {
+    items: Vec<T>,
+    waiters: VecDeque<Waker>,
+}
+
+impl<T> Pool<T> {
+    /// Create a new pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Items in pool.
+    pub(crate) fn new(items: Vec<T>) -> Self {
+        Self {
+            state: Mutex::new(PoolState {
+                items,
+                waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Get the number of items that are currently not checked out.
+    pub(crate) fn num_available(&self) -> usize {
+        self.state.lock().unwrap().items.len()
+    }
+
+    /// Check out an item, waiting until one becomes available.
+    pub(crate) fn acquire(&self) -> Acquire<'_, T> {
+        Acquire { pool: self }
+    }
+
+    /// Return an item to the pool and wake up waiters.
+    ///
+    /// All waiters are woken up, so that no wakeup is lost when a woken waiter is dropped before
+    /// it gets to check out the item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Item to return.
+    fn release(&self, item: T) {
+        let waiters = {
+            let mut state = self.state.lock().unwrap();
+            state.items.push(item);
+            std::mem::take(&mut state.waiters)
+        };
+        for waiter in waiters {
+            waiter.wake();
+        }
+    }
+}
+
+/// Future returned by [`Pool::acquire()`].
+pub(crate) struct Acquire<'pool, T> {
+    pool: &'pool Pool<T>,
+}
+
+impl<'pool, T> Future for Acquire<'pool, T> {
+    type Output = PoolGuard<'pool, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let pool = self.pool;
+        let mut state = pool.state.lock().unwrap();
+        match state.items.pop() {
+            Some(item) => Poll::Ready(PoolGuard {
+                pool,
+                item: Some(item),
+            }),
+            None => {
+                if !state
+                    .waiters
+                    .iter()
+                    .any(|waiter| waiter.will_wake(cx.waker()))
+                {
+                    state.waiters.push_back(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Item checked out from a [`Pool`].
+///
+/// The item is returned to the pool on drop.
+pub(crate) struct PoolGuard<'pool, T> {
+    pool: &'pool Pool<T>,
+    item: Option<T>,
+}
+
+impl<'pool, T> std::ops::Deref for PoolGuard<'pool, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item.as_ref().unwrap()
+    }
+}
+
+impl<'pool, T> std::ops::DerefMut for PoolGuard<'pool, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.item.as_mut().unwrap()
+    }
+}
+
+impl<'pool, T> Drop for PoolGuard<'pool, T> {
+    fn drop(&mut self) {
+        if let Some(item) = self.item.take() {
+            self.pool.release(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pool_acquire_and_release() {
+        let pool = Pool::new(vec![1, 2]);
+        let first = pool.acquire().await;
+        let second = pool.acquire().await;
+        assert_eq!(pool.num_available(), 0);
+        assert_eq!(*first + *second, 3);
+        drop(first);
+        assert_eq!(pool.num_available(), 1);
+        let third = pool.acquire().await;
+        assert_eq!(*third, 2);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::error::Error;
+use crate::ffi::dims::Dims;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Axis-aligned bounding box in the pixel coordinates of the model input.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    /// Left edge.
+    pub x_min: f32,
+    /// Top edge.
+    pub y_min: f32,
+    /// Right edge.
+    pub x_max: f32,
+    /// Bottom edge.
+    pub y_max: f32,
+}
+
+impl BoundingBox {
+    /// Create a new [`BoundingBox`] from its center and size.
+    ///
+    /// # Arguments
+    ///
+    /// * `center_x` - Horizontal center.
+    /// * `center_y` - Vertical center.
+    /// * `width` - Width.
+    /// * `height` - Height.
+    pub fn from_center(center_x: f32, center_y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x_min: center_x - width / 2.0,
+            y_min: center_y - height / 2.0,
+            x_max: center_x + width / 2.0,
+            y_max: center_y + height / 2.0,
+        }
+    }
+
+    /// Width of the box.
+    #[inline(always)]
+    pub fn width(&self) -> f32 {
+        (self.x_max - self.x_min).max(0.0)
+    }
+
+    /// Height of the box.
+    #[inline(always)]
+    pub fn height(&self) -> f32 {
+        (self.y_max - self.y_min).max(0.0)
+    }
+
+    /// Area of the box.
+    #[inline(always)]
+    pub fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    /// Intersection over union with another box.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Box to compare with.
+    ///
+    /// # Return value
+    ///
+    /// Value between `0` (no overlap) and `1` (identical boxes).
+    pub fn iou(&self, other: &BoundingBox) -> f32 {
+        let intersection = BoundingBox {
+            x_min: self.x_min.max(other.x_min),
+            y_min: self.y_min.max(other.y_min),
+            x_max: self.x_max.min(other.x_max),
+            y_max: self.y_max.min(other.y_max),
+        }
+        .area();
+        let union = self.area() + other.area() - intersection;
+        if union > 0.0 {
+            intersection / union
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Object detected by a detection model.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Detection {
+    /// Bounding box of the object.
+    pub bbox: BoundingBox,
+    /// Confidence score.
+    pub score: f32,
+    /// Index of the class of the object.
+    pub class: usize,
+}
+
+/// Layout of the raw output of a detection model.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DetectionFormat {
+    /// Output of shape `[batch, num_anchors, 5 + num_classes]`, where each anchor holds the box
+    /// center and size, an objectness score and a score per class, as produced by YOLOv5 and
+    /// YOLOv7. The score of a detection is the objectness score times the class score.
+    Yolov5,
+    /// Output of shape `[batch, 4 + num_classes, num_anchors]`, where each anchor holds the box
+    /// center and size and a score per class, as produced by YOLOv8 and up.
+    Yolov8,
+}
+
+/// Configuration for decoding the raw output of a detection model.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PostprocessConfig {
+    format: DetectionFormat,
+    score_threshold: f32,
+    iou_threshold: f32,
+    max_detections: usize,
+    class_agnostic: bool,
+}
+
+impl PostprocessConfig {
+    /// Create a new [`PostprocessConfig`] with a score threshold of `0.25`, an IoU threshold of
+    /// `0.45` and at most `300` detections per image.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Layout of the model output.
+    pub fn new(format: DetectionFormat) -> Self {
+        Self {
+            format,
+            score_threshold: 0.25,
+            iou_threshold: 0.45,
+            max_detections: 300,
+            class_agnostic: false,
+        }
+    }
+
+    /// Set the minimum score of a detection.
+    ///
+    /// # Arguments
+    ///
+    /// * `score_threshold` - Minimum score.
+    pub fn with_score_threshold(mut self, score_threshold: f32) -> Self {
+        self.score_threshold = score_threshold;
+        self
+    }
+
+    /// Set the IoU above which the detection with the lower score is suppressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `iou_threshold` - IoU threshold.
+    pub fn with_iou_threshold(mut self, iou_threshold: f32) -> Self {
+        self.iou_threshold = iou_threshold;
+        self
+    }
+
+    /// Set the maximum number of detections per image.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_detections` - Maximum number of detections.
+    pub fn with_max_detections(mut self, max_detections: usize) -> Self {
+        self.max_detections = max_detections;
+        self
+    }
+
+    /// Suppress overlapping detections regardless of their class.
+    ///
+    /// By default, only detections of the same class suppress each other.
+    pub fn with_class_agnostic_nms(mut self) -> Self {
+        self.class_agnostic = true;
+        self
+    }
+}
+
+/// Decode the raw output of a detection model and apply non-maximum suppression.
+///
+/// Note that this function is CPU-intensive. Callers should not use it in async context or spawn
+/// a blocking task for it.
+///
+/// # Arguments
+///
+/// * `output` - Raw output data.
+/// * `shape` - Shape of the output tensor, see [`crate::ExecutionContext::tensor_shape()`].
+/// * `config` - Postprocessing configuration.
+///
+/// # Return value
+///
+/// Detections per image in the batch, ordered by descending score.
+pub fn postprocess(
+    output: &[f32],
+    shape: &Dims,
+    config: &PostprocessConfig,
+) -> Result<Vec<Vec<Detection>>> {
+    Ok(decode(output, shape, config)?
+        .into_iter()
+        .map(|detections| {
+            let mut detections =
+                non_max_suppression(detections, config.iou_threshold, config.class_agnostic);
+            detections.truncate(config.max_detections);
+            detections
+        })
+        .collect())
+}
+
+/// Decode the raw output of a detection model into detections, without non-maximum suppression.
+///
+/// Detections with a score below the score threshold are dropped.
+///
+/// # Arguments
+///
+/// * `output` - Raw output data.
+/// * `shape` - Shape of the output tensor, see [`crate::ExecutionContext::tensor_shape()`].
+/// * `config` - Postprocessing configuration.
+///
+/// # Return value
+///
+/// Detections per image in the batch.
+pub fn decode(
+    output: &[f32],
+    shape: &Dims,
+    config: &PostprocessConfig,
+) -> Result<Vec<Vec<Detection>>> {
+    let &[batch_size, rows, columns] = shape.as_slice() else {
+        return Err(Error::InvalidArgument(format!(
+            "detection output must have shape [batch, rows, columns], but has shape {shape}"
+        )));
+    };
+    if batch_size < 0 || rows < 0 || columns < 0 {
+        return Err(Error::InvalidArgument(format!(
+            "detection output has a dynamic shape that is not specified: {shape}"
+        )));
+    }
+    let (batch_size, rows, columns) = (batch_size as usize, rows as usize, columns as usize);
+    if output.len() != batch_size * rows * columns {
+        return Err(Error::InvalidArgument(format!(
+            "detection output has {} elements but its shape {shape} requires {}",
+            output.len(),
+            batch_size * rows * columns,
+        )));
+    }
+    let (num_anchors, num_attributes, num_box_attributes) = match config.format {
+        DetectionFormat::Yolov5 => (rows, columns, 5),
+        DetectionFormat::Yolov8 => (columns, rows, 4),
+    };
+    if num_attributes <= num_box_attributes {
+        return Err(Error::InvalidArgument(format!(
+            "detection output with shape {shape} has no class scores for format {:?}",
+            config.format,
+        )));
+    }
+
+    Ok((0..batch_size)
+        .map(|index| {
+            let image = &output[index * rows * columns..(index + 1) * rows * columns];
+            let attribute = |anchor: usize, attribute: usize| match config.format {
+                DetectionFormat::Yolov5 => image[anchor * num_attributes + attribute],
+                DetectionFormat::Yolov8 => image[attribute * num_anchors + anchor],
+            };
+            (0..num_anchors)
+                .filter_map(|anchor| {
+                    let objectness = match config.format {
+                        DetectionFormat::Yolov5 => attribute(anchor, 4),
+                        DetectionFormat::Yolov8 => 1.0,
+                    };
+                    let (class, class_score) = (num_box_attributes..num_attributes)
+                        .map(|index| (index - num_box_attributes, attribute(anchor, index)))
+                        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+                    let score = objectness * class_score;
+                    (score >= config.score_threshold).then(|| Detection {
+                        bbox: BoundingBox::from_center(
+                            attribute(anchor, 0),
+                            attribute(anchor, 1),
+                            attribute(anchor, 2),
+                            attribute(anchor, 3),
+                        ),
+                        score,
+                        class,
+                    })
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Greedy non-maximum suppression.
+///
+/// Detections are visited in order of descending score, and each detection is kept unless it
+/// overlaps a detection that was kept before by more than the IoU threshold.
+///
+/// # Arguments
+///
+/// * `detections` - Detections to filter.
+/// * `iou_threshold` - IoU above which the detection with the lower score is suppressed.
+/// * `class_agnostic` - Whether detections of different classes suppress each other.
+///
+/// # Return value
+///
+/// Kept detections, ordered by descending score.
+pub fn non_max_suppression(
+    mut detections: Vec<Detection>,
+    iou_threshold: f32,
+    class_agnostic: bool,
+) -> Vec<Detection> {
+    detections.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let mut kept: Vec<Detection> = Vec::with_capacity(detections.len());
+    for detection in detections {
+        let suppressed = kept.iter().any(|other| {
+            (class_agnostic || other.class == detection.class)
+                && other.bbox.iou(&detection.bbox) > iou_threshold
+        });
+        if !suppressed {
+            kept.push(detection);
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detection(x_min: f32, score: f32, class: usize) -> Detection {
+        Detection {
+            bbox: BoundingBox {
+                x_min,
+                y_min: 0.0,
+                x_max: x_min + 10.0,
+                y_max: 10.0,
+            },
+            score,
+            class,
+        }
+    }
+
+    #[test]
+    fn test_bounding_box_iou() {
+        let a = BoundingBox::from_center(5.0, 5.0, 10.0, 10.0);
+        let b = BoundingBox::from_center(10.0, 5.0, 10.0, 10.0);
+        assert_eq!(a.iou(&a), 1.0);
+        assert!((a.iou(&b) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(
+            a.iou(&BoundingBox::from_center(50.0, 50.0, 10.0, 10.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_non_max_suppression() {
+        let detections = vec![
+            detection(1.0, 0.8, 0),
+            detection(0.0, 0.9, 0),
+            detection(0.0, 0.7, 1),
+            detection(50.0, 0.6, 0),
+        ];
+        let kept = non_max_suppression(detections.clone(), 0.5, false);
+        assert_eq!(
+            kept,
+            vec![
+                detection(0.0, 0.9, 0),
+                detection(0.0, 0.7, 1),
+                detection(50.0, 0.6, 0),
+            ]
+        );
+        let kept = non_max_suppression(detections, 0.5, true);
+        assert_eq!(kept, vec![detection(0.0, 0.9, 0), detection(50.0, 0.6, 0)]);
+    }
+
+    #[test]
+    fn test_postprocess_yolov8() {
+        // Two anchors, two classes: [1, 6, 2].
+        #[rustfmt::skip]
+        let output = [
+            5.0, 50.0, // center x
+            5.0, 50.0, // center y
+            10.0, 10.0, // width
+            10.0, 10.0, // height
+            0.9, 0.1, // class 0
+            0.2, 0.05, // class 1
+        ];
+        let config = PostprocessConfig::new(DetectionFormat::Yolov8);
+        let detections = postprocess(&output, &Dims::from(vec![1, 6, 2]), &config).unwrap();
+        assert_eq!(detections, vec![vec![detection(0.0, 0.9, 0)]]);
+    }
+
+    #[test]
+    fn test_postprocess_yolov5() {
+        // Two anchors, two classes: [1, 2, 7].
+        #[rustfmt::skip]
+        let output = [
+            5.0, 5.0, 10.0, 10.0, 0.5, 0.2, 0.8,
+            50.0, 50.0, 10.0, 10.0, 0.1, 0.9, 0.1,
+        ];
+        let config = PostprocessConfig::new(DetectionFormat::Yolov5);
+        let detections = postprocess(&output, &Dims::from(vec![1, 2, 7]), &config).unwrap();
+        assert_eq!(detections, vec![vec![detection(0.0, 0.4, 1)]]);
+    }
+
+    #[test]
+    fn test_postprocess_shape_mismatch() {
+        let config = PostprocessConfig::new(DetectionFormat::Yolov8);
+        assert!(matches!(
+            postprocess(&[0.0; 4], &Dims::from(vec![1, 6, 2]), &config),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            postprocess(&[0.0; 12], &Dims::from(vec![6, 2]), &config),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+use async_cuda::HostBuffer;
+
+pub use image::imageops::FilterType;
+
+/// Memory layout of a preprocessed image.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Channels first (planar), as expected by most vision models.
+    Chw,
+    /// Channels last (interleaved).
+    Hwc,
+}
+
+/// Configuration for preprocessing an image into model input.
+///
+/// Each pixel value is scaled to `[0, 1]`, after which the per-channel mean is subtracted and the
+/// result is divided by the per-channel standard deviation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PreprocessConfig {
+    width: u32,
+    height: u32,
+    filter: FilterType,
+    mean: [f32; 3],
+    std: [f32; 3],
+    layout: Layout,
+}
+
+impl PreprocessConfig {
+    /// Mean of the ImageNet dataset per RGB channel.
+    pub const IMAGENET_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+
+    /// Standard deviation of the ImageNet dataset per RGB channel.
+    pub const IMAGENET_STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+    /// Create a new [`PreprocessConfig`] that resizes to the given size and produces CHW output
+    /// without normalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of model input.
+    /// * `height` - Height of model input.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            filter: FilterType::Triangle,
+            mean: [0.0; 3],
+            std: [1.0; 3],
+            layout: Layout::Chw,
+        }
+    }
+
+    /// Set the filter used for resizing.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Resize filter.
+    pub fn with_filter(mut self, filter: FilterType) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the per-channel mean and standard deviation used for normalization.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - Mean per RGB channel.
+    /// * `std` - Standard deviation per RGB channel.
+    pub fn with_normalization(mut self, mean: [f32; 3], std: [f32; 3]) -> Self {
+        self.mean = mean;
+        self.std = std;
+        self
+    }
+
+    /// Normalize with the ImageNet mean and standard deviation.
+    pub fn with_imagenet_normalization(self) -> Self {
+        self.with_normalization(Self::IMAGENET_MEAN, Self::IMAGENET_STD)
+    }
+
+    /// Set the memory layout of the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - Output layout.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Number of elements in the output of preprocessing.
+    pub fn num_elements(&self) -> usize {
+        self.width as usize * self.height as usize * 3
+    }
+}
+
+/// Resize, normalize and convert the layout of an image.
+///
+/// Note that this function is CPU-intensive. Callers should not use it in async context or spawn
+/// a blocking task for it.
+///
+/// # Arguments
+///
+/// * `image` - Image to preprocess.
+/// * `config` - Preprocessing configuration.
+///
+/// # Return value
+///
+/// Preprocessed RGB image data.
+pub fn preprocess(image: &image::DynamicImage, config: &PreprocessConfig) -> Vec<f32> {
+    let mut output = vec![0.0; config.num_elements()];
+    preprocess_into(image, config, &mut output);
+    output
+}
+
+/// Resize, normalize and convert the layout of an image into an existing slice.
+///
+/// # Arguments
+///
+/// * `image` - Image to preprocess.
+/// * `config` - Preprocessing configuration.
+/// * `output` - Slice to write preprocessed RGB image data to.
+///
+/// # Panics
+///
+/// If `output` does not hold exactly [`PreprocessConfig::num_elements()`] elements.
+pub fn preprocess_into(image: &image::DynamicImage, config: &PreprocessConfig, output: &mut [f32]) {
+    assert_eq!(
+        output.len(),
+        config.num_elements(),
+        "output must hold exactly width * height * 3 elements"
+    );
+    let image = image.to_rgb8();
+    let image = if image.width() != config.width || image.height() != config.height {
+        image::imageops::resize(&image, config.width, config.height, config.filter)
+    } else {
+        image
+    };
+    let num_pixels = config.width as usize * config.height as usize;
+    for (index, pixel) in image.pixels().enumerate() {
+        for channel in 0..3 {
+            let value = pixel.0[channel] as f32 / 255.0;
+            let value = (value - config.mean[channel]) / config.std[channel];
+            let output_index = match config.layout {
+                Layout::Chw => channel * num_pixels + index,
+                Layout::Hwc => index * 3 + channel,
+            };
+            output[output_index] = value;
+        }
+    }
+}
+
+/// Resize, normalize and convert the layout of an image directly into a page-locked host buffer,
+/// ready to be copied to the device.
+///
+/// Like [`preprocess()`], this function is CPU-intensive. Callers should not use it in async
+/// context or spawn a blocking task for it. The buffer can be allocated once and reused for every
+/// image.
+///
+/// # Arguments
+///
+/// * `image` - Image to preprocess.
+/// * `config` - Preprocessing configuration.
+/// * `output` - Page-locked host buffer to write preprocessed RGB image data to.
+///
+/// # Panics
+///
+/// If `output` does not hold exactly [`PreprocessConfig::num_elements()`] elements.
+pub fn preprocess_into_host_buffer(
+    image: &image::DynamicImage,
+    config: &PreprocessConfig,
+    output: &mut HostBuffer<f32>,
+) {
+    let num_elements = output.num_elements();
+    let output_ptr = output.inner_mut().as_mut_internal().as_mut_ptr() as *mut f32;
+    // SAFETY: The host buffer holds `num_elements` elements of type `f32`, for which any bit
+    // pattern is valid, and it is borrowed mutably for the lifetime of the slice.
+    let output = unsafe { std::slice::from_raw_parts_mut(output_ptr, num_elements) };
+    preprocess_into(image, config, output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_image() -> image::DynamicImage {
+        let mut image = image::RgbImage::new(2, 1);
+        image.put_pixel(0, 0, image::Rgb([255, 0, 0]));
+        image.put_pixel(1, 0, image::Rgb([0, 255, 0]));
+        image::DynamicImage::ImageRgb8(image)
+    }
+
+    #[test]
+    fn test_preprocess_chw() {
+        let output = preprocess(&test_image(), &PreprocessConfig::new(2, 1));
+        assert_eq!(output, vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_preprocess_hwc() {
+        let config = PreprocessConfig::new(2, 1).with_layout(Layout::Hwc);
+        let output = preprocess(&test_image(), &config);
+        assert_eq!(output, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_preprocess_normalization() {
+        let config = PreprocessConfig::new(2, 1).with_normalization([0.5; 3], [0.5; 3]);
+        let output = preprocess(&test_image(), &config);
+        assert_eq!(output, vec![1.0, -1.0, -1.0, 1.0, -1.0, -1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_preprocess_into_host_buffer() {
+        let config = PreprocessConfig::new(2, 1);
+        let mut host_buffer = HostBuffer::new(config.num_elements()).await;
+        preprocess_into_host_buffer(&test_image(), &config, &mut host_buffer);
+        assert_eq!(host_buffer.to_vec(), preprocess(&test_image(), &config));
+    }
+}
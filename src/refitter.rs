@@ -0,0 +1,190 @@
+use crate::element::TensorElement;
+use crate::engine::Engine;
+use crate::executor::Future;
+use crate::ffi::data_type::DataType;
+#[cfg(feature = "onnx")]
+use crate::ffi::parsers::onnx::ParserRefitter as InnerParserRefitter;
+use crate::ffi::sync::refitter::Refitter as InnerRefitter;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// Updates weights in an engine without rebuilding it.
+///
+/// The engine must have been built with the `kREFIT` flag.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_refitter.html)
+pub struct Refitter<'engine> {
+    inner: InnerRefitter<'engine>,
+}
+
+impl<'engine> Refitter<'engine> {
+    /// Create a new [`Refitter`] for an [`Engine`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/namespacenvinfer1_1_1anonymous__namespace_02_nv_infer_8h_03.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to refit.
+    pub async fn new(engine: &'engine mut Engine) -> Result<Refitter<'engine>> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || InnerRefitter::new(engine.inner_mut()).map(Refitter::from_inner))
+        }
+        .await
+    }
+
+    /// Create [`Refitter`] from its inner object.
+    fn from_inner(inner: InnerRefitter<'engine>) -> Self {
+        Self { inner }
+    }
+
+    /// Specify new weights of given name.
+    ///
+    /// The weights are copied and kept alive by the refitter until it is dropped. The size of `T`
+    /// must match the size of `data_type`, see [`crate::Weights::new()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_refitter.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of weights.
+    /// * `data_type` - Data type of weights.
+    /// * `values` - Weight values.
+    #[inline(always)]
+    pub fn set_named_weights<T: TensorElement>(
+        &mut self,
+        name: &str,
+        data_type: DataType,
+        values: &[T],
+    ) -> Result<()> {
+        self.inner.set_named_weights(name, data_type, values)
+    }
+
+    /// Get names of weights that still need to be set before the engine can be refitted.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_refitter.html)
+    #[inline(always)]
+    pub fn get_missing_weights(&self) -> Vec<String> {
+        self.inner.get_missing_weights()
+    }
+
+    /// Update the engine with the new weights.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_refitter.html)
+    pub async fn refit_cuda_engine(&mut self) -> Result<()> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe { Future::new(move || self.inner.refit_cuda_engine()) }.await
+    }
+}
+
+/// Refits an engine with the weights of an updated ONNX model.
+///
+/// This allows shipping weight updates as ONNX models and applying them without rebuilding the
+/// engine. The updated model must have the same structure as the model the engine was built from,
+/// and the engine must have been built with the `kREFIT` flag. Requires TensorRT 10 or up.
+///
+/// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser_refitter.html)
+#[cfg(feature = "onnx")]
+pub struct ParserRefitter<'refitter, 'engine> {
+    inner: InnerParserRefitter<'refitter, 'engine>,
+    model: &'refitter [u8],
+}
+
+#[cfg(feature = "onnx")]
+impl<'refitter, 'engine> ParserRefitter<'refitter, 'engine> {
+    /// Create a new [`ParserRefitter`].
+    ///
+    /// # Arguments
+    ///
+    /// * `refitter` - Refitter of the engine to refit.
+    /// * `model` - Serialized ONNX model with the updated weights.
+    pub fn new(refitter: &'refitter mut Refitter<'engine>, model: &'refitter [u8]) -> Result<Self> {
+        let inner = InnerParserRefitter::new(&mut refitter.inner)?;
+        Ok(Self { inner, model })
+    }
+
+    /// Set the weights of the ONNX model and update the engine with them.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvonnxparser_1_1_i_parser_refitter.html)
+    pub async fn refit(&mut self) -> Result<()> {
+        let inner = &mut self.inner;
+        let model = self.model;
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                inner.refit_from_bytes(model)?;
+                inner.refit_cuda_engine()
+            })
+        }
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+    use crate::Runtime;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_refitter_refit_cuda_engine() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await.with_refit();
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let mut engine = Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        let mut refitter = Refitter::new(&mut engine).await.unwrap();
+        assert!(refitter.get_missing_weights().is_empty());
+        assert!(refitter.refit_cuda_engine().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_refitter_set_named_weights_invalid() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await.with_refit();
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let mut engine = Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        let mut refitter = Refitter::new(&mut engine).await.unwrap();
+        assert!(matches!(
+            refitter.set_named_weights("weights", DataType::Float, &[0u8; 4]),
+            Err(crate::Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            refitter.set_named_weights("unknown", DataType::Float, &[0.0f32; 4]),
+            Err(crate::Error::TensorRt(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_parser_refitter_refit() {
+        let (mut builder, mut network) = simple_network!();
+        let builder_config = builder.config().await.with_refit();
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let mut engine = Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        let mut refitter = Refitter::new(&mut engine).await.unwrap();
+        let mut parser_refitter =
+            ParserRefitter::new(&mut refitter, crate::tests::onnx::SIMPLE_ONNX).unwrap();
+        assert!(parser_refitter.refit().await.is_ok());
+    }
+}
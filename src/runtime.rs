@@ -1,9 +1,16 @@
-use async_cuda::runtime::Future;
+use async_cuda::device::DeviceId;
 
+use crate::device::DeviceGuard;
 use crate::engine::Engine;
+use crate::executor::Future;
+use crate::ffi::gpu_allocator::GpuAllocator;
 use crate::ffi::memory::HostBuffer;
+#[cfg(not(feature = "dispatch"))]
+use crate::ffi::plugin::PluginCreatorInfo;
 use crate::ffi::sync::runtime::Runtime as InnerRuntime;
 
+pub use crate::ffi::sync::runtime::TempfileControlFlags;
+
 type Result<T> = std::result::Result<T, crate::error::Error>;
 
 /// Allows a serialized engine to be serialized.
@@ -16,10 +23,199 @@ pub struct Runtime {
 impl Runtime {
     /// Create a new [`Runtime`].
     pub async fn new() -> Self {
-        let inner = Future::new(InnerRuntime::new).await;
+        // SAFETY: The future is awaited right away and not leaked.
+        let inner = unsafe { Future::new(InnerRuntime::new) }.await;
         Self { inner }
     }
 
+    /// Create a new [`Runtime`] on the given device.
+    ///
+    /// This holds a [`crate::DeviceGuard`] while creating the runtime, so it waits for other
+    /// guards to be released first.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Device to create the runtime on.
+    pub async fn new_on_device(device: DeviceId) -> Result<Self> {
+        let device_guard = DeviceGuard::new(device).await?;
+        let runtime = Self::new().await;
+        device_guard.restore().await?;
+        Ok(runtime)
+    }
+
+    /// Get the device that the runtime was created on.
+    #[inline(always)]
+    pub fn device(&self) -> DeviceId {
+        self.inner.device()
+    }
+
+    /// Set the allocator for all GPU memory allocated by the runtime and its engines.
+    ///
+    /// This must be called before deserializing an engine.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `allocator` - GPU allocator to use.
+    #[inline(always)]
+    pub fn set_gpu_allocator(&mut self, allocator: impl GpuAllocator + 'static) {
+        self.inner.set_gpu_allocator(Box::new(allocator))
+    }
+
+    /// Load plugins from a shared library so that engines that use them can be deserialized.
+    ///
+    /// The plugins are registered in the global plugin registry.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to plugin library.
+    #[cfg(not(feature = "dispatch"))]
+    #[inline(always)]
+    pub fn load_library(&mut self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        self.inner.load_library(path)
+    }
+
+    /// Get all plugin creators that are registered in the plugin registry.
+    ///
+    /// To register the plugins that ship with TensorRT, use [`crate::init_libnvinfer_plugins()`].
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_plugin_registry.html)
+    #[cfg(not(feature = "dispatch"))]
+    #[inline(always)]
+    pub fn plugin_creators(&self) -> Vec<PluginCreatorInfo> {
+        self.inner.plugin_creators()
+    }
+
+    /// Set the DLA core that deserialized engines must execute on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `dla_core` - DLA core to execute on, in the range `[0, num_dla_cores)`.
+    #[inline(always)]
+    pub fn set_dla_core(&mut self, dla_core: i32) {
+        self.inner.set_dla_core(dla_core)
+    }
+
+    /// Get the DLA core that deserialized engines execute on.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    #[inline(always)]
+    pub fn dla_core(&self) -> i32 {
+        self.inner.dla_core()
+    }
+
+    /// Set the maximum number of threads that the runtime may use.
+    ///
+    /// The default is one thread, which includes the calling thread. Bounding this is useful in
+    /// environments with CPU quotas.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `max_threads` - Maximum number of threads, must be at least one.
+    #[inline(always)]
+    pub fn set_max_threads(&mut self, max_threads: usize) -> Result<()> {
+        self.inner.set_max_threads(max_threads)
+    }
+
+    /// Get the maximum number of threads that the runtime may use.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    #[inline(always)]
+    pub fn max_threads(&self) -> usize {
+        self.inner.max_threads()
+    }
+
+    /// Get the number of DLA cores available to the runtime.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    #[inline(always)]
+    pub fn num_dla_cores(&self) -> usize {
+        self.inner.num_dla_cores()
+    }
+
+    /// Set whether or not the runtime may execute host code that is serialized in engines.
+    ///
+    /// Version compatible engines that are built with the lean runtime excluded contain host code
+    /// that the runtime must load to deserialize them. This is disallowed by default. Requires
+    /// TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed` - Whether or not engine host code is allowed.
+    #[inline(always)]
+    pub fn set_engine_host_code_allowed(&mut self, allowed: bool) -> Result<()> {
+        self.inner.set_engine_host_code_allowed(allowed)
+    }
+
+    /// Whether or not the runtime may execute host code that is serialized in engines.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    #[inline(always)]
+    pub fn engine_host_code_allowed(&self) -> bool {
+        self.inner.engine_host_code_allowed()
+    }
+
+    /// Set the directory that the runtime creates temporary files in.
+    ///
+    /// TensorRT creates temporary files to load the host code of version compatible engines. On
+    /// read-only filesystems, point this to a writable location such as a tmpfs, or disallow
+    /// temporary files with [`Runtime::set_tempfile_control_flags()`]. Requires TensorRT 8.6 or
+    /// up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Temporary directory, or `None` to use the default of the platform.
+    #[inline(always)]
+    pub fn set_temporary_directory(&mut self, path: Option<&std::path::Path>) -> Result<()> {
+        self.inner.set_temporary_directory(path)
+    }
+
+    /// Get the directory that the runtime creates temporary files in.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Return value
+    ///
+    /// Temporary directory, or `None` if the default of the platform is used.
+    #[inline(always)]
+    pub fn temporary_directory(&self) -> Option<std::path::PathBuf> {
+        self.inner.temporary_directory()
+    }
+
+    /// Set how the runtime may create temporary files.
+    ///
+    /// Pass [`TempfileControlFlags::empty()`] to disallow temporary files altogether. Requires
+    /// TensorRT 8.6 or up.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - Allowed kinds of temporary files.
+    #[inline(always)]
+    pub fn set_tempfile_control_flags(&mut self, flags: TempfileControlFlags) -> Result<()> {
+        self.inner.set_tempfile_control_flags(flags)
+    }
+
+    /// Get how the runtime may create temporary files.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    #[inline(always)]
+    pub fn tempfile_control_flags(&self) -> TempfileControlFlags {
+        self.inner.tempfile_control_flags()
+    }
+
     /// Deserialize engine from a plan (a [`HostBuffer`]).
     ///
     /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html#ad0dc765e77cab99bfad901e47216a767)
@@ -27,12 +223,83 @@ impl Runtime {
     /// # Arguments
     ///
     /// * `plan` - Plan to deserialize from.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device()))
+    )]
     pub async fn deserialize_engine_from_plan(self, plan: &HostBuffer) -> Result<Engine> {
-        Future::new(move || {
-            self.inner
-                .deserialize_engine_from_plan(plan)
-                .map(Engine::from_inner)
-        })
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .deserialize_engine_from_plan(plan)
+                    .map(Engine::from_inner)
+            })
+        }
+        .await
+    }
+
+    /// Deserialize engine from a plan file.
+    ///
+    /// On Unix platforms the file is memory-mapped instead of read into memory.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to plan file to deserialize from.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                device = self.device(),
+                path = %path.as_ref().display(),
+            )
+        )
+    )]
+    pub async fn deserialize_engine_from_file(
+        self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Engine> {
+        let path = path.as_ref();
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .deserialize_engine_from_file(path)
+                    .map(Engine::from_inner)
+            })
+        }
+        .await
+    }
+
+    /// Deserialize engine from a reader.
+    ///
+    /// The plan is streamed from the reader, so that it is never fully loaded into host memory.
+    /// Streaming requires TensorRT 10 or up. On older versions the plan is read into memory first.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html)
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Reader to read plan from.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device()))
+    )]
+    pub async fn deserialize_engine_from_reader(
+        self,
+        reader: impl std::io::Read + std::io::Seek + Send,
+    ) -> Result<Engine> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .deserialize_engine_from_reader(reader)
+                    .map(Engine::from_inner)
+            })
+        }
         .await
     }
 
@@ -43,12 +310,221 @@ impl Runtime {
     /// # Arguments
     ///
     /// * `buffer` - Buffer slice to read from.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = self.device()))
+    )]
     pub async fn deserialize_engine(self, buffer: &[u8]) -> Result<Engine> {
-        Future::new(move || {
-            self.inner
-                .deserialize_engine(buffer)
-                .map(Engine::from_inner)
-        })
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .deserialize_engine(buffer)
+                    .map(Engine::from_inner)
+            })
+        }
         .await
     }
+
+    /// Deserialize engine from a slice buffer on a specific device.
+    ///
+    /// Setting the device and deserializing happen in a single operation on the runtime thread,
+    /// so other tasks cannot change the current device in between. The engine is bound to
+    /// `device`, regardless of the device that was current when the runtime was created.
+    ///
+    /// [TensorRT documentation](https://docs.nvidia.com/deeplearning/tensorrt/api/c_api/classnvinfer1_1_1_i_runtime.html#ad0dc765e77cab99bfad901e47216a767)
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Buffer slice to read from.
+    /// * `device` - Device to deserialize the engine on.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(device = device))
+    )]
+    pub async fn deserialize_engine_on_device(
+        self,
+        buffer: &[u8],
+        device: DeviceId,
+    ) -> Result<Engine> {
+        // SAFETY: The future is awaited right away and not leaked.
+        unsafe {
+            Future::new(move || {
+                self.inner
+                    .deserialize_engine_on_device(buffer, device)
+                    .map(Engine::from_inner)
+            })
+        }
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use cpp::cpp;
+
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingGpuAllocator {
+        num_allocations: AtomicUsize,
+        num_deallocations: AtomicUsize,
+    }
+
+    impl GpuAllocator for CountingGpuAllocator {
+        fn allocate(&self, size: usize, _alignment: usize) -> *mut std::ffi::c_void {
+            self.num_allocations.fetch_add(1, Ordering::SeqCst);
+            cpp!(unsafe [size as "std::size_t"] -> *mut std::ffi::c_void as "void*" {
+                void* memory = nullptr;
+                if (cudaMalloc(&memory, size) != cudaSuccess) {
+                    return nullptr;
+                }
+                return memory;
+            })
+        }
+
+        fn deallocate(&self, memory: *mut std::ffi::c_void) -> bool {
+            self.num_deallocations.fetch_add(1, Ordering::SeqCst);
+            cpp!(unsafe [memory as "void*"] -> bool as "bool" {
+                return cudaFree(memory) == cudaSuccess;
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runtime_gpu_allocator() {
+        let allocator = Arc::new(CountingGpuAllocator::default());
+        let network_plan = simple_network_plan!();
+        let mut runtime = Runtime::new().await;
+        runtime.set_gpu_allocator(allocator.clone());
+        let engine = runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+        drop(engine);
+        let num_allocations = allocator.num_allocations.load(Ordering::SeqCst);
+        assert!(num_allocations > 0);
+        assert_eq!(
+            allocator.num_deallocations.load(Ordering::SeqCst),
+            num_allocations
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runtime_engine_host_code_allowed() {
+        let mut runtime = Runtime::new().await;
+        assert!(!runtime.engine_host_code_allowed());
+        runtime.set_engine_host_code_allowed(true).unwrap();
+        assert!(runtime.engine_host_code_allowed());
+    }
+
+    #[tokio::test]
+    async fn test_runtime_temporary_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut runtime = Runtime::new().await;
+        runtime
+            .set_temporary_directory(Some(directory.path()))
+            .unwrap();
+        assert_eq!(
+            runtime.temporary_directory().as_deref(),
+            Some(directory.path())
+        );
+        runtime.set_temporary_directory(None).unwrap();
+        assert_eq!(runtime.temporary_directory(), None);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_tempfile_control_flags() {
+        let mut runtime = Runtime::new().await;
+        assert_eq!(
+            runtime.tempfile_control_flags(),
+            TempfileControlFlags::default()
+        );
+        runtime
+            .set_tempfile_control_flags(TempfileControlFlags::ALLOW_IN_MEMORY_FILES)
+            .unwrap();
+        let flags = runtime.tempfile_control_flags();
+        assert!(flags.contains(TempfileControlFlags::ALLOW_IN_MEMORY_FILES));
+        assert!(!flags.contains(TempfileControlFlags::ALLOW_TEMPORARY_FILES));
+    }
+
+    #[tokio::test]
+    async fn test_runtime_dla_core() {
+        let mut runtime = Runtime::new().await;
+        if runtime.num_dla_cores() > 0 {
+            runtime.set_dla_core(0);
+            assert_eq!(runtime.dla_core(), 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runtime_max_threads() {
+        let mut runtime = Runtime::new().await;
+        runtime.set_max_threads(2).unwrap();
+        assert_eq!(runtime.max_threads(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_deserialize_engine_from_reader() {
+        let network_plan = simple_network_plan!();
+        let reader = std::io::Cursor::new(network_plan.as_bytes().to_vec());
+        let runtime = Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_from_reader(reader)
+            .await
+            .unwrap();
+        assert_eq!(engine.num_io_tensors(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_deserialize_engine_recorded_errors() {
+        let runtime = Runtime::new().await;
+        let err = runtime
+            .deserialize_engine(&[0, 1, 2, 3])
+            .await
+            .err()
+            .unwrap();
+        assert!(!err.recorded_errors().is_empty());
+        let crate::Error::TensorRt(code, message, recorded_errors) = &err else {
+            panic!("expected TensorRT error");
+        };
+        assert_eq!(*code, recorded_errors[0].0);
+        for (_, recorded_message) in recorded_errors {
+            assert!(message.contains(recorded_message.as_str()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runtime_new_on_device() {
+        let runtime = Runtime::new_on_device(0).await.unwrap();
+        assert_eq!(runtime.device(), 0);
+        assert!(Runtime::new_on_device(-1).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_runtime_deserialize_engine_on_device() {
+        let network_plan = simple_network_plan!();
+        let runtime = Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_on_device(&network_plan, 0)
+            .await
+            .unwrap();
+        assert_eq!(engine.device(), 0);
+        assert_eq!(engine.num_io_tensors(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_deserialize_engine_on_invalid_device() {
+        let network_plan = simple_network_plan!();
+        let runtime = Runtime::new().await;
+        assert!(runtime
+            .deserialize_engine_on_device(&network_plan, -1)
+            .await
+            .is_err());
+    }
 }
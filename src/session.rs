@@ -0,0 +1,834 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+
+use async_cuda::{DeviceBuffer, HostBuffer, Stream};
+
+use crate::context_pool::ExecutionContextPool;
+use crate::element::{as_bytes, from_bytes, TensorData, TensorElement};
+use crate::engine::{Engine, ExecutionContext, IoTensorDescriptor, TensorIoMode};
+use crate::error::Error;
+use crate::ffi::data_type::DataType;
+
+type Result<T> = std::result::Result<T, crate::error::Error>;
+
+/// High-level session for running inference on an [`Engine`].
+///
+/// The session owns an execution context, a stream and one device buffer for each IO tensor of the
+/// engine. Calling [`InferenceSession::infer()`] copies the inputs to the device, executes
+/// inference and copies the outputs back to the host.
+///
+/// Note that the session allocates its buffers from the tensor shapes of the engine, so all IO
+/// tensors must have static shapes.
+pub struct InferenceSession {
+    context: ExecutionContext<'static>,
+    stream: Stream,
+    io_buffers: HashMap<String, DeviceBuffer<u8>>,
+    data_types: HashMap<String, DataType>,
+    input_names: Vec<String>,
+    output_names: Vec<String>,
+}
+
+impl InferenceSession {
+    /// Create a new [`InferenceSession`].
+    ///
+    /// This consumes the engine and allocates a device buffer for each of its IO tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to perform inference with.
+    /// * `stream` - CUDA stream to execute on.
+    pub async fn new(engine: Engine, stream: Stream) -> Result<Self> {
+        let io_tensors = engine.io_tensors();
+        let context = ExecutionContext::from_engine(engine).await?;
+        Self::from_context(context, stream, &io_tensors).await
+    }
+
+    /// Create a new [`InferenceSession`] from an existing execution context.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Execution context to perform inference with.
+    /// * `stream` - CUDA stream to execute on.
+    /// * `io_tensors` - IO tensors of the engine that the context belongs to.
+    pub(crate) async fn from_context(
+        context: ExecutionContext<'static>,
+        stream: Stream,
+        io_tensors: &[IoTensorDescriptor],
+    ) -> Result<Self> {
+        let mut io_buffers = HashMap::with_capacity(io_tensors.len());
+        let mut data_types = HashMap::with_capacity(io_tensors.len());
+        let mut input_names = Vec::new();
+        let mut output_names = Vec::new();
+        for io_tensor in io_tensors {
+            let data_type = io_tensor.data_type.ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "tensor {} has a data type that is not supported",
+                    io_tensor.name,
+                ))
+            })?;
+            let num_elements = io_tensor.shape.num_elements().unwrap_or(0);
+            let buffer = DeviceBuffer::new(num_elements * data_type.size(), &stream).await;
+            match io_tensor.io_mode {
+                TensorIoMode::Input => input_names.push(io_tensor.name.clone()),
+                TensorIoMode::Output => output_names.push(io_tensor.name.clone()),
+                _ => {}
+            }
+            io_buffers.insert(io_tensor.name.clone(), buffer);
+            data_types.insert(io_tensor.name.clone(), data_type);
+        }
+        Ok(Self {
+            context,
+            stream,
+            io_buffers,
+            data_types,
+            input_names,
+            output_names,
+        })
+    }
+
+    /// Run inference.
+    ///
+    /// Every input tensor of the engine must be in `inputs`. All IO tensors must have data type
+    /// [`DataType::Float`]. Use [`InferenceSession::infer_typed()`] or
+    /// [`InferenceSession::infer_tensors()`] for other data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn infer(
+        &mut self,
+        inputs: HashMap<&str, &[f32]>,
+    ) -> Result<HashMap<String, Vec<f32>>> {
+        self.infer_typed(inputs).await
+    }
+
+    /// Run inference with IO tensors of data type `T`.
+    ///
+    /// With the `half` feature enabled, this accepts [`crate::f16`] and [`crate::bf16`] data for
+    /// half precision engines.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn infer_typed<T: TensorElement>(
+        &mut self,
+        inputs: HashMap<&str, &[T]>,
+    ) -> Result<HashMap<String, Vec<T>>> {
+        for name in &self.output_names {
+            self.check_data_type(name, T::DATA_TYPE)?;
+        }
+        let inputs = inputs
+            .into_iter()
+            .map(|(name, data)| (name, T::DATA_TYPE, as_bytes(data)))
+            .collect();
+        let outputs = self.infer_bytes(inputs).await?;
+        Ok(outputs
+            .into_iter()
+            .map(|(name, data)| (name, from_bytes(&data)))
+            .collect())
+    }
+
+    /// Run inference with IO tensors of any data type.
+    ///
+    /// The variant of each input must match the data type of the tensor. Outputs are returned in
+    /// the variant that matches the data type of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name.
+    pub async fn infer_tensors(
+        &mut self,
+        inputs: HashMap<&str, TensorData>,
+    ) -> Result<HashMap<String, TensorData>> {
+        let inputs = inputs
+            .iter()
+            .map(|(name, data)| (*name, data.data_type(), data.as_bytes()))
+            .collect();
+        let outputs = self.infer_bytes(inputs).await?;
+        outputs
+            .into_iter()
+            .map(|(name, data)| {
+                let data_type = self.data_types[&name];
+                let data = TensorData::from_bytes(data_type, &data).ok_or_else(|| {
+                    Error::InvalidArgument(format!(
+                        "output tensor {name} has data type {data_type:?} which cannot be read back"
+                    ))
+                })?;
+                Ok((name, data))
+            })
+            .collect()
+    }
+
+    /// Run inference with raw input and output data.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input tensor name, data type and raw data.
+    ///
+    /// # Return value
+    ///
+    /// Raw output data by tensor name.
+    async fn infer_bytes(
+        &mut self,
+        inputs: Vec<(&str, DataType, &[u8])>,
+    ) -> Result<HashMap<String, Vec<u8>>> {
+        for name in &self.input_names {
+            if !inputs.iter().any(|(input_name, _, _)| input_name == name) {
+                return Err(Error::InvalidArgument(format!(
+                    "missing input tensor: {name}"
+                )));
+            }
+        }
+        for (name, data_type, data) in inputs {
+            self.check_data_type(name, data_type)?;
+            let buffer = self
+                .io_buffers
+                .get_mut(name)
+                .ok_or_else(|| Error::UnknownTensor(name.to_string()))?;
+            if buffer.num_elements() != data.len() {
+                return Err(Error::InvalidArgument(format!(
+                    "input tensor {name} has {} elements but {} were provided",
+                    buffer.num_elements() / data_type.size(),
+                    data.len() / data_type.size(),
+                )));
+            }
+            let host_buffer = HostBuffer::from_slice(data).await;
+            buffer.copy_from(&host_buffer, &self.stream).await?;
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_bytes_transferred(
+                crate::metrics::Direction::HostToDevice,
+                data.len(),
+            );
+        }
+
+        let mut io_buffers = self
+            .io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (name.as_str(), buffer))
+            .collect();
+        self.context.enqueue(&mut io_buffers, &self.stream).await?;
+
+        let mut host_buffers = Vec::with_capacity(self.output_names.len());
+        for name in &self.output_names {
+            let buffer = &self.io_buffers[name];
+            let mut host_buffer = HostBuffer::new(buffer.num_elements()).await;
+            buffer.copy_to(&mut host_buffer, &self.stream).await?;
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_bytes_transferred(
+                crate::metrics::Direction::DeviceToHost,
+                host_buffer.num_elements(),
+            );
+            host_buffers.push((name.clone(), host_buffer));
+        }
+        self.stream.synchronize().await?;
+        Ok(host_buffers
+            .into_iter()
+            .map(|(name, host_buffer)| (name, host_buffer.to_vec()))
+            .collect())
+    }
+
+    /// Check that the data type of an IO tensor matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Tensor name.
+    /// * `data_type` - Data type of the data provided for or expected from the tensor.
+    fn check_data_type(&self, name: &str, data_type: DataType) -> Result<()> {
+        match self.data_types.get(name) {
+            Some(&expected) if expected != data_type => Err(Error::DataTypeMismatch {
+                tensor_name: name.to_string(),
+                expected,
+                actual: data_type,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the stream the session executes on.
+    #[inline(always)]
+    pub fn stream(&self) -> &Stream {
+        &self.stream
+    }
+}
+
+/// Session for stateful models that carry state from one step to the next, such as streaming
+/// speech recognition models.
+///
+/// The state is passed between steps through pairs of IO tensors: an output tensor that holds the
+/// state after a step, and an input tensor that receives it at the next step. The state stays on
+/// the device. After each step, the buffers of each pair are swapped, so that the output of the
+/// previous step is bound as input without copying it.
+///
+/// State outputs are not returned from [`StatefulSession::step()`]. State inputs start out zeroed,
+/// and may be overwritten by passing them as regular inputs.
+///
+/// Like [`InferenceSession`], all IO tensors must have static shapes.
+pub struct StatefulSession {
+    session: InferenceSession,
+    state: Vec<(String, String)>,
+}
+
+impl StatefulSession {
+    /// Create a new [`StatefulSession`].
+    ///
+    /// This consumes the engine and allocates a device buffer for each of its IO tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to perform inference with.
+    /// * `stream` - CUDA stream to execute on.
+    /// * `state` - Map from state output tensor name to the name of the input tensor that receives
+    ///   it at the next step. Both tensors must have the same data type and size.
+    pub async fn new(engine: Engine, stream: Stream, state: HashMap<&str, &str>) -> Result<Self> {
+        let io_tensors = engine.io_tensors();
+        let find_io_tensor = |name: &str, io_mode: TensorIoMode| {
+            io_tensors
+                .iter()
+                .find(|io_tensor| io_tensor.name == name && io_tensor.io_mode == io_mode)
+                .ok_or_else(|| {
+                    Error::InvalidArgument(format!(
+                        "tensor {name} is not an {} tensor",
+                        if io_mode == TensorIoMode::Input {
+                            "input"
+                        } else {
+                            "output"
+                        },
+                    ))
+                })
+        };
+        for (&output_name, &input_name) in &state {
+            let output = find_io_tensor(output_name, TensorIoMode::Output)?;
+            let input = find_io_tensor(input_name, TensorIoMode::Input)?;
+            if let (Some(expected), Some(actual)) = (input.data_type, output.data_type) {
+                if expected != actual {
+                    return Err(Error::DataTypeMismatch {
+                        tensor_name: output_name.to_string(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+            if input.shape.num_elements() != output.shape.num_elements() {
+                return Err(Error::ShapeMismatch {
+                    tensor_name: output_name.to_string(),
+                    expected: input.shape.iter().map(|&dim| dim.max(0) as usize).collect(),
+                    actual: output
+                        .shape
+                        .iter()
+                        .map(|&dim| dim.max(0) as usize)
+                        .collect(),
+                });
+            }
+        }
+
+        let context = ExecutionContext::from_engine(engine).await?;
+        let mut session = InferenceSession::from_context(context, stream, &io_tensors).await?;
+        session
+            .output_names
+            .retain(|name| !state.contains_key(name.as_str()));
+        session
+            .input_names
+            .retain(|name| !state.values().any(|input_name| input_name == name));
+        let mut stateful_session = Self {
+            session,
+            state: state
+                .into_iter()
+                .map(|(output_name, input_name)| (output_name.to_string(), input_name.to_string()))
+                .collect(),
+        };
+        stateful_session.reset().await?;
+        Ok(stateful_session)
+    }
+
+    /// Run a single step.
+    ///
+    /// Every input tensor of the engine except the state inputs must be in `inputs`. All IO
+    /// tensors must have data type [`DataType::Float`]. Use [`StatefulSession::step_typed()`] for
+    /// other data types.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name, excluding state outputs.
+    pub async fn step(
+        &mut self,
+        inputs: HashMap<&str, &[f32]>,
+    ) -> Result<HashMap<String, Vec<f32>>> {
+        self.step_typed(inputs).await
+    }
+
+    /// Run a single step with IO tensors of data type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name, excluding state outputs.
+    pub async fn step_typed<T: TensorElement>(
+        &mut self,
+        inputs: HashMap<&str, &[T]>,
+    ) -> Result<HashMap<String, Vec<T>>> {
+        let outputs = self.session.infer_typed(inputs).await?;
+        for (output_name, input_name) in &self.state {
+            let output_buffer = self.session.io_buffers.remove(output_name).unwrap();
+            let input_buffer = self
+                .session
+                .io_buffers
+                .insert(input_name.clone(), output_buffer)
+                .unwrap();
+            self.session
+                .io_buffers
+                .insert(output_name.clone(), input_buffer);
+        }
+        Ok(outputs)
+    }
+
+    /// Reset the state by zeroing all state inputs.
+    pub async fn reset(&mut self) -> Result<()> {
+        for (_, input_name) in &self.state {
+            let buffer = self.session.io_buffers.get_mut(input_name).unwrap();
+            let zeros = HostBuffer::from_slice(&vec![0_u8; buffer.num_elements()]).await;
+            buffer.copy_from(&zeros, &self.session.stream).await?;
+        }
+        self.session.stream.synchronize().await?;
+        Ok(())
+    }
+
+    /// Get the stream the session executes on.
+    #[inline(always)]
+    pub fn stream(&self) -> &Stream {
+        self.session.stream()
+    }
+}
+
+/// Runs inference on an arbitrary number of samples by splitting them into batches.
+///
+/// The first dimension of every IO tensor is treated as the batch dimension. For inputs, this
+/// dimension must be dynamic, and the maximum batch size is taken from the first optimization
+/// profile of the engine. All other dimensions must be static.
+///
+/// Batches run on a pool of execution contexts. With a single context, batches run sequentially.
+/// With more contexts, batches run concurrently. Either way, outputs are reassembled in the order
+/// of the samples.
+pub struct BatchRunner {
+    contexts: ExecutionContextPool,
+    inputs: Vec<IoTensorDescriptor>,
+    outputs: Vec<IoTensorDescriptor>,
+    max_batch_size: usize,
+}
+
+impl BatchRunner {
+    /// Create a new [`BatchRunner`].
+    ///
+    /// This consumes the engine.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - Engine to perform inference with.
+    /// * `num_contexts` - Number of execution contexts, which is the maximum number of batches
+    ///   that run concurrently.
+    pub async fn new(engine: Engine, num_contexts: usize) -> Result<Self> {
+        let inputs = engine.inputs();
+        let outputs = engine.outputs();
+        let mut max_batch_size = usize::MAX;
+        for input in &inputs {
+            if input.shape.first() != Some(&-1) || input.shape[1..].iter().any(|&dim| dim < 0) {
+                return Err(Error::InvalidArgument(format!(
+                    "input tensor {} must have a dynamic batch dimension and static other \
+                     dimensions",
+                    input.name,
+                )));
+            }
+            let profile_max_shape = engine.profile_max_shape(&input.name, 0).ok_or_else(|| {
+                Error::InvalidArgument(format!(
+                    "input tensor {} has no optimization profile",
+                    input.name,
+                ))
+            })?;
+            max_batch_size = max_batch_size.min(profile_max_shape[0].max(1) as usize);
+        }
+        let contexts = ExecutionContextPool::new(engine, num_contexts).await?;
+        Ok(Self {
+            contexts,
+            inputs,
+            outputs,
+            max_batch_size,
+        })
+    }
+
+    /// Get the maximum number of samples in a single batch.
+    #[inline(always)]
+    pub fn max_batch_size(&self) -> usize {
+        self.max_batch_size
+    }
+
+    /// Run inference on all samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input data by tensor name. The data for each input holds all samples
+    ///   back-to-back.
+    ///
+    /// # Return value
+    ///
+    /// Output data by tensor name, with the outputs of all samples back-to-back.
+    pub async fn run(&self, inputs: HashMap<&str, &[f32]>) -> Result<HashMap<String, Vec<f32>>> {
+        let mut num_samples = None;
+        for input in &self.inputs {
+            let data = inputs.get(input.name.as_str()).ok_or_else(|| {
+                Error::InvalidArgument(format!("missing input tensor: {}", input.name))
+            })?;
+            let sample_size = sample_size(input);
+            if sample_size == 0 || data.len() % sample_size != 0 {
+                return Err(Error::InvalidArgument(format!(
+                    "input tensor {} has {} elements which is not a multiple of the sample size {}",
+                    input.name,
+                    data.len(),
+                    sample_size,
+                )));
+            }
+            let input_num_samples = data.len() / sample_size;
+            if matches!(num_samples, Some(num_samples) if num_samples != input_num_samples) {
+                return Err(Error::InvalidArgument(
+                    "inputs have different numbers of samples".to_string(),
+                ));
+            }
+            num_samples = Some(input_num_samples);
+        }
+        let num_samples = num_samples.unwrap_or(0);
+
+        let batches = (0..num_samples)
+            .step_by(self.max_batch_size)
+            .map(|start| {
+                let batch_size = self.max_batch_size.min(num_samples - start);
+                let batch_inputs = self
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let sample_size = sample_size(input);
+                        let data = inputs[input.name.as_str()];
+                        (
+                            input,
+                            &data[start * sample_size..(start + batch_size) * sample_size],
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                Box::pin(self.run_batch(batch_inputs, batch_size))
+                    as Pin<Box<dyn Future<Output = _> + Send + '_>>
+            })
+            .collect::<Vec<_>>();
+
+        let mut outputs = self
+            .outputs
+            .iter()
+            .map(|output| (output.name.clone(), Vec::new()))
+            .collect::<HashMap<_, _>>();
+        for batch_outputs in join_all(batches).await {
+            for (name, data) in batch_outputs? {
+                outputs.get_mut(&name).unwrap().extend_from_slice(&data);
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Run inference on a single batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - Input tensors with the data of the batch.
+    /// * `batch_size` - Number of samples in batch.
+    async fn run_batch(
+        &self,
+        inputs: Vec<(&IoTensorDescriptor, &[f32])>,
+        batch_size: usize,
+    ) -> Result<HashMap<String, Vec<f32>>> {
+        let mut context = self.contexts.acquire().await;
+        let stream = Stream::new().await?;
+
+        let mut io_buffers = HashMap::with_capacity(inputs.len() + self.outputs.len());
+        for (input, data) in inputs {
+            let mut shape = input.shape.clone();
+            shape[0] = batch_size as i64;
+            context.set_input_shape(&input.name, shape)?;
+            let host_buffer = HostBuffer::from_slice(data).await;
+            let mut buffer = DeviceBuffer::new(data.len(), &stream).await;
+            buffer.copy_from(&host_buffer, &stream).await?;
+            io_buffers.insert(input.name.as_str(), buffer);
+        }
+        for output in &self.outputs {
+            let num_elements = context
+                .tensor_shape(&output.name)
+                .num_elements()
+                .unwrap_or(0);
+            let buffer = DeviceBuffer::new(num_elements, &stream).await;
+            io_buffers.insert(output.name.as_str(), buffer);
+        }
+
+        let mut io_buffers_ref = io_buffers
+            .iter_mut()
+            .map(|(name, buffer)| (*name, buffer))
+            .collect();
+        context.execute(&mut io_buffers_ref, &stream).await?;
+
+        let mut host_buffers = Vec::with_capacity(self.outputs.len());
+        for output in &self.outputs {
+            let buffer = &io_buffers[output.name.as_str()];
+            let mut host_buffer = HostBuffer::new(buffer.num_elements()).await;
+            buffer.copy_to(&mut host_buffer, &stream).await?;
+            host_buffers.push((output.name.clone(), host_buffer));
+        }
+        stream.synchronize().await?;
+        Ok(host_buffers
+            .into_iter()
+            .map(|(name, host_buffer)| (name, host_buffer.to_vec()))
+            .collect())
+    }
+}
+
+/// Get the number of elements in a single sample of a batched tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - Tensor of which the first dimension is the batch dimension.
+fn sample_size(tensor: &IoTensorDescriptor) -> usize {
+    tensor.shape[1..].iter().product::<i64>().max(0) as usize
+}
+
+/// Wait for all futures to complete, and return their outputs in order.
+///
+/// # Arguments
+///
+/// * `futures` - Futures to run concurrently.
+async fn join_all<T>(mut futures: Vec<Pin<Box<dyn Future<Output = T> + Send + '_>>>) -> Vec<T> {
+    let mut outputs = futures.iter().map(|_| None).collect::<Vec<_>>();
+    std::future::poll_fn(|cx| {
+        let mut done = true;
+        for (future, output) in futures.iter_mut().zip(outputs.iter_mut()) {
+            if output.is_none() {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(value) => *output = Some(value),
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+        if done {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+    outputs.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_inference_session_infer() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let mut session = InferenceSession::new(engine, stream).await.unwrap();
+        let outputs = session
+            .infer(HashMap::from([("X", [2.0, 4.0].as_slice())]))
+            .await
+            .unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(&outputs["Y"], &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[tokio::test]
+    async fn test_inference_session_infer_invalid_input() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let mut session = InferenceSession::new(engine, stream).await.unwrap();
+        assert!(session
+            .infer(HashMap::from([("X", [2.0, 4.0, 6.0].as_slice())]))
+            .await
+            .is_err());
+        assert!(matches!(
+            session
+                .infer(HashMap::from([
+                    ("X", [2.0, 4.0].as_slice()),
+                    ("Z", [2.0, 4.0].as_slice()),
+                ]))
+                .await,
+            Err(Error::UnknownTensor(_))
+        ));
+        assert!(matches!(
+            session.infer(HashMap::new()).await,
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_inference_session_infer_typed_data_type_mismatch() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let mut session = InferenceSession::new(engine, stream).await.unwrap();
+        assert!(matches!(
+            session
+                .infer_typed(HashMap::from([("X", [2, 4].as_slice())]))
+                .await,
+            Err(Error::DataTypeMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_inference_session_infer_tensors() {
+        let stream = Stream::new().await.unwrap();
+        let engine = simple_engine!();
+        let mut session = InferenceSession::new(engine, stream).await.unwrap();
+        let outputs = session
+            .infer_tensors(HashMap::from([("X", TensorData::F32(vec![2.0, 4.0]))]))
+            .await
+            .unwrap();
+        assert_eq!(
+            outputs["Y"],
+            TensorData::F32(vec![2.0, 0.0, 0.0, 0.0, 0.0, 0.0])
+        );
+        assert!(matches!(
+            session
+                .infer_tensors(HashMap::from([("X", TensorData::I64(vec![2, 4]))]))
+                .await,
+            Err(Error::DataTypeMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stateful_session_step() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let mut network =
+            builder.network_definition(crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        {
+            let input = network
+                .add_input("X", crate::DataType::Float, &[1, 4])
+                .unwrap();
+            let state = network
+                .add_input("S", crate::DataType::Float, &[1, 4])
+                .unwrap();
+            let sum = network
+                .add_elementwise(&input, &state, crate::ElementWiseOperation::Sum)
+                .unwrap();
+            let mut output = sum.output(0);
+            output.set_name("Y");
+            network.mark_output(&output);
+            let copy = network.add_shuffle(&output, None).unwrap();
+            let mut next_state = copy.output(0);
+            next_state.set_name("S_next");
+            network.mark_output(&next_state);
+        }
+        let builder_config = builder.config().await;
+        let plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let engine = crate::Runtime::new()
+            .await
+            .deserialize_engine_from_plan(&plan)
+            .await
+            .unwrap();
+        let stream = Stream::new().await.unwrap();
+        let mut session = StatefulSession::new(engine, stream, HashMap::from([("S_next", "S")]))
+            .await
+            .unwrap();
+        let input = [1.0, 1.0, 1.0, 1.0];
+        for step in 1..=3 {
+            let outputs = session
+                .step(HashMap::from([("X", input.as_slice())]))
+                .await
+                .unwrap();
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(&outputs["Y"], &[step as f32; 4]);
+        }
+        session.reset().await.unwrap();
+        let outputs = session
+            .step(HashMap::from([("X", input.as_slice())]))
+            .await
+            .unwrap();
+        assert_eq!(&outputs["Y"], &[1.0; 4]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_runner_run() {
+        let mut builder = crate::Builder::new().await.unwrap();
+        let mut network =
+            builder.network_definition(crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        {
+            let input = network
+                .add_input("X", crate::DataType::Float, &[-1, 2])
+                .unwrap();
+            let relu = network
+                .add_activation(&input, crate::ActivationType::Relu)
+                .unwrap();
+            let mut output = relu.output(0);
+            output.set_name("Y");
+            network.mark_output(&output);
+        }
+        let builder_config = builder.config().await;
+        let mut optimization_profile = builder.optimization_profile().unwrap();
+        optimization_profile
+            .try_set_min_dimensions("X", &[1, 2])
+            .unwrap();
+        optimization_profile
+            .try_set_opt_dimensions("X", &[2, 2])
+            .unwrap();
+        optimization_profile
+            .try_set_max_dimensions("X", &[2, 2])
+            .unwrap();
+        let builder_config = builder_config
+            .with_optimization_profile(optimization_profile)
+            .unwrap();
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let runtime = crate::Runtime::new().await;
+        let engine = runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap();
+
+        let batch_runner = BatchRunner::new(engine, 2).await.unwrap();
+        assert_eq!(batch_runner.max_batch_size(), 2);
+        let outputs = batch_runner
+            .run(HashMap::from([(
+                "X",
+                [1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0, -4.0, 5.0, -5.0].as_slice(),
+            )]))
+            .await
+            .unwrap();
+        assert_eq!(
+            &outputs["Y"],
+            &[1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 4.0, 0.0, 5.0, 0.0],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_runner_static_batch() {
+        let engine = simple_engine!();
+        assert!(BatchRunner::new(engine, 1).await.is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Synchronous API.
+//!
+//! The async types of this crate run every TensorRT call on a background thread (see
+//! [`crate::configure_executor()`]). The types in this module expose the same functionality
+//! without that hop: each call executes TensorRT directly on the calling thread and blocks until
+//! it returns. This suits applications that already run inference from dedicated threads and do
+//! not use an async runtime.
+//!
+//! This module is available with the `sync-api` feature. The async API is available as well
+//! unless the default `async` feature is disabled, in which case only this module and the
+//! synchronous types in [`crate::ffi`] are compiled:
+//!
+//! ```toml
+//! async-tensorrt = { version = "0.9", default-features = false, features = ["sync-api"] }
+//! ```
+//!
+//! Each type mirrors its async counterpart, and each method links to the async method that it is
+//! the synchronous version of. The differences are:
+//!
+//! * Calls block the calling thread. Expensive calls such as building or deserializing an engine
+//!   should not be made from async tasks.
+//! * Objects are bound to the device that is current on the calling thread when they are created,
+//!   and calls must be made with that device current. Use [`async_cuda::ffi::device::Device::set()`]
+//!   to select the device of the calling thread.
+//! * Streams and device buffers are the synchronous types from [`async_cuda::ffi`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::collections::HashMap;
+//!
+//! use async_cuda::ffi::memory::DeviceBuffer;
+//! use async_cuda::ffi::stream::Stream;
+//! use async_tensorrt::sync::{ExecutionContext, Runtime};
+//!
+//! let engine = Runtime::new()
+//!     .deserialize_engine_from_file("model.engine")
+//!     .unwrap();
+//! let mut context = ExecutionContext::from_engine(engine).unwrap();
+//! let stream = Stream::new().unwrap();
+//! let mut input = DeviceBuffer::<f32>::new(4, &stream);
+//! let mut output = DeviceBuffer::<f32>::new(4, &stream);
+//! let mut io_buffers = HashMap::from([("X", &mut input), ("Y", &mut output)]);
+//! context.enqueue(&mut io_buffers, &stream).unwrap();
+//! stream.synchronize().unwrap();
+//! ```
+
+#[cfg(not(feature = "dispatch"))]
+pub use crate::ffi::sync::builder::Builder;
+pub use crate::ffi::sync::engine::{Engine, ExecutionContext};
+pub use crate::ffi::sync::event::Event;
+#[cfg(not(feature = "dispatch"))]
+pub use crate::ffi::sync::refitter::Refitter;
+pub use crate::ffi::sync::runtime::Runtime;
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use crate::tests::utils::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sync_deserialize_and_create_context() {
+        let plan = simple_network_plan!();
+        let engine = Runtime::new().deserialize_engine_from_plan(&plan).unwrap();
+        assert_eq!(engine.num_io_tensors(), 2);
+        assert!(ExecutionContext::from_engine(engine).is_ok());
+    }
+}
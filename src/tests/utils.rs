@@ -7,7 +7,7 @@ macro_rules! simple_network {
             .with_default_optimization_profile()
             .unwrap();
         let network =
-            builder.network_definition($crate::NetworkDefinitionCreationFlags::ExplicitBatchSize);
+            builder.network_definition($crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
         let network =
             $crate::Parser::parse_network_definition_from_file(network, &simple_onnx_file.path())
                 .unwrap();
@@ -37,6 +37,42 @@ macro_rules! simple_engine {
     }};
 }
 
+macro_rules! identity_network {
+    () => {{
+        let mut builder = $crate::Builder::new().await.unwrap();
+        let mut network =
+            builder.network_definition($crate::NetworkDefinitionCreationFlags::EXPLICIT_BATCH_SIZE);
+        {
+            let input = network
+                .add_input("X", $crate::DataType::Float, &[1, 4])
+                .unwrap();
+            let identity = network.add_shuffle(&input, None).unwrap();
+            let mut output = identity.output(0);
+            output.set_name("Y");
+            network.mark_output(&output);
+        }
+        (builder, network)
+    }};
+}
+
+macro_rules! identity_engine {
+    () => {{
+        let (mut builder, mut network) = $crate::tests::utils::identity_network!();
+        let builder_config = builder.config().await;
+        let network_plan = builder
+            .build_serialized_network(&mut network, builder_config)
+            .await
+            .unwrap();
+        let runtime = $crate::Runtime::new().await;
+        runtime
+            .deserialize_engine_from_plan(&network_plan)
+            .await
+            .unwrap()
+    }};
+}
+
+pub(crate) use identity_engine;
+pub(crate) use identity_network;
 pub(crate) use simple_engine;
 pub(crate) use simple_network;
 pub(crate) use simple_network_plan;